@@ -1,27 +1,172 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
+/**
+ * A client certificate, either as PEM certificate and key files or as a single PKCS#12 file
+ */
+export type ClientCertificate = { enabled?: boolean, 
+/**
+ * Host to use the certificate for, like `api.example.com`, `*.example.com` or `localhost:8443`
+ */
+host: string, crtFile: string | null, keyFile: string | null, pfxFile: string | null, passphrase: string | null, };
+
+/**
+ * An address to connect to for a host, instead of the one from DNS, like curl's `--resolve`.
+ * Both fields may contain templates.
+ */
+export type DnsOverride = { enabled?: boolean, 
+/**
+ * Hostname to override, like `api.example.com`
+ */
+host: string, 
+/**
+ * IPv4 or IPv6 address to connect to instead
+ */
+address: string, id?: string, };
+
 export type Environment = { model: "environment", id: string, workspaceId: string, createdAt: string, updatedAt: string, name: string, public: boolean, base: boolean, variables: Array<EnvironmentVariable>, };
 
 export type EnvironmentVariable = { enabled?: boolean, name: string, value: string, id?: string, };
 
-export type Folder = { model: "folder", id: string, createdAt: string, updatedAt: string, workspaceId: string, folderId: string | null, name: string, description: string, sortPriority: number, };
+export type Folder = { model: "folder", id: string, createdAt: string, updatedAt: string, workspaceId: string, folderId: string | null, authentication: Record<string, any>, authenticationType: string | null, description: string, headers: Array<HttpRequestHeader>, name: string, sortPriority: number, variables: Array<EnvironmentVariable>, settingFollowRedirects: boolean | null, settingMaxResponseSize: number | null, settingRequestTimeout: number | null, settingValidateCertificates: boolean | null, };
 
 export type GrpcMetadataEntry = { enabled?: boolean, name: string, value: string, id?: string, };
 
-export type GrpcRequest = { model: "grpc_request", id: string, createdAt: string, updatedAt: string, workspaceId: string, folderId: string | null, authenticationType: string | null, authentication: Record<string, any>, description: string, message: string, metadata: Array<GrpcMetadataEntry>, method: string | null, name: string, service: string | null, sortPriority: number, url: string, };
+export type GrpcRequest = { model: "grpc_request", id: string, createdAt: string, updatedAt: string, workspaceId: string, folderId: string | null, authenticationType: string | null, authentication: Record<string, any>, description: string, message: string, metadata: Array<GrpcMetadataEntry>, method: string | null, name: string, service: string | null, sortPriority: number, url: string, settingRequestTimeout: number | null, settingValidateCertificates: boolean | null, };
+
+export type HttpAssertionOperator = "equals" | "not_equals" | "contains" | "matches" | "less_than" | "greater_than" | "exists";
+
+export type HttpAssertionResult = { source: HttpAssertionSource, property: string, operator: HttpAssertionOperator, expected: string, actual: string | null, passed: boolean, error: string | null, };
 
-export type HttpRequest = { model: "http_request", id: string, createdAt: string, updatedAt: string, workspaceId: string, folderId: string | null, authentication: Record<string, any>, authenticationType: string | null, body: Record<string, any>, bodyType: string | null, description: string, headers: Array<HttpRequestHeader>, method: string, name: string, sortPriority: number, url: string, urlParameters: Array<HttpUrlParameter>, };
+export type HttpAssertionSource = "status" | "header" | "json_path" | "elapsed";
+
+export type HttpRequest = { model: "http_request", id: string, createdAt: string, updatedAt: string, workspaceId: string, folderId: string | null, assertions: Array<HttpRequestAssertion>, authentication: Record<string, any>, authenticationType: string | null, body: Record<string, any>, bodyType: string | null, description: string, headers: Array<HttpRequestHeader>, httpVersion: HttpVersion, method: string, name: string, retry: HttpRequestRetry, sortPriority: number, url: string, urlParameters: Array<HttpUrlParameter>, settingFollowRedirects: boolean | null, settingMaxResponseSize: number | null, settingRequestTimeout: number | null, settingValidateCertificates: boolean | null, };
+
+export type HttpRequestAssertion = { enabled?: boolean, source: HttpAssertionSource, property: string, operator: HttpAssertionOperator, value: string, id?: string, };
 
 export type HttpRequestHeader = { enabled?: boolean, name: string, value: string, id?: string, };
 
-export type HttpResponse = { model: "http_response", id: string, createdAt: string, updatedAt: string, workspaceId: string, requestId: string, bodyPath: string | null, contentLength: number | null, elapsed: number, elapsedHeaders: number, error: string | null, headers: Array<HttpResponseHeader>, remoteAddr: string | null, status: number, statusReason: string | null, state: HttpResponseState, url: string, version: string | null, };
+/**
+ * When and how often to re-send a request that failed
+ */
+export type HttpRequestRetry = { enabled: boolean, 
+/**
+ * Total number of attempts, including the first one
+ */
+maxAttempts: number, statusCodes: Array<number>, errors: Array<HttpRetryError>, 
+/**
+ * Delay before the first retry, in milliseconds. It doubles for each retry after that.
+ */
+initialDelay: number, 
+/**
+ * Upper bound for the delay between attempts, in milliseconds
+ */
+maxDelay: number, 
+/**
+ * Wait as long as the server's Retry-After header asks, instead of backing off
+ */
+respectRetryAfter: boolean, };
+
+export type HttpResponse = { model: "http_response", id: string, createdAt: string, updatedAt: string, workspaceId: string, requestId: string, assertionResults: Array<HttpAssertionResult>, attempts: Array<HttpResponseAttempt>, bodyPath: string | null, 
+/**
+ * Bytes of the request body files that were uploaded so far
+ */
+bytesUploaded: number, contentLength: number | null, elapsed: number, elapsedHeaders: number, error: string | null, headers: Array<HttpResponseHeader>, redirects: Array<HttpResponseRedirect>, remoteAddr: string | null, sentRequest: HttpSentRequest | null, status: number, statusReason: string | null, state: HttpResponseState, timings: HttpResponseTimings, tlsInfo: HttpResponseTlsInfo | null, 
+/**
+ * Whether the body was cut off, because it was longer than the max response size
+ */
+truncated: boolean, 
+/**
+ * Total size of the request body files being uploaded, when the body is streamed from disk
+ */
+uploadSize: number | null, url: string, version: string | null, };
+
+/**
+ * One attempt at sending a request that has retries enabled
+ */
+export type HttpResponseAttempt = { 
+/**
+ * Status of the response, or 0 when the attempt failed without one
+ */
+status: number, error: string | null, elapsed: number, 
+/**
+ * How long was waited after this attempt before making the next one, in milliseconds
+ */
+delay: number, };
 
 export type HttpResponseHeader = { name: string, value: string, };
 
+/**
+ * A redirect response that was followed on the way to the final response
+ */
+export type HttpResponseRedirect = { method: string, url: string, status: number, headers: Array<HttpResponseHeader>, };
+
 export type HttpResponseState = "initialized" | "connected" | "closed";
 
+/**
+ * How long each phase of the request took, in milliseconds. The connection phases are empty when
+ * they didn't happen, like DNS for an IP address or all of them when a connection was reused.
+ */
+export type HttpResponseTimings = { dns: number | null, connect: number | null, tls: number | null, 
+/**
+ * From the connection being ready until the response headers arrived
+ */
+ttfb: number, download: number, };
+
+export type HttpResponseTlsInfo = { version: string | null, 
+/**
+ * Name of the negotiated cipher suite, like `TLS13_AES_128_GCM_SHA256`
+ */
+cipher: string | null, 
+/**
+ * PEM encoded certificates presented by the server, starting with the leaf
+ */
+certificates: Array<string>, };
+
+/**
+ * Transport failures that a request can be retried after
+ */
+export type HttpRetryError = "connect" | "timeout" | "other";
+
+/**
+ * The request as it went on the wire, after cookies and authentication were applied. Headers
+ * that the HTTP client adds itself (eg. Host and Accept-Encoding) are not included.
+ */
+export type HttpSentRequest = { method: string, url: string, headers: Array<HttpResponseHeader>, 
+/**
+ * Unknown for streamed bodies, like large file uploads
+ */
+bodySize: number | null, bodyPreview: string | null, };
+
 export type HttpUrlParameter = { enabled?: boolean, name: string, value: string, id?: string, };
 
-export type WebsocketRequest = { model: "websocket_request", id: string, createdAt: string, updatedAt: string, workspaceId: string, folderId: string | null, authentication: Record<string, any>, authenticationType: string | null, description: string, headers: Array<HttpRequestHeader>, message: string, name: string, sortPriority: number, url: string, urlParameters: Array<HttpUrlParameter>, };
+/**
+ * HTTP version to send a request with
+ */
+export type HttpVersion = "auto" | "http1" | "http2" | "http3";
+
+export type SseRequest = { model: "sse_request", id: string, createdAt: string, updatedAt: string, workspaceId: string, folderId: string | null, authentication: Record<string, any>, authenticationType: string | null, description: string, headers: Array<HttpRequestHeader>, name: string, sortPriority: number, url: string, urlParameters: Array<HttpUrlParameter>, settingRequestTimeout: number | null, settingValidateCertificates: boolean | null, };
+
+export type WebsocketRequest = { model: "websocket_request", id: string, createdAt: string, updatedAt: string, workspaceId: string, folderId: string | null, authentication: Record<string, any>, authenticationType: string | null, description: string, headers: Array<HttpRequestHeader>, message: string, name: string, sortPriority: number, url: string, urlParameters: Array<HttpUrlParameter>, settingRequestTimeout: number | null, settingValidateCertificates: boolean | null, };
 
-export type Workspace = { model: "workspace", id: string, createdAt: string, updatedAt: string, name: string, description: string, encryptionKeyChallenge: string | null, settingValidateCertificates: boolean, settingFollowRedirects: boolean, settingRequestTimeout: number, };
+export type Workspace = { model: "workspace", id: string, createdAt: string, updatedAt: string, name: string, description: string, encryptionKeyChallenge: string | null, authentication: Record<string, any>, authenticationType: string | null, headers: Array<HttpRequestHeader>, variables: Array<EnvironmentVariable>, settingValidateCertificates: boolean, settingFollowRedirects: boolean, settingMaxRedirects: number, 
+/**
+ * Switch to GET (dropping the body) when following a 301, 302 or 303, like browsers do
+ */
+settingRedirectRewriteMethod: boolean, settingRequestTimeout: number, 
+/**
+ * Largest response body to store, in bytes. Longer bodies are truncated and their download
+ * stopped. 0 is unlimited.
+ */
+settingMaxResponseSize: number, 
+/**
+ * Certificates to present to servers that ask for one (mutual TLS), matched by host
+ */
+settingClientCertificates: Array<ClientCertificate>, 
+/**
+ * Paths to PEM bundles of extra CAs to trust, on top of the system roots
+ */
+settingCaCertificates: Array<string>, 
+/**
+ * Addresses to connect to instead of the ones DNS returns, by host
+ */
+settingDnsOverrides: Array<DnsOverride>, };
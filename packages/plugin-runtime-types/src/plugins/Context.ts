@@ -10,6 +10,7 @@ import type {
   RenderHttpRequestResponse,
   SendHttpRequestRequest,
   SendHttpRequestResponse,
+  SetEnvironmentVariableRequest,
   ShowToastRequest,
   TemplateRenderRequest,
   TemplateRenderResponse,
@@ -49,4 +50,7 @@ export interface Context {
   templates: {
     render(args: TemplateRenderRequest): Promise<TemplateRenderResponse['data']>;
   };
+  environment: {
+    setVariable(args: SetEnvironmentVariableRequest): Promise<void>;
+  };
 }
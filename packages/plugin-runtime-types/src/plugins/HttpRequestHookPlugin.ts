@@ -0,0 +1,19 @@
+import type { HttpRequest } from '../bindings/gen_models';
+import type { PostResponseHookRequest, PreRequestHookRequest } from '../bindings/gen_events';
+import type { Context } from './Context';
+
+export type HttpRequestHookPlugin = {
+  /**
+   * Called with the rendered request just before it is sent. Return a modified request to send
+   * that instead, or nothing to send it unchanged.
+   */
+  onPreRequest?(
+    ctx: Context,
+    args: PreRequestHookRequest,
+  ): Promise<HttpRequest | void> | HttpRequest | void;
+
+  /**
+   * Called after the response has finished, eg. to store values from it in the environment
+   */
+  onPostResponse?(ctx: Context, args: PostResponseHookRequest): Promise<void> | void;
+};
@@ -1,6 +1,7 @@
 import { AuthenticationPlugin } from './AuthenticationPlugin';
 import type { FilterPlugin } from './FilterPlugin';
 import type { HttpRequestActionPlugin } from './HttpRequestActionPlugin';
+import type { HttpRequestHookPlugin } from './HttpRequestHookPlugin';
 import type { ImporterPlugin } from './ImporterPlugin';
 import type { TemplateFunctionPlugin } from './TemplateFunctionPlugin';
 import type { ThemePlugin } from './ThemePlugin';
@@ -16,5 +17,6 @@ export type PluginDefinition = {
   filter?: FilterPlugin;
   authentication?: AuthenticationPlugin;
   httpRequestActions?: HttpRequestActionPlugin[];
+  httpRequestHooks?: HttpRequestHookPlugin;
   templateFunctions?: TemplateFunctionPlugin[];
 };
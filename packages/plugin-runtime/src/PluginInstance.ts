@@ -226,6 +226,8 @@ export class PluginInstance {
             {
               type: 'call_http_authentication_response',
               setHeaders: result.setHeaders,
+              setQueryParameters: result.setQueryParameters,
+              setUrl: result.setUrl,
             },
             replyId,
           );
@@ -277,6 +279,31 @@ export class PluginInstance {
         }
       }
 
+      if (
+        payload.type === 'pre_request_hook_request' &&
+        typeof this.#mod?.httpRequestHooks?.onPreRequest === 'function'
+      ) {
+        const httpRequest = await this.#mod.httpRequestHooks.onPreRequest(ctx, payload);
+        this.#sendPayload(
+          windowContext,
+          {
+            type: 'pre_request_hook_response',
+            httpRequest: httpRequest ?? payload.httpRequest,
+          },
+          replyId,
+        );
+        return;
+      }
+
+      if (
+        payload.type === 'post_response_hook_request' &&
+        typeof this.#mod?.httpRequestHooks?.onPostResponse === 'function'
+      ) {
+        await this.#mod.httpRequestHooks.onPostResponse(ctx, payload);
+        this.#sendPayload(windowContext, { type: 'post_response_hook_response' }, replyId);
+        return;
+      }
+
       if (payload.type === 'reload_request') {
         this.#importModule();
       }
@@ -536,6 +563,12 @@ export class PluginInstance {
           return result.deleted;
         },
       },
+      environment: {
+        setVariable: async (args) => {
+          const payload = { type: 'set_environment_variable_request', ...args } as const;
+          await this.#sendAndWaitForReply(event.windowContext, payload);
+        },
+      },
     };
   }
 }
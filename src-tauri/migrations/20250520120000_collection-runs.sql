@@ -0,0 +1,20 @@
+CREATE TABLE collection_runs
+(
+    id              TEXT                               NOT NULL
+        PRIMARY KEY,
+    model           TEXT     DEFAULT 'collection_run'  NOT NULL,
+    workspace_id    TEXT                               NOT NULL
+        REFERENCES workspaces
+            ON DELETE CASCADE,
+    created_at      DATETIME DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    updated_at      DATETIME DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    folder_id       TEXT,
+    environment_id  TEXT,
+    cookie_jar_id   TEXT,
+    delay           INTEGER  DEFAULT 0                 NOT NULL,
+    elapsed         INTEGER  DEFAULT 0                 NOT NULL,
+    iterations      INTEGER  DEFAULT 1                 NOT NULL,
+    results         TEXT     DEFAULT '[]'              NOT NULL,
+    state           TEXT                               NOT NULL,
+    stop_on_failure BOOLEAN  DEFAULT FALSE             NOT NULL
+);
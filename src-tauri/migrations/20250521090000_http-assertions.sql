@@ -0,0 +1,7 @@
+-- Assertions are checked against every response to the request
+ALTER TABLE http_requests
+    ADD COLUMN assertions TEXT DEFAULT '[]' NOT NULL;
+
+-- Results of evaluating the request's assertions, stored alongside the response they were run on
+ALTER TABLE http_responses
+    ADD COLUMN assertion_results TEXT DEFAULT '[]' NOT NULL;
@@ -0,0 +1,18 @@
+-- Headers, authentication and variables that are inherited by requests in a folder or workspace
+ALTER TABLE folders
+    ADD COLUMN headers TEXT DEFAULT '[]' NOT NULL;
+ALTER TABLE folders
+    ADD COLUMN authentication TEXT DEFAULT '{}' NOT NULL;
+ALTER TABLE folders
+    ADD COLUMN authentication_type TEXT NULL;
+ALTER TABLE folders
+    ADD COLUMN variables TEXT DEFAULT '[]' NOT NULL;
+
+ALTER TABLE workspaces
+    ADD COLUMN headers TEXT DEFAULT '[]' NOT NULL;
+ALTER TABLE workspaces
+    ADD COLUMN authentication TEXT DEFAULT '{}' NOT NULL;
+ALTER TABLE workspaces
+    ADD COLUMN authentication_type TEXT NULL;
+ALTER TABLE workspaces
+    ADD COLUMN variables TEXT DEFAULT '[]' NOT NULL;
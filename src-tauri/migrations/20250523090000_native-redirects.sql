@@ -0,0 +1,8 @@
+ALTER TABLE workspaces
+    ADD COLUMN setting_max_redirects INTEGER DEFAULT 10 NOT NULL;
+ALTER TABLE workspaces
+    ADD COLUMN setting_redirect_rewrite_method BOOLEAN DEFAULT TRUE NOT NULL;
+
+-- Each redirect hop that was followed before arriving at the final response
+ALTER TABLE http_responses
+    ADD COLUMN redirects TEXT DEFAULT '[]' NOT NULL;
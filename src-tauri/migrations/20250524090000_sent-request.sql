@@ -0,0 +1,3 @@
+-- The final request that was sent, including headers added after rendering (cookies, auth, etc)
+ALTER TABLE http_responses
+    ADD COLUMN sent_request TEXT DEFAULT 'null' NOT NULL;
@@ -0,0 +1,7 @@
+-- Breakdown of where the time was spent (DNS, connect, TLS, waiting and downloading)
+ALTER TABLE http_responses
+    ADD COLUMN timings TEXT DEFAULT '{}' NOT NULL;
+
+-- Negotiated TLS version and the certificate chain presented by the server
+ALTER TABLE http_responses
+    ADD COLUMN tls_info TEXT DEFAULT 'null' NOT NULL;
@@ -0,0 +1,4 @@
+ALTER TABLE workspaces
+    ADD COLUMN setting_client_certificates TEXT DEFAULT '[]' NOT NULL;
+ALTER TABLE workspaces
+    ADD COLUMN setting_ca_certificates TEXT DEFAULT '[]' NOT NULL;
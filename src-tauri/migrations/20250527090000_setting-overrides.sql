@@ -0,0 +1,24 @@
+-- Request settings that override the workspace's. NULL inherits from the parent folder or workspace.
+ALTER TABLE folders
+    ADD COLUMN setting_follow_redirects BOOLEAN NULL;
+ALTER TABLE folders
+    ADD COLUMN setting_request_timeout INTEGER NULL;
+ALTER TABLE folders
+    ADD COLUMN setting_validate_certificates BOOLEAN NULL;
+
+ALTER TABLE http_requests
+    ADD COLUMN setting_follow_redirects BOOLEAN NULL;
+ALTER TABLE http_requests
+    ADD COLUMN setting_request_timeout INTEGER NULL;
+ALTER TABLE http_requests
+    ADD COLUMN setting_validate_certificates BOOLEAN NULL;
+
+ALTER TABLE grpc_requests
+    ADD COLUMN setting_request_timeout INTEGER NULL;
+ALTER TABLE grpc_requests
+    ADD COLUMN setting_validate_certificates BOOLEAN NULL;
+
+ALTER TABLE websocket_requests
+    ADD COLUMN setting_request_timeout INTEGER NULL;
+ALTER TABLE websocket_requests
+    ADD COLUMN setting_validate_certificates BOOLEAN NULL;
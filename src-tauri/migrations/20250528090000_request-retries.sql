@@ -0,0 +1,6 @@
+ALTER TABLE http_requests
+    ADD COLUMN retry TEXT DEFAULT '{}' NOT NULL;
+
+-- Each attempt that was made, when the request has retries enabled
+ALTER TABLE http_responses
+    ADD COLUMN attempts TEXT DEFAULT '[]' NOT NULL;
@@ -0,0 +1,5 @@
+-- Progress of request bodies that are streamed from disk while uploading
+ALTER TABLE http_responses
+    ADD COLUMN bytes_uploaded INTEGER DEFAULT 0 NOT NULL;
+ALTER TABLE http_responses
+    ADD COLUMN upload_size INTEGER NULL;
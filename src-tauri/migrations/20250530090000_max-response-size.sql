@@ -0,0 +1,10 @@
+-- Largest response body to store, in bytes. 0 is unlimited, and NULL inherits from the parent.
+ALTER TABLE workspaces
+    ADD COLUMN setting_max_response_size INTEGER DEFAULT 0 NOT NULL;
+ALTER TABLE folders
+    ADD COLUMN setting_max_response_size INTEGER NULL;
+ALTER TABLE http_requests
+    ADD COLUMN setting_max_response_size INTEGER NULL;
+
+ALTER TABLE http_responses
+    ADD COLUMN truncated BOOLEAN DEFAULT FALSE NOT NULL;
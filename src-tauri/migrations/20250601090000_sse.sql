@@ -0,0 +1,68 @@
+CREATE TABLE sse_requests
+(
+    id                            TEXT                               NOT NULL
+        PRIMARY KEY,
+    model                         TEXT     DEFAULT 'sse_request'     NOT NULL,
+    workspace_id                  TEXT                               NOT NULL
+        REFERENCES workspaces
+            ON DELETE CASCADE,
+    folder_id                     TEXT,
+    created_at                    DATETIME DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    updated_at                    DATETIME DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    deleted_at                    DATETIME,
+    authentication                TEXT     DEFAULT '{}'              NOT NULL,
+    authentication_type           TEXT,
+    description                   TEXT                               NOT NULL,
+    name                          TEXT                               NOT NULL,
+    url                           TEXT                               NOT NULL,
+    headers                       TEXT                               NOT NULL,
+    sort_priority                 REAL                               NOT NULL,
+    url_parameters                TEXT     DEFAULT '[]'              NOT NULL,
+    setting_request_timeout       INTEGER                            NULL,
+    setting_validate_certificates BOOLEAN                            NULL
+);
+
+CREATE TABLE sse_connections
+(
+    id            TEXT                               NOT NULL
+        PRIMARY KEY,
+    model         TEXT     DEFAULT 'sse_connection'  NOT NULL,
+    workspace_id  TEXT                               NOT NULL
+        REFERENCES workspaces
+            ON DELETE CASCADE,
+    request_id    TEXT                               NOT NULL
+        REFERENCES sse_requests
+            ON DELETE CASCADE,
+    created_at    DATETIME DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    updated_at    DATETIME DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    url           TEXT                               NOT NULL,
+    state         TEXT                               NOT NULL,
+    status        INTEGER  DEFAULT -1                NOT NULL,
+    error         TEXT                               NULL,
+    elapsed       INTEGER  DEFAULT 0                 NOT NULL,
+    headers       TEXT     DEFAULT '[]'              NOT NULL,
+    last_event_id TEXT                               NULL,
+    reconnects    INTEGER  DEFAULT 0                 NOT NULL
+);
+
+CREATE TABLE sse_events
+(
+    id            TEXT                                                    NOT NULL
+        PRIMARY KEY,
+    model         TEXT     DEFAULT 'sse_event'                            NOT NULL,
+    workspace_id  TEXT                                                    NOT NULL
+        REFERENCES workspaces
+            ON DELETE CASCADE,
+    request_id    TEXT                                                    NOT NULL
+        REFERENCES sse_requests
+            ON DELETE CASCADE,
+    connection_id TEXT                                                    NOT NULL
+        REFERENCES sse_connections
+            ON DELETE CASCADE,
+    created_at    DATETIME DEFAULT (STRFTIME('%Y-%m-%d %H:%M:%f', 'NOW')) NOT NULL,
+    updated_at    DATETIME DEFAULT (STRFTIME('%Y-%m-%d %H:%M:%f', 'NOW')) NOT NULL,
+    data          TEXT                                                    NOT NULL,
+    event_id      TEXT                                                    NULL,
+    event_type    TEXT                                                    NOT NULL,
+    retry         INTEGER                                                 NULL
+);
@@ -0,0 +1,2 @@
+ALTER TABLE workspaces
+    ADD COLUMN setting_dns_overrides TEXT DEFAULT '[]' NOT NULL;
@@ -0,0 +1,3 @@
+-- HTTP version to send the request with, or 'auto' to negotiate it
+ALTER TABLE http_requests
+    ADD COLUMN http_version TEXT DEFAULT 'auto' NOT NULL;
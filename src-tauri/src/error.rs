@@ -19,6 +19,9 @@ pub enum Error {
     #[error(transparent)]
     GitError(#[from] yaak_git::error::Error),
 
+    #[error(transparent)]
+    HttpError(#[from] yaak_http::error::Error),
+
     #[error(transparent)]
     WebsocketError(#[from] yaak_ws::error::Error),
 
@@ -28,6 +31,9 @@ pub enum Error {
     #[error(transparent)]
     PluginError(#[from] yaak_plugins::error::Error),
 
+    #[error(transparent)]
+    ProxyError(#[from] yaak_proxy::error::Error),
+
     #[error("Updater error: {0}")]
     UpdaterError(#[from] tauri_plugin_updater::Error),
     
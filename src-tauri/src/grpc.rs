@@ -1,12 +1,71 @@
 use std::collections::BTreeMap;
+use std::time::Duration;
 
 use crate::error::Result;
 use KeyAndValueRef::{Ascii, Binary};
+use log::warn;
 use tauri::{Manager, Runtime, WebviewWindow};
 use yaak_grpc::{KeyAndValueRef, MetadataMap};
-use yaak_models::models::GrpcRequest;
-use yaak_plugins::events::{CallHttpAuthenticationRequest, HttpHeader};
-use yaak_plugins::manager::PluginManager;
+use yaak_http::auth::{AuthenticationCallback, AuthenticationRequest, AuthenticationRequestType};
+use yaak_http::render::render_dns_overrides;
+use yaak_models::models::{Environment, GrpcRequest};
+use yaak_models::query_manager::QueryManagerExt;
+use yaak_models::render::InheritedSettings;
+use yaak_plugins::auth_callback::PluginAuthenticationCallback;
+use yaak_plugins::events::{PluginWindowContext, RenderPurpose};
+use yaak_plugins::template_callback::PluginTemplateCallback;
+use yaak_proxy::config::ProxyConfig;
+use yaak_proxy::dns::DnsOverrides;
+use yaak_tls::config::TlsSettings;
+
+/// How to connect for a gRPC request, from its workspace (with any overrides from its folders)
+/// and the app's proxy setting
+pub(crate) struct GrpcConnectionSettings {
+    pub tls: TlsSettings,
+    pub proxy: ProxyConfig,
+    pub dns_overrides: DnsOverrides,
+    pub connect_timeout: Option<Duration>,
+}
+
+pub(crate) async fn grpc_connection_settings<R: Runtime>(
+    window: &WebviewWindow<R>,
+    unrendered_request: &GrpcRequest,
+    base_environment: &Environment,
+    environment: Option<&Environment>,
+    inherited: &InheritedSettings,
+) -> Result<GrpcConnectionSettings> {
+    let app_handle = window.app_handle();
+    let workspace = inherited.resolve_workspace(
+        &app_handle.db().get_workspace(&unrendered_request.workspace_id)?,
+        unrendered_request,
+    );
+    let proxy = ProxyConfig::load(app_handle.db().get_settings().proxy).await?;
+    let dns_overrides = render_dns_overrides(
+        &workspace,
+        base_environment,
+        environment,
+        inherited,
+        &PluginTemplateCallback::new(
+            app_handle,
+            &PluginWindowContext::new(window),
+            RenderPurpose::Send,
+        ),
+    )
+    .await?;
+    let connect_timeout = match workspace.setting_request_timeout > 0 {
+        true => {
+            Some(Duration::from_millis(workspace.setting_request_timeout.unsigned_abs() as u64))
+        }
+        false => None,
+    };
+
+    Ok(GrpcConnectionSettings {
+        tls: TlsSettings::from_workspace(&workspace),
+        proxy,
+        dns_overrides,
+        connect_timeout,
+    })
+}
 
 pub(crate) fn metadata_to_map(metadata: MetadataMap) -> BTreeMap<String, String> {
     let mut entries = BTreeMap::new();
@@ -23,7 +82,6 @@ pub(crate) async fn build_metadata<R: Runtime>(
     window: &WebviewWindow<R>,
     request: &GrpcRequest,
 ) -> Result<BTreeMap<String, String>> {
-    let plugin_manager = window.state::<PluginManager>();
     let mut metadata = BTreeMap::new();
 
     // Add the rest of metadata
@@ -40,24 +98,22 @@ pub(crate) async fn build_metadata<R: Runtime>(
     }
 
     if let Some(auth_name) = request.authentication_type.clone() {
-        let auth = request.authentication.clone();
-        let plugin_req = CallHttpAuthenticationRequest {
+        let auth_req = AuthenticationRequest {
             context_id: format!("{:x}", md5::compute(request.id.clone())),
-            values: serde_json::from_value(serde_json::to_value(&auth).unwrap()).unwrap(),
+            request_type: AuthenticationRequestType::Grpc,
+            values: request.authentication.clone(),
             method: "POST".to_string(),
             url: request.url.clone(),
-            headers: metadata
-                .iter()
-                .map(|(name, value)| HttpHeader {
-                    name: name.to_string(),
-                    value: value.to_string(),
-                })
-                .collect(),
+            headers: metadata.iter().map(|(n, v)| (n.to_string(), v.to_string())).collect(),
+            body: None,
         };
-        let plugin_result =
-            plugin_manager.call_http_authentication(&window, &auth_name, plugin_req).await?;
-        for header in plugin_result.set_headers {
-            metadata.insert(header.name, header.value);
+        let result =
+            PluginAuthenticationCallback::new(window).authenticate(&auth_name, auth_req).await?;
+        if result.set_url.is_some() || !result.set_query_parameters.is_empty() {
+            warn!("Ignoring URL changes from {auth_name:?} auth, which gRPC doesn't support");
+        }
+        for (name, value) in result.set_headers {
+            metadata.insert(name, value);
         }
     }
 
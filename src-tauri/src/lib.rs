@@ -1,8 +1,8 @@
 extern crate core;
 use crate::encoding::read_response_body;
 use crate::error::Error::GenericError;
-use crate::grpc::{build_metadata, metadata_to_map};
-use crate::http_request::send_http_request;
+use crate::grpc::{build_metadata, grpc_connection_settings, metadata_to_map};
+use crate::http_request::{connect_sse_request, run_http_collection, send_http_request};
 use crate::notifications::YaakNotifier;
 use crate::render::{render_grpc_request, render_template};
 use crate::updates::{UpdateMode, UpdateTrigger, YaakUpdater};
@@ -28,17 +28,18 @@ use tokio::task::block_in_place;
 use yaak_common::window::WorkspaceWindowTrait;
 use yaak_grpc::manager::{DynamicMessage, GrpcHandle};
 use yaak_grpc::{Code, ServiceDefinition, deserialize_message, serialize_message};
+use yaak_http::client_pool::{HTTP3_SUPPORTED, HttpClientPool};
 use yaak_models::models::{
-    CookieJar, Environment, Folder, GrpcConnection, GrpcConnectionState, GrpcEvent, GrpcEventType,
-    GrpcRequest, HttpRequest, HttpResponse, HttpResponseState, Plugin, WebsocketRequest, Workspace,
-    WorkspaceMeta,
+    CollectionRun, CookieJar, Environment, Folder, GrpcConnection, GrpcConnectionState, GrpcEvent,
+    GrpcEventType, GrpcRequest, HttpRequest, HttpResponse, Plugin, SseConnection, SseRequest,
+    WebsocketRequest, Workspace, WorkspaceMeta,
 };
 use yaak_models::query_manager::QueryManagerExt;
 use yaak_models::util::{
     BatchUpsertResult, UpdateSource, get_workspace_export_resources, maybe_gen_id, maybe_gen_id_opt,
 };
 use yaak_plugins::events::{
-    BootResponse, CallHttpRequestActionRequest, FilterResponse,
+    AuthenticationRequestType, BootResponse, CallHttpRequestActionRequest, FilterResponse,
     GetHttpAuthenticationConfigResponse, GetHttpAuthenticationSummaryResponse,
     GetHttpRequestActionsResponse, GetTemplateFunctionsResponse, InternalEvent,
     InternalEventPayload, JsonPrimitive, PluginWindowContext, RenderPurpose,
@@ -71,6 +72,7 @@ struct AppMetaData {
     name: String,
     app_data_dir: String,
     app_log_dir: String,
+    supports_http3: bool,
 }
 
 #[tauri::command]
@@ -83,6 +85,7 @@ async fn cmd_metadata(app_handle: AppHandle) -> YaakResult<AppMetaData> {
         name: app_handle.package_info().name.to_string(),
         app_data_dir: app_data_dir.to_string_lossy().to_string(),
         app_log_dir: app_log_dir.to_string_lossy().to_string(),
+        supports_http3: HTTP3_SUPPORTED,
     })
 }
 
@@ -153,10 +156,15 @@ async fn cmd_grpc_reflect<R: Runtime>(
     let unrendered_request = app_handle.db().get_grpc_request(request_id)?;
     let base_environment =
         app_handle.db().get_base_environment(&unrendered_request.workspace_id)?;
+    let inherited = app_handle.db().resolve_inherited_settings(
+        &unrendered_request.workspace_id,
+        unrendered_request.folder_id.as_deref(),
+    )?;
     let req = render_grpc_request(
         &unrendered_request,
         &base_environment,
         environment.as_ref(),
+        &inherited,
         &PluginTemplateCallback::new(
             &app_handle,
             &PluginWindowContext::new(&window),
@@ -167,6 +175,14 @@ async fn cmd_grpc_reflect<R: Runtime>(
 
     let uri = safe_uri(&req.url);
     let metadata = build_metadata(&window, &req).await?;
+    let settings = grpc_connection_settings(
+        &window,
+        &unrendered_request,
+        &base_environment,
+        environment.as_ref(),
+        &inherited,
+    )
+    .await?;
 
     Ok(grpc_handle
         .lock()
@@ -176,6 +192,10 @@ async fn cmd_grpc_reflect<R: Runtime>(
             &uri,
             &proto_files.iter().map(|p| PathBuf::from_str(p).unwrap()).collect(),
             &metadata,
+            &settings.tls,
+            &settings.proxy,
+            &settings.dns_overrides,
+            settings.connect_timeout,
         )
         .await
         .map_err(|e| GenericError(e.to_string()))?)
@@ -197,10 +217,15 @@ async fn cmd_grpc_go<R: Runtime>(
     let unrendered_request = app_handle.db().get_grpc_request(request_id)?;
     let base_environment =
         app_handle.db().get_base_environment(&unrendered_request.workspace_id)?;
+    let inherited = app_handle.db().resolve_inherited_settings(
+        &unrendered_request.workspace_id,
+        unrendered_request.folder_id.as_deref(),
+    )?;
     let request = render_grpc_request(
         &unrendered_request,
         &base_environment,
         environment.as_ref(),
+        &inherited,
         &PluginTemplateCallback::new(
             &app_handle,
             &PluginWindowContext::new(&window),
@@ -249,6 +274,14 @@ async fn cmd_grpc_go<R: Runtime>(
         }
     };
 
+    let settings = grpc_connection_settings(
+        &window,
+        &unrendered_request,
+        &base_environment,
+        environment.as_ref(),
+        &inherited,
+    )
+    .await?;
     let start = std::time::Instant::now();
     let connection = grpc_handle
         .lock()
@@ -258,6 +291,10 @@ async fn cmd_grpc_go<R: Runtime>(
             uri.as_str(),
             &proto_files.iter().map(|p| PathBuf::from_str(p).unwrap()).collect(),
             &metadata,
+            &settings.tls,
+            &settings.proxy,
+            &settings.dns_overrides,
+            settings.connect_timeout,
         )
         .await;
 
@@ -707,7 +744,8 @@ async fn cmd_send_ephemeral_request<R: Runtime>(
         }
     });
 
-    send_http_request(&window, &request, &response, environment, cookie_jar, &mut cancel_rx).await
+    send_http_request(&window, &request, &response, environment, cookie_jar, false, &mut cancel_rx)
+        .await
 }
 
 #[tauri::command]
@@ -729,6 +767,11 @@ async fn cmd_filter_response<R: Runtime>(
         return Err(GenericError("Response body path not set".to_string()));
     }
 
+    // Filtering a partial JSON or XML document would give misleading results
+    if response.truncated {
+        return Err(GenericError("Can't filter a truncated response body".to_string()));
+    }
+
     let mut content_type = "".to_string();
     for header in response.headers.iter() {
         if header.name.to_lowercase() == "content-type" {
@@ -846,6 +889,17 @@ async fn cmd_import_data<R: Runtime>(
         })
         .collect();
 
+    let sse_requests: Vec<SseRequest> = resources
+        .sse_requests
+        .into_iter()
+        .map(|mut v| {
+            v.id = maybe_gen_id::<SseRequest>(v.id.as_str(), &mut id_map);
+            v.workspace_id = maybe_gen_id::<Workspace>(v.workspace_id.as_str(), &mut id_map);
+            v.folder_id = maybe_gen_id_opt::<Folder>(v.folder_id, &mut id_map);
+            v
+        })
+        .collect();
+
     info!("Importing data");
 
     let upserted = app_handle.with_tx(|tx| {
@@ -856,6 +910,7 @@ async fn cmd_import_data<R: Runtime>(
             http_requests,
             grpc_requests,
             websocket_requests,
+            sse_requests,
             &UpdateSource::Import,
         )
     })?;
@@ -895,9 +950,10 @@ async fn cmd_get_http_authentication_config<R: Runtime>(
     auth_name: &str,
     values: HashMap<String, JsonPrimitive>,
     request_id: &str,
+    request_type: AuthenticationRequestType,
 ) -> YaakResult<GetHttpAuthenticationConfigResponse> {
     Ok(plugin_manager
-        .get_http_authentication_config(&window, auth_name, values, request_id)
+        .get_http_authentication_config(&window, auth_name, values, request_id, request_type)
         .await?)
 }
 
@@ -918,9 +974,17 @@ async fn cmd_call_http_authentication_action<R: Runtime>(
     action_index: i32,
     values: HashMap<String, JsonPrimitive>,
     request_id: &str,
+    request_type: AuthenticationRequestType,
 ) -> YaakResult<()> {
     Ok(plugin_manager
-        .call_http_authentication_action(&window, auth_name, action_index, values, request_id)
+        .call_http_authentication_action(
+            &window,
+            auth_name,
+            action_index,
+            values,
+            request_id,
+            request_type,
+        )
         .await?)
 }
 
@@ -996,6 +1060,7 @@ async fn cmd_send_http_request<R: Runtime>(
     //   condition where the user may have just edited a field before sending
     //   that has not yet been saved in the DB.
     request: HttpRequest,
+    fresh_connection: Option<bool>,
 ) -> YaakResult<HttpResponse> {
     let response = app_handle.db().upsert_http_response(
         &HttpResponse {
@@ -1029,24 +1094,89 @@ async fn cmd_send_http_request<R: Runtime>(
         None => None,
     };
 
-    send_http_request(&window, &request, &response, environment, cookie_jar, &mut cancel_rx).await
+    send_http_request(
+        &window,
+        &request,
+        &response,
+        environment,
+        cookie_jar,
+        fresh_connection.unwrap_or(false),
+        &mut cancel_rx,
+    )
+    .await
 }
 
-fn response_err<R: Runtime>(
-    app_handle: &AppHandle<R>,
-    response: &HttpResponse,
-    error: String,
-    update_source: &UpdateSource,
-) -> HttpResponse {
-    warn!("Failed to send request: {error:?}");
-    let mut response = response.clone();
-    response.state = HttpResponseState::Closed;
-    response.error = Some(error.clone());
-    response = app_handle
-        .db()
-        .update_http_response_if_id(&response, update_source)
-        .expect("Failed to update response");
-    response
+#[tauri::command]
+async fn cmd_sse_connect<R: Runtime>(
+    app_handle: AppHandle<R>,
+    window: WebviewWindow<R>,
+    environment_id: Option<&str>,
+    // NOTE: Received in full for the same reason as cmd_send_http_request
+    request: SseRequest,
+) -> YaakResult<SseConnection> {
+    let connection = app_handle.db().upsert_sse_connection(
+        &SseConnection {
+            request_id: request.id.clone(),
+            workspace_id: request.workspace_id.clone(),
+            ..Default::default()
+        },
+        &UpdateSource::from_window(&window),
+    )?;
+
+    let (close_tx, mut close_rx) = tokio::sync::watch::channel(false);
+    app_handle.listen_any(format!("close_sse_connection_{}", connection.id), move |_event| {
+        if let Err(e) = close_tx.send(true) {
+            warn!("Failed to send close event for SSE connection {e:?}");
+        }
+    });
+
+    let environment = match environment_id {
+        Some(id) => Some(app_handle.db().get_environment(id)?),
+        None => None,
+    };
+
+    connect_sse_request(&window, &request, &connection, environment, &mut close_rx).await
+}
+
+#[tauri::command]
+async fn cmd_run_collection<R: Runtime>(
+    app_handle: AppHandle<R>,
+    window: WebviewWindow<R>,
+    workspace_id: &str,
+    folder_id: Option<&str>,
+    environment_id: Option<&str>,
+    cookie_jar_id: Option<&str>,
+    iterations: i32,
+    delay: i32,
+    stop_on_failure: bool,
+) -> YaakResult<CollectionRun> {
+    let run = app_handle.db().upsert_collection_run(
+        &CollectionRun {
+            workspace_id: workspace_id.to_string(),
+            folder_id: folder_id.map(|s| s.to_string()),
+            environment_id: environment_id.map(|s| s.to_string()),
+            cookie_jar_id: cookie_jar_id.map(|s| s.to_string()),
+            iterations,
+            delay,
+            stop_on_failure,
+            ..Default::default()
+        },
+        &UpdateSource::from_window(&window),
+    )?;
+
+    let (cancel_tx, mut cancel_rx) = tokio::sync::watch::channel(false);
+    app_handle.listen_any(format!("cancel_collection_run_{}", run.id), move |_event| {
+        if let Err(e) = cancel_tx.send(true) {
+            warn!("Failed to send cancel event for collection run {e:?}");
+        }
+    });
+
+    let environment = match environment_id {
+        Some(id) => Some(app_handle.db().get_environment(id)?),
+        None => None,
+    };
+
+    run_http_collection(&window, &run, environment, &mut cancel_rx).await
 }
 
 #[tauri::command]
@@ -1141,6 +1271,17 @@ async fn cmd_delete_all_grpc_connections<R: Runtime>(
         .delete_all_grpc_connections_for_request(request_id, &UpdateSource::from_window(&window))?)
 }
 
+#[tauri::command]
+async fn cmd_delete_all_sse_connections<R: Runtime>(
+    request_id: &str,
+    app_handle: AppHandle<R>,
+    window: WebviewWindow<R>,
+) -> YaakResult<()> {
+    Ok(app_handle
+        .db()
+        .delete_all_sse_connections_for_request(request_id, &UpdateSource::from_window(&window))?)
+}
+
 #[tauri::command]
 async fn cmd_delete_send_history<R: Runtime>(
     workspace_id: &str,
@@ -1152,6 +1293,7 @@ async fn cmd_delete_send_history<R: Runtime>(
         tx.delete_all_http_responses_for_workspace(workspace_id, source)?;
         tx.delete_all_grpc_connections_for_workspace(workspace_id, source)?;
         tx.delete_all_websocket_connections_for_workspace(workspace_id, source)?;
+        tx.delete_all_sse_connections_for_workspace(workspace_id, source)?;
         Ok(())
     })?)
 }
@@ -1277,6 +1419,9 @@ pub fn run() {
             let grpc_handle = GrpcHandle::new(&app.app_handle());
             app.manage(Mutex::new(grpc_handle));
 
+            // Add HTTP client pool, so connections are reused between sends
+            app.manage(HttpClientPool::new());
+
             monitor_plugin_events(&app.app_handle().clone());
 
             Ok(())
@@ -1289,6 +1434,7 @@ pub fn run() {
             cmd_curl_to_request,
             cmd_delete_all_grpc_connections,
             cmd_delete_all_http_responses,
+            cmd_delete_all_sse_connections,
             cmd_delete_send_history,
             cmd_dismiss_notification,
             cmd_export_data,
@@ -1309,9 +1455,11 @@ pub fn run() {
             cmd_plugin_info,
             cmd_reload_plugins,
             cmd_render_template,
+            cmd_run_collection,
             cmd_save_response,
             cmd_send_ephemeral_request,
             cmd_send_http_request,
+            cmd_sse_connect,
             cmd_template_functions,
             cmd_template_tokens_to_string,
             cmd_uninstall_plugin,
@@ -1342,6 +1490,8 @@ pub fn run() {
                         let _ = db.cancel_pending_http_responses();
                         let _ = db.cancel_pending_grpc_connections();
                         let _ = db.cancel_pending_websocket_connections();
+                        let _ = db.cancel_pending_sse_connections();
+                        let _ = db.cancel_pending_collection_runs();
                     });
                 }
                 RunEvent::WindowEvent {
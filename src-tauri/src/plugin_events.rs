@@ -1,5 +1,5 @@
 use crate::http_request::send_http_request;
-use crate::render::{render_http_request, render_json_value};
+use crate::render::render_json_value;
 use crate::window::{CreateWindowConfig, create_window};
 use crate::{
     call_frontend, cookie_jar_from_window, environment_from_window, get_window_from_window_context,
@@ -9,6 +9,7 @@ use chrono::Utc;
 use log::warn;
 use tauri::{AppHandle, Emitter, Manager, Runtime, State};
 use tauri_plugin_clipboard_manager::ClipboardExt;
+use yaak_http::render::render_http_request;
 use yaak_models::models::{HttpResponse, Plugin};
 use yaak_models::query_manager::QueryManagerExt;
 use yaak_models::util::UpdateSource;
@@ -77,11 +78,17 @@ pub(crate) async fn handle_plugin_event<R: Runtime>(
                 .db()
                 .get_base_environment(&workspace.id)
                 .expect("Failed to get base environment");
+            // Requests from plugins may not exist in the database, so fall back to no inheritance
+            let inherited = app_handle
+                .db()
+                .resolve_inherited_settings(&workspace.id, req.http_request.folder_id.as_deref())
+                .unwrap_or_default();
             let cb = PluginTemplateCallback::new(app_handle, &window_context, req.purpose);
             let http_request = render_http_request(
                 &req.http_request,
                 &base_environment,
                 environment.as_ref(),
+                &inherited,
                 &cb,
             )
             .await
@@ -269,6 +276,30 @@ pub(crate) async fn handle_plugin_event<R: Runtime>(
             let deleted = app_handle.db().delete_plugin_key_value(&name, &req.key).unwrap();
             Some(InternalEventPayload::DeleteKeyValueResponse(DeleteKeyValueResponse { deleted }))
         }
+        InternalEventPayload::SetEnvironmentVariableRequest(req) => {
+            let window = get_window_from_window_context(app_handle, &window_context)
+                .expect("Failed to find window for set environment variable");
+            let workspace =
+                workspace_from_window(&window).expect("Failed to get workspace_id from window URL");
+
+            // Use the active environment, falling back to the base one if there isn't one
+            let environment = match (req.base, environment_from_window(&window)) {
+                (Some(true), _) | (_, None) => app_handle
+                    .db()
+                    .get_base_environment(&workspace.id)
+                    .expect("Failed to get base environment"),
+                (_, Some(e)) => e,
+            };
+            if let Err(e) = app_handle.db().set_environment_variable(
+                &environment,
+                &req.name,
+                &req.value,
+                &UpdateSource::Plugin,
+            ) {
+                warn!("Failed to set environment variable {}: {e:?}", req.name);
+            }
+            Some(InternalEventPayload::SetEnvironmentVariableResponse(EmptyPayload {}))
+        }
         _ => None,
     };
 
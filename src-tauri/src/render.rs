@@ -1,10 +1,7 @@
 use serde_json::Value;
 use std::collections::{BTreeMap, HashMap};
-use yaak_http::apply_path_placeholders;
-use yaak_models::models::{
-    Environment, GrpcMetadataEntry, GrpcRequest, HttpRequest, HttpRequestHeader, HttpUrlParameter,
-};
-use yaak_models::render::make_vars_hashmap;
+use yaak_models::models::{Environment, GrpcMetadataEntry, GrpcRequest};
+use yaak_models::render::{make_request_vars_hashmap, make_vars_hashmap, InheritedSettings};
 use yaak_templates::{parse_and_render, render_json_value_raw, TemplateCallback};
 
 pub async fn render_template<T: TemplateCallback>(
@@ -31,12 +28,29 @@ pub async fn render_grpc_request<T: TemplateCallback>(
     r: &GrpcRequest,
     base_environment: &Environment,
     environment: Option<&Environment>,
+    inherited: &InheritedSettings,
     cb: &T,
 ) -> yaak_templates::error::Result<GrpcRequest> {
-    let vars = &make_vars_hashmap(base_environment, environment);
+    let vars = &make_request_vars_hashmap(base_environment, inherited, environment);
+
+    // Inherited headers are sent as metadata, and are overridden by entries with the same name
+    let mut unrendered_metadata: Vec<GrpcMetadataEntry> = inherited
+        .headers
+        .iter()
+        .filter(|h| {
+            !r.metadata.iter().any(|m| m.enabled && m.name.eq_ignore_ascii_case(&h.name))
+        })
+        .map(|h| GrpcMetadataEntry {
+            enabled: h.enabled,
+            name: h.name.clone(),
+            value: h.value.clone(),
+            id: h.id.clone(),
+        })
+        .collect();
+    unrendered_metadata.extend(r.metadata.clone());
 
     let mut metadata = Vec::new();
-    for p in r.metadata.clone() {
+    for p in unrendered_metadata {
         metadata.push(GrpcMetadataEntry {
             enabled: p.enabled,
             name: render(p.name.as_str(), vars, cb).await?,
@@ -45,8 +59,10 @@ pub async fn render_grpc_request<T: TemplateCallback>(
         })
     }
 
+    let (authentication_type, unrendered_authentication) =
+        inherited.resolve_authentication(&r.authentication_type, &r.authentication);
     let mut authentication = BTreeMap::new();
-    for (k, v) in r.authentication.clone() {
+    for (k, v) in unrendered_authentication {
         authentication.insert(k, render_json_value_raw(v, vars, cb).await?);
     }
 
@@ -56,59 +72,7 @@ pub async fn render_grpc_request<T: TemplateCallback>(
         url,
         metadata,
         authentication,
-        ..r.to_owned()
-    })
-}
-
-pub async fn render_http_request<T: TemplateCallback>(
-    r: &HttpRequest,
-    base_environment: &Environment,
-    environment: Option<&Environment>,
-    cb: &T,
-) -> yaak_templates::error::Result<HttpRequest> {
-    let vars = &make_vars_hashmap(base_environment, environment);
-
-    let mut url_parameters = Vec::new();
-    for p in r.url_parameters.clone() {
-        url_parameters.push(HttpUrlParameter {
-            enabled: p.enabled,
-            name: render(p.name.as_str(), vars, cb).await?,
-            value: render(p.value.as_str(), vars, cb).await?,
-            id: p.id,
-        })
-    }
-
-    let mut headers = Vec::new();
-    for p in r.headers.clone() {
-        headers.push(HttpRequestHeader {
-            enabled: p.enabled,
-            name: render(p.name.as_str(), vars, cb).await?,
-            value: render(p.value.as_str(), vars, cb).await?,
-            id: p.id,
-        })
-    }
-
-    let mut body = BTreeMap::new();
-    for (k, v) in r.body.clone() {
-        body.insert(k, render_json_value_raw(v, vars, cb).await?);
-    }
-
-    let mut authentication = BTreeMap::new();
-    for (k, v) in r.authentication.clone() {
-        authentication.insert(k, render_json_value_raw(v, vars, cb).await?);
-    }
-
-    let url = render(r.url.clone().as_str(), vars, cb).await?;
-
-    // This doesn't fit perfectly with the concept of "rendering" but it kind of does
-    let (url, url_parameters) = apply_path_placeholders(&url, url_parameters);
-
-    Ok(HttpRequest {
-        url,
-        url_parameters,
-        headers,
-        body,
-        authentication,
+        authentication_type,
         ..r.to_owned()
     })
 }
@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+use yaak_http::auth::{AuthenticationCallback, AuthenticationRequest, AuthenticationResult};
+use yaak_http::error::Error::AuthenticationError;
+use yaak_http::hooks::HttpHooksCallback;
+use yaak_models::models::{HttpRequest, HttpResponse};
+use yaak_templates::TemplateCallback;
+use yaak_templates::error::Error::RenderError;
+
+/// Template functions are provided by plugins, which only run inside the app, so the CLI
+/// can only render variables.
+#[derive(Clone, Default)]
+pub struct CliTemplateCallback;
+
+impl TemplateCallback for CliTemplateCallback {
+    async fn run(
+        &self,
+        fn_name: &str,
+        _args: HashMap<String, String>,
+    ) -> yaak_templates::error::Result<String> {
+        Err(RenderError(format!("Template function {fn_name}() is not available in the CLI")))
+    }
+
+    fn transform_arg(
+        &self,
+        _fn_name: &str,
+        _arg_name: &str,
+        arg_value: &str,
+    ) -> yaak_templates::error::Result<String> {
+        Ok(arg_value.to_string())
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct CliAuthenticationCallback;
+
+impl AuthenticationCallback for CliAuthenticationCallback {
+    async fn authenticate(
+        &self,
+        auth_name: &str,
+        req: AuthenticationRequest,
+    ) -> yaak_http::error::Result<AuthenticationResult> {
+        // Same as the app, skip disabled auth instead of failing
+        if let Some(serde_json::Value::Bool(true)) = req.values.get("disabled") {
+            return Ok(AuthenticationResult::default());
+        }
+
+        Err(AuthenticationError(format!(
+            "Authentication type \"{auth_name}\" requires plugins, which are not available in the CLI"
+        )))
+    }
+}
+
+/// Request hooks are provided by plugins too, so the CLI sends requests unchanged
+#[derive(Clone, Default)]
+pub struct CliHooksCallback;
+
+impl HttpHooksCallback for CliHooksCallback {
+    async fn pre_request(&self, req: HttpRequest) -> yaak_http::error::Result<HttpRequest> {
+        Ok(req)
+    }
+
+    async fn post_response(
+        &self,
+        _req: &HttpRequest,
+        _resp: &HttpResponse,
+    ) -> yaak_http::error::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,150 @@
+use crate::callbacks::{CliAuthenticationCallback, CliHooksCallback, CliTemplateCallback};
+use crate::error::Result;
+use clap::Args;
+use log::warn;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tokio::sync::mpsc;
+use yaak_http::client_pool::HttpClientPool;
+use yaak_http::sender::HttpSender;
+use yaak_models::db_context::DbContext;
+use yaak_models::query_manager::QueryManager;
+use yaak_models::util::{UpdateSource, generate_id};
+use yaak_sync::models::SyncModel;
+
+#[derive(Args, Debug)]
+pub struct SourceArgs {
+    /// Path to a Yaak database (db.sqlite in the app data directory)
+    #[arg(
+        long,
+        required_unless_present = "sync_dir",
+        conflicts_with = "sync_dir"
+    )]
+    db: Option<PathBuf>,
+
+    /// Path to a directory synced with Yaak's filesystem sync
+    #[arg(long)]
+    sync_dir: Option<PathBuf>,
+}
+
+pub struct CliContext {
+    pub query_manager: QueryManager,
+    pub response_dir: PathBuf,
+    client_pool: HttpClientPool,
+    temp_db_path: Option<PathBuf>,
+}
+
+impl CliContext {
+    pub async fn open(args: &SourceArgs) -> Result<CliContext> {
+        let (db_path, temp_db_path) = match (&args.db, &args.sync_dir) {
+            (Some(db), _) => (db.clone(), None),
+            (None, _) => {
+                // Sync directories have no database, so load them into a throwaway one
+                let p = std::env::temp_dir().join(format!("yaak-cli-{}.sqlite", generate_id()));
+                (p.clone(), Some(p))
+            }
+        };
+
+        let (query_manager, events_rx) = yaak_models::init_standalone(&db_path).await?;
+        drain_model_events(events_rx);
+
+        if let Some(dir) = &args.sync_dir {
+            load_sync_dir(&query_manager.connect(), dir)?;
+        }
+
+        // Store responses next to the database, like the app does
+        let response_dir = match &temp_db_path {
+            None => db_path.parent().unwrap_or(Path::new(".")).join("responses"),
+            Some(_) => std::env::temp_dir().join("yaak-cli-responses"),
+        };
+
+        Ok(CliContext {
+            query_manager,
+            response_dir,
+            client_pool: HttpClientPool::new(),
+            temp_db_path,
+        })
+    }
+
+    pub fn http_sender(
+        &self,
+    ) -> HttpSender<CliTemplateCallback, CliAuthenticationCallback, CliHooksCallback> {
+        HttpSender {
+            query_manager: self.query_manager.clone(),
+            response_dir: self.response_dir.clone(),
+            update_source: UpdateSource::Background,
+            client_pool: self.client_pool.clone(),
+            fresh_connection: false,
+            template_callback: CliTemplateCallback,
+            auth_callback: CliAuthenticationCallback,
+            hooks_callback: CliHooksCallback,
+        }
+    }
+
+    pub fn db(&self) -> DbContext<'_> {
+        self.query_manager.connect()
+    }
+
+    pub fn close(self) {
+        if let Some(p) = self.temp_db_path {
+            if let Err(e) = fs::remove_file(&p) {
+                warn!("Failed to remove temporary database {p:?}: {e}");
+            }
+        }
+    }
+}
+
+/// Nothing is listening for model changes, but the channel needs to be emptied so that it
+/// does not fill up.
+fn drain_model_events<T: Send + 'static>(mut rx: mpsc::Receiver<T>) {
+    tokio::spawn(async move { while rx.recv().await.is_some() {} });
+}
+
+fn load_sync_dir(db: &DbContext, dir: &Path) -> Result<()> {
+    let mut workspaces = Vec::new();
+    let mut environments = Vec::new();
+    let mut folders = Vec::new();
+    let mut http_requests = Vec::new();
+    let mut grpc_requests = Vec::new();
+    let mut websocket_requests = Vec::new();
+    let mut sse_requests = Vec::new();
+
+    // Walk subdirectories too, skipping hidden ones like .git
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        for entry in fs::read_dir(&dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if entry.file_type()?.is_dir() {
+                if !entry.file_name().to_string_lossy().starts_with('.') {
+                    dirs.push(path);
+                }
+                continue;
+            }
+
+            match SyncModel::from_file(&path)? {
+                Some((SyncModel::Workspace(m), _)) => workspaces.push(m),
+                Some((SyncModel::Environment(m), _)) => environments.push(m),
+                Some((SyncModel::Folder(m), _)) => folders.push(m),
+                Some((SyncModel::HttpRequest(m), _)) => http_requests.push(m),
+                Some((SyncModel::GrpcRequest(m), _)) => grpc_requests.push(m),
+                Some((SyncModel::WebsocketRequest(m), _)) => websocket_requests.push(m),
+                Some((SyncModel::SseRequest(m), _)) => sse_requests.push(m),
+                None => {}
+            }
+        }
+    }
+
+    db.batch_upsert(
+        workspaces,
+        environments,
+        folders,
+        http_requests,
+        grpc_requests,
+        websocket_requests,
+        sse_requests,
+        &UpdateSource::Sync,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,272 @@
+use crate::context::{CliContext, SourceArgs};
+use crate::error::Result;
+use crate::send::{
+    find_cookie_jar, find_environment, find_folder, find_http_request, send_request,
+};
+use clap::{Args, Parser, Subcommand};
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use tokio::sync::watch;
+use yaak_http::runner::run_collection;
+use yaak_models::models::{CollectionRun, CollectionRunState, HttpResponse};
+
+mod callbacks;
+mod context;
+mod error;
+mod send;
+
+/// Run Yaak workspaces without the app
+#[derive(Parser, Debug)]
+#[command(name = "yaak", version)]
+struct Cli {
+    #[command(flatten)]
+    source: SourceArgs,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// List workspaces and their HTTP requests
+    List,
+
+    /// Send an HTTP request and print the response body
+    Send(SendArgs),
+
+    /// Send every HTTP request in a folder or workspace, in sidebar order
+    Run(RunArgs),
+}
+
+#[derive(Args, Debug)]
+struct SendArgs {
+    /// ID or name of the request to send
+    request: String,
+
+    /// ID or name of the environment to render with
+    #[arg(short, long)]
+    environment: Option<String>,
+
+    /// ID or name of the cookie jar to use
+    #[arg(long)]
+    cookie_jar: Option<String>,
+
+    /// Write the response body to a file instead of stdout
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Print the response status and headers before the body
+    #[arg(short, long)]
+    include: bool,
+}
+
+#[derive(Args, Debug)]
+struct RunArgs {
+    /// ID or name of the folder to run
+    #[arg(required_unless_present = "workspace", conflicts_with = "workspace")]
+    folder: Option<String>,
+
+    /// ID of the workspace to run, instead of a single folder
+    #[arg(short, long)]
+    workspace: Option<String>,
+
+    /// ID or name of the environment to render with
+    #[arg(short, long)]
+    environment: Option<String>,
+
+    /// ID or name of the cookie jar to use
+    #[arg(long)]
+    cookie_jar: Option<String>,
+
+    /// Number of times to run the collection
+    #[arg(short = 'n', long, default_value_t = 1)]
+    iterations: i32,
+
+    /// Milliseconds to wait between requests
+    #[arg(long, default_value_t = 0)]
+    delay: i32,
+
+    /// Stop the run after the first failed request
+    #[arg(long)]
+    stop_on_failure: bool,
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let ctx = match CliContext::open(&cli.source).await {
+        Ok(ctx) => ctx,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let result = match cli.command {
+        Command::List => cmd_list(&ctx),
+        Command::Send(args) => cmd_send(&ctx, args).await,
+        Command::Run(args) => cmd_run(&ctx, args).await,
+    };
+
+    ctx.close();
+
+    match result {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn cmd_list(ctx: &CliContext) -> Result<ExitCode> {
+    let db = ctx.db();
+    for w in db.list_workspaces()? {
+        println!("{}\t{}", w.id, w.name);
+        for r in db.list_http_requests(&w.id)? {
+            println!("  {}\t{} {}\t{}", r.id, r.method, r.url, r.name);
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+async fn cmd_send(ctx: &CliContext, args: SendArgs) -> Result<ExitCode> {
+    let request = find_http_request(ctx, &args.request)?;
+    let environment = match &args.environment {
+        Some(e) => Some(find_environment(ctx, &request.workspace_id, e)?),
+        None => None,
+    };
+    let cookie_jar = match &args.cookie_jar {
+        Some(c) => Some(find_cookie_jar(ctx, &request.workspace_id, c)?),
+        None => None,
+    };
+
+    let mut cancel_rx = cancel_on_ctrl_c();
+    let response =
+        send_request(ctx, &request, environment.as_ref(), cookie_jar, &mut cancel_rx).await?;
+
+    if let Some(e) = &response.error {
+        eprintln!("Error: {e}");
+        return Ok(ExitCode::FAILURE);
+    }
+
+    print_response(&response, &args).await?;
+
+    // Report assertions on stderr so they don't mix with the body
+    for a in &response.assertion_results {
+        let outcome = if a.passed { "PASS" } else { "FAIL" };
+        let actual = a.actual.as_deref().unwrap_or("<missing>");
+        match &a.error {
+            Some(e) => eprintln!("{outcome}\t{:?} {} {:?}: {e}", a.source, a.property, a.operator),
+            None => eprintln!(
+                "{outcome}\t{:?} {} {:?} {} (got {actual})",
+                a.source, a.property, a.operator, a.expected
+            ),
+        }
+    }
+
+    match response.assertion_results.iter().all(|a| a.passed) {
+        true => Ok(ExitCode::SUCCESS),
+        false => Ok(ExitCode::FAILURE),
+    }
+}
+
+async fn cmd_run(ctx: &CliContext, args: RunArgs) -> Result<ExitCode> {
+    let (workspace_id, folder_id) = match (&args.folder, &args.workspace) {
+        (Some(f), _) => {
+            let folder = find_folder(ctx, f)?;
+            (folder.workspace_id, Some(folder.id))
+        }
+        (None, Some(w)) => (ctx.db().get_workspace(w)?.id, None),
+        (None, None) => unreachable!("clap requires a folder or workspace"),
+    };
+    let environment = match &args.environment {
+        Some(e) => Some(find_environment(ctx, &workspace_id, e)?),
+        None => None,
+    };
+    let cookie_jar_id = match &args.cookie_jar {
+        Some(c) => Some(find_cookie_jar(ctx, &workspace_id, c)?.id),
+        None => None,
+    };
+
+    let run = CollectionRun {
+        workspace_id,
+        folder_id,
+        environment_id: environment.as_ref().map(|e| e.id.clone()),
+        cookie_jar_id,
+        iterations: args.iterations,
+        delay: args.delay,
+        stop_on_failure: args.stop_on_failure,
+        ..Default::default()
+    };
+
+    let mut cancel_rx = cancel_on_ctrl_c();
+    let sender = ctx.http_sender();
+    let run = run_collection(&sender, &run, environment.as_ref(), &mut cancel_rx).await?;
+
+    let db = ctx.db();
+    for r in &run.results {
+        let name = db.get_http_request(&r.request_id).map(|r| r.name).unwrap_or_default();
+        let outcome = if r.passed { "PASS" } else { "FAIL" };
+        match &r.error {
+            Some(e) => println!("{outcome}\t#{}\t{name}\t{e}", r.iteration + 1),
+            None => {
+                println!("{outcome}\t#{}\t{name}\t{} ({}ms)", r.iteration + 1, r.status, r.elapsed)
+            }
+        }
+    }
+
+    let passed = run.results.iter().filter(|r| r.passed).count();
+    println!("\n{passed}/{} passed in {}ms", run.results.len(), run.elapsed);
+
+    match run.state {
+        CollectionRunState::Passed => Ok(ExitCode::SUCCESS),
+        _ => Ok(ExitCode::FAILURE),
+    }
+}
+
+/// Create a cancellation channel that flips to `true` when the user hits Ctrl-C
+fn cancel_on_ctrl_c() -> watch::Receiver<bool> {
+    let (cancel_tx, cancel_rx) = watch::channel(false);
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            let _ = cancel_tx.send(true);
+        }
+    });
+    cancel_rx
+}
+
+async fn print_response(response: &HttpResponse, args: &SendArgs) -> Result<()> {
+    let mut stdout = std::io::stdout().lock();
+    if args.include {
+        writeln!(
+            stdout,
+            "{} {} {}",
+            response.version.clone().unwrap_or_default(),
+            response.status,
+            response.status_reason.clone().unwrap_or_default()
+        )?;
+        for h in &response.headers {
+            writeln!(stdout, "{}: {}", h.name, h.value)?;
+        }
+        writeln!(stdout)?;
+    }
+
+    let body_path = match &response.body_path {
+        Some(p) => p,
+        None => return Ok(()),
+    };
+
+    match &args.output {
+        Some(output) => {
+            tokio::fs::copy(body_path, output).await?;
+        }
+        None => {
+            stdout.write_all(&tokio::fs::read(body_path).await?)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use crate::context::CliContext;
+use crate::error::Error::NotFound;
+use crate::error::Result;
+use tokio::sync::watch;
+use yaak_models::models::{CookieJar, Environment, Folder, HttpRequest, HttpResponse};
+
+/// Render and send a request, returning the finished response
+pub async fn send_request(
+    ctx: &CliContext,
+    request: &HttpRequest,
+    environment: Option<&Environment>,
+    cookie_jar: Option<CookieJar>,
+    cancelled_rx: &mut watch::Receiver<bool>,
+) -> Result<HttpResponse> {
+    let sender = ctx.http_sender();
+    let response = ctx.db().upsert_http_response(
+        &HttpResponse {
+            request_id: request.id.clone(),
+            workspace_id: request.workspace_id.clone(),
+            ..Default::default()
+        },
+        &sender.update_source,
+    )?;
+
+    Ok(sender.send(request, &response, environment, cookie_jar, cancelled_rx).await?)
+}
+
+/// Find a request by ID, falling back to an exact name match
+pub fn find_http_request(ctx: &CliContext, id_or_name: &str) -> Result<HttpRequest> {
+    let db = ctx.db();
+    if let Ok(r) = db.get_http_request(id_or_name) {
+        return Ok(r);
+    }
+
+    let mut matches = Vec::new();
+    for w in db.list_workspaces()? {
+        for r in db.list_http_requests(&w.id)? {
+            if r.name == id_or_name {
+                matches.push(r);
+            }
+        }
+    }
+
+    match matches.len() {
+        1 => Ok(matches.remove(0)),
+        0 => Err(NotFound(format!("request \"{id_or_name}\""))),
+        n => Err(NotFound(format!("unique request \"{id_or_name}\" ({n} share that name)"))),
+    }
+}
+
+/// Find a folder by ID, falling back to an exact name match
+pub fn find_folder(ctx: &CliContext, id_or_name: &str) -> Result<Folder> {
+    let db = ctx.db();
+    if let Ok(f) = db.get_folder(id_or_name) {
+        return Ok(f);
+    }
+
+    let mut matches = Vec::new();
+    for w in db.list_workspaces()? {
+        for f in db.list_folders(&w.id)? {
+            if f.name == id_or_name {
+                matches.push(f);
+            }
+        }
+    }
+
+    match matches.len() {
+        1 => Ok(matches.remove(0)),
+        0 => Err(NotFound(format!("folder \"{id_or_name}\""))),
+        n => Err(NotFound(format!("unique folder \"{id_or_name}\" ({n} share that name)"))),
+    }
+}
+
+/// Find a (non-base) environment of the workspace by ID or name
+pub fn find_environment(
+    ctx: &CliContext,
+    workspace_id: &str,
+    id_or_name: &str,
+) -> Result<Environment> {
+    ctx.db()
+        .list_environments_ensure_base(workspace_id)?
+        .into_iter()
+        .find(|e| !e.base && (e.id == id_or_name || e.name == id_or_name))
+        .ok_or(NotFound(format!("environment \"{id_or_name}\"")))
+}
+
+/// Find a cookie jar of the workspace by ID or name
+pub fn find_cookie_jar(
+    ctx: &CliContext,
+    workspace_id: &str,
+    id_or_name: &str,
+) -> Result<CookieJar> {
+    ctx.db()
+        .list_cookie_jars(workspace_id)?
+        .into_iter()
+        .find(|c| c.id == id_or_name || c.name == id_or_name)
+        .ok_or(NotFound(format!("cookie jar \"{id_or_name}\"")))
+}
@@ -3,9 +3,9 @@ use crate::transport::get_transport;
 use async_recursion::async_recursion;
 use hyper_rustls::HttpsConnector;
 use hyper_util::client::legacy::Client;
-use hyper_util::client::legacy::connect::HttpConnector;
 use log::debug;
 use std::collections::BTreeMap;
+use std::time::Duration;
 use tokio_stream::StreamExt;
 use tonic::Request;
 use tonic::body::BoxBody;
@@ -18,28 +18,38 @@ use tonic_reflection::pb::v1::{
 };
 use tonic_reflection::pb::v1::{ExtensionRequest, FileDescriptorResponse};
 use tonic_reflection::pb::{v1, v1alpha};
+use yaak_proxy::config::ProxyConfig;
+use yaak_proxy::connect::ProxyConnector;
+use yaak_proxy::dns::DnsOverrides;
+use yaak_tls::config::TlsSettings;
 
-pub struct AutoReflectionClient<T = Client<HttpsConnector<HttpConnector>, BoxBody>> {
+pub struct AutoReflectionClient<T = Client<HttpsConnector<ProxyConnector>, BoxBody>> {
     use_v1alpha: bool,
     client_v1: v1::server_reflection_client::ServerReflectionClient<T>,
     client_v1alpha: v1alpha::server_reflection_client::ServerReflectionClient<T>,
 }
 
 impl AutoReflectionClient {
-    pub fn new(uri: &Uri) -> Self {
+    pub fn new(
+        uri: &Uri,
+        tls_settings: &TlsSettings,
+        proxy: &ProxyConfig,
+        dns_overrides: &DnsOverrides,
+        connect_timeout: Option<Duration>,
+    ) -> Result<Self, String> {
         let client_v1 = v1::server_reflection_client::ServerReflectionClient::with_origin(
-            get_transport(),
+            get_transport(uri, tls_settings, proxy, dns_overrides, connect_timeout)?,
             uri.clone(),
         );
         let client_v1alpha = v1alpha::server_reflection_client::ServerReflectionClient::with_origin(
-            get_transport(),
+            get_transport(uri, tls_settings, proxy, dns_overrides, connect_timeout)?,
             uri.clone(),
         );
-        AutoReflectionClient {
+        Ok(AutoReflectionClient {
             use_v1alpha: false,
             client_v1,
             client_v1alpha,
-        }
+        })
     }
 
     #[async_recursion]
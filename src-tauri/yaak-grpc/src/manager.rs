@@ -1,9 +1,9 @@
 use std::collections::BTreeMap;
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::time::Duration;
 
 use hyper_rustls::HttpsConnector;
-use hyper_util::client::legacy::connect::HttpConnector;
 use hyper_util::client::legacy::Client;
 pub use prost_reflect::DynamicMessage;
 use prost_reflect::{DescriptorPool, MethodDescriptor, ServiceDescriptor};
@@ -19,11 +19,15 @@ use crate::codec::DynamicCodec;
 use crate::reflection::{fill_pool_from_files, fill_pool_from_reflection, method_desc_to_path};
 use crate::transport::get_transport;
 use crate::{json_schema, MethodDefinition, ServiceDefinition};
+use yaak_proxy::config::ProxyConfig;
+use yaak_proxy::connect::ProxyConnector;
+use yaak_proxy::dns::DnsOverrides;
+use yaak_tls::config::TlsSettings;
 
 #[derive(Clone)]
 pub struct GrpcConnection {
     pool: DescriptorPool,
-    conn: Client<HttpsConnector<HttpConnector>, BoxBody>,
+    conn: Client<HttpsConnector<ProxyConnector>, BoxBody>,
     pub uri: Uri,
 }
 
@@ -181,10 +185,22 @@ impl GrpcHandle {
         uri: &str,
         proto_files: &Vec<PathBuf>,
         metadata: &BTreeMap<String, String>,
+        tls_settings: &TlsSettings,
+        proxy: &ProxyConfig,
+        dns_overrides: &DnsOverrides,
+        connect_timeout: Option<Duration>,
     ) -> Result<(), String> {
         let pool = if proto_files.is_empty() {
             let full_uri = uri_from_str(uri)?;
-            fill_pool_from_reflection(&full_uri, metadata).await
+            fill_pool_from_reflection(
+                &full_uri,
+                metadata,
+                tls_settings,
+                proxy,
+                dns_overrides,
+                connect_timeout,
+            )
+            .await
         } else {
             fill_pool_from_files(&self.app_handle, proto_files).await
         }?;
@@ -199,9 +215,23 @@ impl GrpcHandle {
         uri: &str,
         proto_files: &Vec<PathBuf>,
         metadata: &BTreeMap<String, String>,
+        tls_settings: &TlsSettings,
+        proxy: &ProxyConfig,
+        dns_overrides: &DnsOverrides,
+        connect_timeout: Option<Duration>,
     ) -> Result<Vec<ServiceDefinition>, String> {
         // Ensure reflection is up-to-date
-        self.reflect(id, uri, proto_files, metadata).await?;
+        self.reflect(
+            id,
+            uri,
+            proto_files,
+            metadata,
+            tls_settings,
+            proxy,
+            dns_overrides,
+            connect_timeout,
+        )
+        .await?;
 
         let pool = self.get_pool(id, uri, proto_files).ok_or("Failed to get pool".to_string())?;
         Ok(self.services_from_pool(&pool))
@@ -238,12 +268,26 @@ impl GrpcHandle {
         uri: &str,
         proto_files: &Vec<PathBuf>,
         metadata: &BTreeMap<String, String>,
+        tls_settings: &TlsSettings,
+        proxy: &ProxyConfig,
+        dns_overrides: &DnsOverrides,
+        connect_timeout: Option<Duration>,
     ) -> Result<GrpcConnection, String> {
-        self.reflect(id, uri, proto_files, metadata).await?;
+        self.reflect(
+            id,
+            uri,
+            proto_files,
+            metadata,
+            tls_settings,
+            proxy,
+            dns_overrides,
+            connect_timeout,
+        )
+        .await?;
         let pool = self.get_pool(id, uri, proto_files).ok_or("Failed to get pool")?;
 
         let uri = uri_from_str(uri)?;
-        let conn = get_transport();
+        let conn = get_transport(&uri, tls_settings, proxy, dns_overrides, connect_timeout)?;
         let connection = GrpcConnection {
             pool: pool.clone(),
             conn,
@@ -3,6 +3,7 @@ use std::env::temp_dir;
 use std::ops::Deref;
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::time::Duration;
 
 use crate::client::AutoReflectionClient;
 use anyhow::anyhow;
@@ -19,6 +20,9 @@ use tonic::codegen::http::uri::PathAndQuery;
 use tonic::transport::Uri;
 use tonic_reflection::pb::v1::server_reflection_request::MessageRequest;
 use tonic_reflection::pb::v1::server_reflection_response::MessageResponse;
+use yaak_proxy::config::ProxyConfig;
+use yaak_proxy::dns::DnsOverrides;
+use yaak_tls::config::TlsSettings;
 
 pub async fn fill_pool_from_files(
     app_handle: &AppHandle,
@@ -93,9 +97,14 @@ pub async fn fill_pool_from_files(
 pub async fn fill_pool_from_reflection(
     uri: &Uri,
     metadata: &BTreeMap<String, String>,
+    tls_settings: &TlsSettings,
+    proxy: &ProxyConfig,
+    dns_overrides: &DnsOverrides,
+    connect_timeout: Option<Duration>,
 ) -> Result<DescriptorPool, String> {
     let mut pool = DescriptorPool::new();
-    let mut client = AutoReflectionClient::new(uri);
+    let mut client =
+        AutoReflectionClient::new(uri, tls_settings, proxy, dns_overrides, connect_timeout)?;
 
     for service in list_services(&mut client, metadata).await? {
         if service == "grpc.reflection.v1alpha.ServerReflection" {
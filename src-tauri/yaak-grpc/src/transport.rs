@@ -1,31 +1,39 @@
 use hyper_rustls::{HttpsConnector, HttpsConnectorBuilder};
-use hyper_util::client::legacy::connect::HttpConnector;
 use hyper_util::client::legacy::Client;
 use hyper_util::rt::TokioExecutor;
-use rustls::crypto::ring;
-use rustls::ClientConfig;
-use rustls_platform_verifier::BuilderVerifierExt;
-use std::sync::Arc;
+use std::time::Duration;
 use tonic::body::BoxBody;
+use tonic::transport::Uri;
+use yaak_proxy::config::ProxyConfig;
+use yaak_proxy::connect::ProxyConnector;
+use yaak_proxy::dns::DnsOverrides;
+use yaak_tls::config::{get_config, TlsSettings};
 
-pub(crate) fn get_transport() -> Client<HttpsConnector<HttpConnector>, BoxBody> {
-    let arc_crypto_provider = Arc::new(ring::default_provider());
-    let config = ClientConfig::builder_with_provider(arc_crypto_provider)
-        .with_safe_default_protocol_versions()
-        .unwrap()
-        .with_platform_verifier()
-        .with_no_client_auth();
+pub(crate) fn get_transport(
+    uri: &Uri,
+    tls_settings: &TlsSettings,
+    proxy: &ProxyConfig,
+    dns_overrides: &DnsOverrides,
+    connect_timeout: Option<Duration>,
+) -> Result<Client<HttpsConnector<ProxyConnector>, BoxBody>, String> {
+    let port = uri.port_u16().or_else(|| match uri.scheme_str() {
+        Some("https") => Some(443),
+        _ => Some(80),
+    });
+    let config = get_config(tls_settings, uri.host().unwrap_or_default(), port)
+        .map_err(|e| e.to_string())?;
 
-    let mut http = HttpConnector::new();
-    http.enforce_http(false);
-
-    let connector =
-        HttpsConnectorBuilder::new().with_tls_config(config).https_or_http().enable_http2().build();
+    let route = proxy.route_str(&uri.to_string()).map_err(|e| e.to_string())?;
+    let connector = HttpsConnectorBuilder::new()
+        .with_tls_config(config)
+        .https_or_http()
+        .enable_http2()
+        .wrap_connector(ProxyConnector::new(route, dns_overrides.clone(), connect_timeout));
 
     let client = Client::builder(TokioExecutor::new())
         .pool_max_idle_per_host(0)
         .http2_only(true)
         .build(connector);
 
-    client
+    Ok(client)
 }
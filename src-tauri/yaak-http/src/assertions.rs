@@ -0,0 +1,233 @@
+use jsonpath_rust::JsonPath;
+use regex::Regex;
+use serde_json::Value;
+use std::str::FromStr;
+use yaak_models::models::{
+    HttpAssertionOperator, HttpAssertionResult, HttpAssertionSource, HttpRequestAssertion,
+    HttpResponse,
+};
+
+/// Check each enabled assertion against a finished response and its body
+pub fn evaluate_assertions(
+    assertions: &[HttpRequestAssertion],
+    response: &HttpResponse,
+    body: &[u8],
+) -> Vec<HttpAssertionResult> {
+    assertions
+        .iter()
+        .filter(|a| a.enabled)
+        .map(|a| {
+            let mut result = HttpAssertionResult {
+                source: a.source,
+                property: a.property.clone(),
+                operator: a.operator,
+                expected: a.value.clone(),
+                ..Default::default()
+            };
+
+            let checked = actual_value(a, response, body).and_then(|actual| {
+                result.actual = actual.clone();
+                compare(a.operator, actual.as_deref(), &a.value)
+            });
+
+            match checked {
+                Ok(passed) => result.passed = passed,
+                Err(e) => result.error = Some(e),
+            }
+
+            result
+        })
+        .collect()
+}
+
+fn actual_value(
+    assertion: &HttpRequestAssertion,
+    response: &HttpResponse,
+    body: &[u8],
+) -> Result<Option<String>, String> {
+    let value = match assertion.source {
+        HttpAssertionSource::Status => Some(response.status.to_string()),
+        HttpAssertionSource::Elapsed => Some(response.elapsed.to_string()),
+        HttpAssertionSource::Header => response
+            .headers
+            .iter()
+            .find(|h| h.name.eq_ignore_ascii_case(&assertion.property))
+            .map(|h| h.value.clone()),
+        HttpAssertionSource::JsonPath => {
+            let json: Value = serde_json::from_slice(body)
+                .map_err(|e| format!("Response body is not valid JSON: {e}"))?;
+            let path = JsonPath::from_str(&assertion.property)
+                .map_err(|e| format!("Invalid JSONPath {}: {e}", assertion.property))?;
+            let mut values = path
+                .find_slice_ptr(&json)
+                .into_iter()
+                .map(|v| (*v).clone())
+                .collect::<Vec<Value>>();
+            match values.len() {
+                0 => None,
+                1 => Some(value_to_string(values.remove(0))),
+                _ => Some(value_to_string(Value::Array(values))),
+            }
+        }
+    };
+
+    Ok(value)
+}
+
+fn value_to_string(v: Value) -> String {
+    match v {
+        Value::String(s) => s,
+        v => v.to_string(),
+    }
+}
+
+fn compare(
+    operator: HttpAssertionOperator,
+    actual: Option<&str>,
+    expected: &str,
+) -> Result<bool, String> {
+    let actual = match (operator, actual) {
+        (HttpAssertionOperator::Exists, a) => return Ok(a.is_some()),
+        (_, None) => return Ok(false),
+        (_, Some(a)) => a,
+    };
+
+    let passed = match operator {
+        HttpAssertionOperator::Equals => values_equal(actual, expected),
+        HttpAssertionOperator::NotEquals => !values_equal(actual, expected),
+        HttpAssertionOperator::Contains => actual.contains(expected),
+        HttpAssertionOperator::Matches => Regex::new(expected)
+            .map_err(|e| format!("Invalid regex {expected}: {e}"))?
+            .is_match(actual),
+        HttpAssertionOperator::LessThan => parse_number(actual)? < parse_number(expected)?,
+        HttpAssertionOperator::GreaterThan => parse_number(actual)? > parse_number(expected)?,
+        HttpAssertionOperator::Exists => unreachable!(),
+    };
+
+    Ok(passed)
+}
+
+/// Compare numerically when both sides are numbers, so `1.0` equals `1`
+fn values_equal(actual: &str, expected: &str) -> bool {
+    match (actual.trim().parse::<f64>(), expected.trim().parse::<f64>()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => actual == expected,
+    }
+}
+
+fn parse_number(v: &str) -> Result<f64, String> {
+    v.trim().parse::<f64>().map_err(|_| format!("{v:?} is not a number"))
+}
+
+#[cfg(test)]
+mod assertions_tests {
+    use crate::assertions::evaluate_assertions;
+    use yaak_models::models::{
+        HttpAssertionOperator, HttpAssertionSource, HttpRequestAssertion, HttpResponse,
+        HttpResponseHeader,
+    };
+
+    fn assertion(
+        source: HttpAssertionSource,
+        property: &str,
+        operator: HttpAssertionOperator,
+        value: &str,
+    ) -> HttpRequestAssertion {
+        HttpRequestAssertion {
+            enabled: true,
+            source,
+            property: property.into(),
+            operator,
+            value: value.into(),
+            id: None,
+        }
+    }
+
+    fn response() -> HttpResponse {
+        HttpResponse {
+            status: 200,
+            elapsed: 120,
+            headers: vec![HttpResponseHeader {
+                name: "content-type".into(),
+                value: "application/json; charset=utf-8".into(),
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn status_and_elapsed() {
+        let assertions = vec![
+            assertion(HttpAssertionSource::Status, "", HttpAssertionOperator::Equals, "200"),
+            assertion(HttpAssertionSource::Elapsed, "", HttpAssertionOperator::LessThan, "100"),
+        ];
+        let results = evaluate_assertions(&assertions, &response(), b"");
+        assert!(results[0].passed);
+        assert!(!results[1].passed);
+        assert_eq!(results[1].actual, Some("120".into()));
+    }
+
+    #[test]
+    fn header_matches() {
+        let assertions = vec![
+            assertion(
+                HttpAssertionSource::Header,
+                "Content-Type",
+                HttpAssertionOperator::Matches,
+                "^application/json",
+            ),
+            assertion(HttpAssertionSource::Header, "x-missing", HttpAssertionOperator::Exists, ""),
+        ];
+        let results = evaluate_assertions(&assertions, &response(), b"");
+        assert!(results[0].passed);
+        assert!(!results[1].passed);
+    }
+
+    #[test]
+    fn json_path() {
+        let body = br#"{"user": {"id": 1.0, "name": "Alice"}, "tags": []}"#;
+        let assertions = vec![
+            assertion(
+                HttpAssertionSource::JsonPath,
+                "$.user.id",
+                HttpAssertionOperator::Equals,
+                "1",
+            ),
+            assertion(
+                HttpAssertionSource::JsonPath,
+                "$.user.name",
+                HttpAssertionOperator::Equals,
+                "Alice",
+            ),
+            assertion(
+                HttpAssertionSource::JsonPath,
+                "$.tags[0]",
+                HttpAssertionOperator::Exists,
+                "",
+            ),
+        ];
+        let results = evaluate_assertions(&assertions, &response(), body);
+        assert!(results[0].passed);
+        assert!(results[1].passed);
+        assert!(!results[2].passed);
+    }
+
+    #[test]
+    fn invalid_input_is_an_error() {
+        let assertions = vec![
+            assertion(HttpAssertionSource::JsonPath, "$.a", HttpAssertionOperator::Exists, ""),
+            assertion(HttpAssertionSource::Status, "", HttpAssertionOperator::Matches, "("),
+        ];
+        let results = evaluate_assertions(&assertions, &response(), b"not json");
+        assert!(!results[0].passed && results[0].error.is_some());
+        assert!(!results[1].passed && results[1].error.is_some());
+    }
+
+    #[test]
+    fn disabled_are_skipped() {
+        let mut a =
+            assertion(HttpAssertionSource::Status, "", HttpAssertionOperator::Equals, "500");
+        a.enabled = false;
+        assert!(evaluate_assertions(&[a], &response(), b"").is_empty());
+    }
+}
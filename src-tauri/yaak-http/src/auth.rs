@@ -0,0 +1,188 @@
+use crate::error::Error::AuthenticationError;
+use crate::error::Result;
+use http::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Request, Url};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::str::FromStr;
+
+/// The kind of request being authenticated, since not every provider supports all of them
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AuthenticationRequestType {
+    #[default]
+    Http,
+    Grpc,
+    Websocket,
+    Sse,
+}
+
+/// Everything an authentication provider needs to know about the outgoing request
+#[derive(Debug, Clone, Default)]
+pub struct AuthenticationRequest {
+    pub context_id: String,
+    pub request_type: AuthenticationRequestType,
+    pub values: BTreeMap<String, Value>,
+    pub method: String,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    /// The body as it will be sent, so it can be signed. Bodies that are streamed, like large
+    /// file uploads, aren't known up front and are left out.
+    pub body: Option<Vec<u8>>,
+}
+
+impl AuthenticationRequest {
+    /// Describe a request that's ready to be sent
+    pub fn from_request(
+        context_id: String,
+        request_type: AuthenticationRequestType,
+        values: BTreeMap<String, Value>,
+        req: &Request,
+    ) -> AuthenticationRequest {
+        AuthenticationRequest {
+            context_id,
+            request_type,
+            values,
+            method: req.method().to_string(),
+            url: req.url().to_string(),
+            headers: req
+                .headers()
+                .iter()
+                .map(|(name, value)| {
+                    (name.to_string(), value.to_str().unwrap_or_default().to_string())
+                })
+                .collect(),
+            body: req.body().and_then(|b| b.as_bytes()).map(|b| b.to_vec()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AuthenticationResult {
+    pub set_headers: Vec<(String, String)>,
+    /// Query parameters to add, replacing any existing ones with the same name
+    pub set_query_parameters: Vec<(String, String)>,
+    /// A new URL for the request, which the query parameters are then applied to
+    pub set_url: Option<String>,
+}
+
+impl AuthenticationResult {
+    /// Apply the changes to a request, in the order of URL, query parameters, then headers
+    pub fn apply(&self, req: &mut Request) -> Result<()> {
+        self.apply_to_url(req.url_mut())?;
+        self.apply_to_headers(req.headers_mut())
+    }
+
+    /// Apply the URL and query parameter changes, for requests that aren't sent with reqwest
+    pub fn apply_to_url(&self, url: &mut Url) -> Result<()> {
+        if let Some(new_url) = &self.set_url {
+            *url = Url::parse(new_url)
+                .map_err(|e| AuthenticationError(format!("Invalid URL {new_url:?}: {e}")))?;
+        }
+
+        if !self.set_query_parameters.is_empty() {
+            let kept = url
+                .query_pairs()
+                .filter(|(name, _)| !self.set_query_parameters.iter().any(|(n, _)| n == name))
+                .map(|(name, value)| (name.into_owned(), value.into_owned()))
+                .collect::<Vec<_>>();
+            url.query_pairs_mut()
+                .clear()
+                .extend_pairs(kept)
+                .extend_pairs(&self.set_query_parameters);
+        }
+
+        Ok(())
+    }
+
+    /// Apply the header changes, replacing existing headers with the same name
+    pub fn apply_to_headers(&self, headers: &mut HeaderMap) -> Result<()> {
+        for (name, value) in &self.set_headers {
+            let name = HeaderName::from_str(name)
+                .map_err(|e| AuthenticationError(format!("Invalid header name {name:?}: {e}")))?;
+            let value = HeaderValue::from_str(value)
+                .map_err(|e| AuthenticationError(format!("Invalid value for {name}: {e}")))?;
+            headers.insert(name, value);
+        }
+
+        Ok(())
+    }
+}
+
+/// Applies `authentication_type` to a request. The app implements this by calling into
+/// plugins, while headless consumers can provide their own.
+pub trait AuthenticationCallback {
+    fn authenticate(
+        &self,
+        auth_name: &str,
+        req: AuthenticationRequest,
+    ) -> impl Future<Output = Result<AuthenticationResult>> + Send;
+}
+
+#[cfg(test)]
+mod auth_tests {
+    use crate::auth::{AuthenticationRequest, AuthenticationRequestType, AuthenticationResult};
+    use reqwest::{Client, Method};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn includes_buffered_body() {
+        let req = Client::new()
+            .request(Method::POST, "https://example.com/upload?a=1")
+            .header("Content-Type", "text/plain")
+            .body("hello")
+            .build()
+            .unwrap();
+
+        let auth_req = AuthenticationRequest::from_request(
+            "ctx".into(),
+            AuthenticationRequestType::Http,
+            BTreeMap::new(),
+            &req,
+        );
+        assert_eq!(auth_req.url, "https://example.com/upload?a=1");
+        assert_eq!(auth_req.headers, vec![("content-type".into(), "text/plain".into())]);
+        assert_eq!(auth_req.body, Some(b"hello".to_vec()));
+    }
+
+    #[test]
+    fn applies_url_then_query_then_headers() {
+        let mut req = Client::new()
+            .request(Method::GET, "https://example.com/a?keep=1&sig=old&sig=older")
+            .build()
+            .unwrap();
+
+        let result = AuthenticationResult {
+            set_headers: vec![("X-Signature".into(), "abc".into())],
+            set_query_parameters: vec![("sig".into(), "new value".into())],
+            set_url: None,
+        };
+        result.apply(&mut req).unwrap();
+        assert_eq!(req.url().as_str(), "https://example.com/a?keep=1&sig=new+value");
+        assert_eq!(req.headers().get("x-signature").unwrap(), "abc");
+
+        let result = AuthenticationResult {
+            set_url: Some("https://signed.example.com/b?x=1".into()),
+            set_query_parameters: vec![("y".into(), "2".into())],
+            ..Default::default()
+        };
+        result.apply(&mut req).unwrap();
+        assert_eq!(req.url().as_str(), "https://signed.example.com/b?x=1&y=2");
+    }
+
+    #[test]
+    fn rejects_invalid_results() {
+        let mut req = Client::new().request(Method::GET, "https://example.com").build().unwrap();
+        let bad_url = AuthenticationResult {
+            set_url: Some("not a url".into()),
+            ..Default::default()
+        };
+        assert!(bad_url.apply(&mut req).is_err());
+
+        let bad_header = AuthenticationResult {
+            set_headers: vec![("Bad Name".into(), "v".into())],
+            ..Default::default()
+        };
+        assert!(bad_header.apply(&mut req).is_err());
+    }
+}
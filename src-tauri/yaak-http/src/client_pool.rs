@@ -0,0 +1,270 @@
+use crate::error::Error::GenericError;
+use crate::error::Result;
+use crate::timing::{KnownConnections, TimingLayer, TimingResolver};
+use crate::tls;
+use log::{debug, warn};
+use reqwest::redirect::Policy;
+use reqwest::{Client, Proxy, Url};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use yaak_models::models::{HttpVersion, ProxySetting, Workspace};
+use yaak_proxy::config::{ProxyConfig, ProxyRoute};
+use yaak_proxy::dns::DnsOverrides;
+use yaak_tls::config::TlsSettings;
+
+/// Clients that haven't been used the longest are dropped once there are more than this
+const MAX_CLIENTS: usize = 32;
+
+/// Whether requests can use HTTP/3. reqwest only supports it when built with the `http3` feature
+/// and `RUSTFLAGS="--cfg reqwest_unstable"`, so the option is hidden otherwise.
+pub const HTTP3_SUPPORTED: bool = cfg!(feature = "http3");
+
+/// Everything that's baked into a client when it's built, so sends that share these can also
+/// share a client. Redirects and cookies are handled per send by execute_with_redirects, and the
+/// client certificate is picked per connection, so none of those need a client of their own.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ClientSettings {
+    pub workspace_id: String,
+    pub tls: TlsSettings,
+    pub proxy: ProxyConfig,
+    pub timeout: Option<Duration>,
+    /// Connect to this Unix domain socket instead of the host, ignoring any proxy
+    pub unix_socket: Option<PathBuf>,
+    /// Addresses to connect to instead of the ones DNS returns
+    pub dns_overrides: DnsOverrides,
+    pub http_version: HttpVersion,
+}
+
+impl ClientSettings {
+    pub fn new(workspace: &Workspace, proxy: ProxyConfig) -> Self {
+        ClientSettings {
+            workspace_id: workspace.id.clone(),
+            tls: TlsSettings::from_workspace(workspace),
+            proxy,
+            timeout: match workspace.setting_request_timeout > 0 {
+                true => Some(Duration::from_millis(
+                    workspace.setting_request_timeout.unsigned_abs() as u64,
+                )),
+                false => None,
+            },
+            unix_socket: None,
+            dns_overrides: DnsOverrides::default(),
+            http_version: HttpVersion::Auto,
+        }
+    }
+
+    /// Whether the client picks the proxy itself, rather than using the system's or none
+    fn routes_proxy(&self) -> bool {
+        self.unix_socket.is_none()
+            && !self.proxy.is_automatic()
+            && !matches!(self.proxy.setting, Some(ProxySetting::Disabled))
+    }
+
+    /// Pick the proxy for a request to `url` like the client will when connecting. The client
+    /// can't fail a request when that goes wrong (like a PAC script that throws), so it would
+    /// connect directly instead. Sends check first, to fail with the error.
+    pub fn check_proxy(&self, url: &Url) -> Result<()> {
+        if self.routes_proxy() {
+            self.proxy
+                .route(url)
+                .map_err(|e| GenericError(format!("Failed to pick a proxy for {url}: {e}")))?;
+        }
+        Ok(())
+    }
+}
+
+/// A client along with the TLS details of the connections it opened
+#[derive(Debug, Clone)]
+pub struct PooledClient {
+    pub client: Client,
+    pub connections: KnownConnections,
+}
+
+/// Clients shared between sends, so that connections and TLS sessions are reused instead of
+/// starting from scratch every time.
+#[derive(Debug, Clone, Default)]
+pub struct HttpClientPool {
+    clients: Arc<Mutex<HashMap<ClientSettings, (PooledClient, Instant)>>>,
+}
+
+impl HttpClientPool {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the client for these settings, building it if there isn't one yet
+    pub fn get(&self, settings: &ClientSettings) -> Result<PooledClient> {
+        let mut clients = self.clients.lock().map_err(|e| GenericError(e.to_string()))?;
+        if let Some((client, last_used)) = clients.get_mut(settings) {
+            *last_used = Instant::now();
+            return Ok(client.clone());
+        }
+
+        let client = build_client(settings)?;
+        if clients.len() >= MAX_CLIENTS {
+            let oldest = clients.iter().min_by_key(|(_, (_, t))| *t).map(|(s, _)| s.clone());
+            if let Some(s) = oldest {
+                clients.remove(&s);
+            }
+        }
+
+        debug!("Created HTTP client for workspace {}", settings.workspace_id);
+        clients.insert(settings.clone(), (client.clone(), Instant::now()));
+        Ok(client)
+    }
+}
+
+/// Build a new client that doesn't share connections with any other
+pub fn build_client(settings: &ClientSettings) -> Result<PooledClient> {
+    let connections = KnownConnections::new();
+    let mut tls_config = tls::client_config(&settings.tls)?;
+
+    // Only offer the forced version, or servers could pick one the client won't speak
+    match settings.http_version {
+        HttpVersion::Http1 => tls_config.alpn_protocols = vec![b"http/1.1".to_vec()],
+        HttpVersion::Http2 => tls_config.alpn_protocols = vec![b"h2".to_vec()],
+        // The config is shared with TCP connections, and reqwest sets h3 on its own copy for QUIC
+        HttpVersion::Http3 | HttpVersion::Auto => {}
+    }
+
+    // Redirects are followed by execute_with_redirects so each hop can be recorded
+    let mut client_builder = Client::builder()
+        .redirect(Policy::none())
+        .connection_verbose(true)
+        .gzip(true)
+        .brotli(true)
+        .deflate(true)
+        .referer(false)
+        .dns_resolver(Arc::new(TimingResolver::new()))
+        .connector_layer(TimingLayer::new(&connections))
+        .use_preconfigured_tls(tls_config);
+
+    match &settings.proxy.setting {
+        Some(ProxySetting::Disabled) => client_builder = client_builder.no_proxy(),
+        Some(_) if settings.routes_proxy() => {
+            let proxy = settings.proxy.clone();
+            client_builder =
+                client_builder.proxy(Proxy::custom(move |url| match proxy.route(url) {
+                    Ok(ProxyRoute::Proxy(proxy_url)) => Some(proxy_url),
+                    Ok(ProxyRoute::Direct) => None,
+                    // Sends already failed for their own URL (see check_proxy), so this is a
+                    // redirect to somewhere the proxy can't be picked for
+                    Err(e) => {
+                        warn!("Failed to pick a proxy for {url}, connecting directly: {e}");
+                        None
+                    }
+                }));
+        }
+        _ => {} // Nothing to do for this one, as it is the default
+    }
+
+    match settings.http_version {
+        HttpVersion::Auto => {}
+        HttpVersion::Http1 => client_builder = client_builder.http1_only(),
+        HttpVersion::Http2 => client_builder = client_builder.http2_prior_knowledge(),
+        #[cfg(feature = "http3")]
+        HttpVersion::Http3 => client_builder = client_builder.http3_prior_knowledge(),
+        #[cfg(not(feature = "http3"))]
+        HttpVersion::Http3 => {
+            return Err(GenericError("HTTP/3 is not supported by this build".to_string()));
+        }
+    }
+
+    // These are looked up before the resolver is asked, so they aren't timed as DNS
+    for (host, addrs) in settings.dns_overrides.iter() {
+        client_builder = client_builder.resolve_to_addrs(host, &addrs);
+    }
+
+    if let Some(path) = &settings.unix_socket {
+        #[cfg(unix)]
+        {
+            client_builder = client_builder.unix_socket(path.clone());
+        }
+        #[cfg(not(unix))]
+        {
+            let path = path.display();
+            return Err(GenericError(format!(
+                "Unix sockets are not supported on this platform: {path}"
+            )));
+        }
+    }
+
+    if let Some(timeout) = settings.timeout {
+        client_builder = client_builder.timeout(timeout);
+    }
+
+    Ok(PooledClient {
+        client: client_builder.build()?,
+        connections,
+    })
+}
+
+#[cfg(test)]
+mod client_pool_tests {
+    use crate::client_pool::{ClientSettings, HttpClientPool, build_client};
+    use reqwest::Url;
+    use yaak_models::models::{HttpVersion, ProxySetting, Workspace};
+    use yaak_proxy::config::ProxyConfig;
+
+    #[test]
+    fn reuses_clients_with_the_same_settings() {
+        let workspace = Workspace {
+            id: "wk_1".to_string(),
+            setting_validate_certificates: true,
+            ..Default::default()
+        };
+        let pool = HttpClientPool::new();
+
+        let proxy = ProxyConfig::default();
+        pool.get(&ClientSettings::new(&workspace, proxy.clone())).unwrap();
+        pool.get(&ClientSettings::new(&workspace, proxy.clone())).unwrap();
+        assert_eq!(pool.clients.lock().unwrap().len(), 1);
+
+        let other = Workspace {
+            setting_request_timeout: 1000,
+            ..workspace.clone()
+        };
+        pool.get(&ClientSettings::new(&other, proxy)).unwrap();
+        assert_eq!(pool.clients.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn builds_clients_for_forced_versions() {
+        let settings = ClientSettings::new(&Workspace::default(), ProxyConfig::default());
+        let with_version = |http_version| ClientSettings {
+            http_version,
+            ..settings.clone()
+        };
+
+        build_client(&with_version(HttpVersion::Http1)).unwrap();
+        build_client(&with_version(HttpVersion::Http2)).unwrap();
+        #[cfg(not(feature = "http3"))]
+        assert!(build_client(&with_version(HttpVersion::Http3)).is_err());
+
+        let pool = HttpClientPool::new();
+        pool.get(&with_version(HttpVersion::Auto)).unwrap();
+        pool.get(&with_version(HttpVersion::Http2)).unwrap();
+        assert_eq!(pool.clients.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn fails_to_check_a_proxy_that_cant_be_picked() {
+        let proxy = ProxyConfig::load(Some(ProxySetting::Enabled {
+            disabled: false,
+            http: "http://[bad".to_string(),
+            https: String::new(),
+            auth: None,
+            bypass: String::new(),
+        }))
+        .await
+        .unwrap();
+        let settings = ClientSettings::new(&Workspace::default(), proxy);
+
+        let https = Url::parse("https://example.com").unwrap();
+        settings.check_proxy(&https).unwrap();
+        let http = Url::parse("http://example.com").unwrap();
+        assert!(settings.check_proxy(&http).is_err());
+    }
+}
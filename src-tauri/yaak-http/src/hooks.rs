@@ -0,0 +1,18 @@
+use crate::error::Result;
+use std::future::Future;
+use yaak_models::models::{HttpRequest, HttpResponse};
+
+/// Hooks that run around each request sent by [`crate::sender::HttpSender`]. The app implements
+/// this by calling into plugins, while headless consumers can provide their own.
+pub trait HttpHooksCallback {
+    /// Called with the rendered request just before it is sent. The returned request is the one
+    /// that gets sent.
+    fn pre_request(&self, req: HttpRequest) -> impl Future<Output = Result<HttpRequest>> + Send;
+
+    /// Called once the response has finished, whether it succeeded or not
+    fn post_response(
+        &self,
+        req: &HttpRequest,
+        resp: &HttpResponse,
+    ) -> impl Future<Output = Result<()>> + Send;
+}
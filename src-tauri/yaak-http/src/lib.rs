@@ -1,5 +1,22 @@
 use yaak_models::models::HttpUrlParameter;
 
+pub mod assertions;
+pub mod auth;
+pub mod client_pool;
+pub mod error;
+pub mod hooks;
+pub mod progress;
+pub mod redirects;
+pub mod render;
+pub mod retry;
+pub mod runner;
+pub mod sender;
+pub mod sse;
+pub mod timing;
+pub mod tls;
+pub mod unix_socket;
+pub mod upload;
+
 pub fn apply_path_placeholders(
     url: &str,
     parameters: Vec<HttpUrlParameter>,
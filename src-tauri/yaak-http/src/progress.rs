@@ -0,0 +1,84 @@
+use std::time::{Duration, Instant};
+
+/// How often a transfer that's in progress is written to the database, at most
+pub const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Amount of transferred bytes after which progress is written, even within the interval
+pub const PROGRESS_BYTES: usize = 4 * 1024 * 1024;
+
+/// Batches progress updates of a body transfer, so that large bodies don't cause a database
+/// write (and model change event) for every chunk. An update is due once some bytes are pending
+/// and either the interval passed since the last update, or enough bytes piled up.
+pub struct ProgressThrottle {
+    last_update: Instant,
+    pending_bytes: usize,
+}
+
+impl Default for ProgressThrottle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProgressThrottle {
+    pub fn new() -> Self {
+        Self {
+            last_update: Instant::now(),
+            pending_bytes: 0,
+        }
+    }
+
+    /// Record bytes that were transferred but not reported yet
+    pub fn record(&mut self, bytes: usize) {
+        self.pending_bytes += bytes;
+    }
+
+    pub fn is_due(&self) -> bool {
+        self.is_due_at(Instant::now())
+    }
+
+    /// When pending bytes should be reported if nothing else arrives in the meantime
+    pub fn deadline(&self) -> Option<Instant> {
+        match self.pending_bytes {
+            0 => None,
+            _ => Some(self.last_update + PROGRESS_INTERVAL),
+        }
+    }
+
+    /// Mark the pending bytes as reported
+    pub fn reset(&mut self) {
+        self.last_update = Instant::now();
+        self.pending_bytes = 0;
+    }
+
+    fn is_due_at(&self, now: Instant) -> bool {
+        self.pending_bytes > 0
+            && (self.pending_bytes >= PROGRESS_BYTES
+                || now.duration_since(self.last_update) >= PROGRESS_INTERVAL)
+    }
+}
+
+#[cfg(test)]
+mod progress_tests {
+    use crate::progress::{PROGRESS_BYTES, PROGRESS_INTERVAL, ProgressThrottle};
+
+    #[test]
+    fn due_after_interval_or_enough_bytes() {
+        let mut throttle = ProgressThrottle::new();
+        let start = throttle.last_update;
+        assert!(!throttle.is_due_at(start + PROGRESS_INTERVAL));
+        assert_eq!(throttle.deadline(), None);
+
+        throttle.record(10);
+        assert!(!throttle.is_due_at(start));
+        assert!(throttle.is_due_at(start + PROGRESS_INTERVAL));
+        assert_eq!(throttle.deadline(), Some(start + PROGRESS_INTERVAL));
+
+        throttle.record(PROGRESS_BYTES);
+        assert!(throttle.is_due_at(start));
+
+        throttle.reset();
+        assert!(!throttle.is_due());
+        assert_eq!(throttle.deadline(), None);
+    }
+}
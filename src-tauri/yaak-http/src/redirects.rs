@@ -0,0 +1,412 @@
+use crate::error::Error::GenericError;
+use crate::error::Result;
+use crate::tls;
+use http::header::{
+    AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, COOKIE, LOCATION, PROXY_AUTHORIZATION, SET_COOKIE,
+    TRANSFER_ENCODING, WWW_AUTHENTICATE,
+};
+use http::{HeaderMap, StatusCode};
+use log::debug;
+use reqwest::cookie::CookieStore;
+use reqwest::{Client, Method, Request, Response, Url};
+use reqwest_cookie_store::CookieStoreMutex;
+use std::sync::Arc;
+use yaak_models::models::{HttpResponseHeader, HttpResponseRedirect, HttpSentRequest, Workspace};
+
+/// Only the start of the body is kept, since it's just for debugging
+const BODY_PREVIEW_BYTES: usize = 2048;
+
+#[derive(Debug, Clone)]
+pub struct RedirectSettings {
+    pub follow: bool,
+    pub max_redirects: usize,
+    pub rewrite_method: bool,
+}
+
+impl RedirectSettings {
+    pub fn from_workspace(workspace: &Workspace) -> Self {
+        RedirectSettings {
+            follow: workspace.setting_follow_redirects,
+            max_redirects: workspace.setting_max_redirects.max(0) as usize,
+            rewrite_method: workspace.setting_redirect_rewrite_method,
+        }
+    }
+}
+
+pub struct ExecutedRequest {
+    pub response: Response,
+    pub redirects: Vec<HttpResponseRedirect>,
+    /// The request that produced the final response
+    pub sent_request: HttpSentRequest,
+}
+
+/// Execute a request, following redirects ourselves (instead of letting reqwest do it) so that
+/// every hop can be recorded on the response.
+pub async fn execute_with_redirects(
+    client: &Client,
+    request: Request,
+    settings: &RedirectSettings,
+    cookie_store: Option<Arc<CookieStoreMutex>>,
+) -> Result<ExecutedRequest> {
+    let mut redirects = Vec::new();
+    let mut request = request;
+
+    // A Cookie header set on the request itself takes precedence over the cookie jar
+    let explicit_cookie = request.headers().contains_key(COOKIE);
+
+    loop {
+        // Add cookies ourselves, rather than leaving it to reqwest, so they can be recorded
+        if let (Some(store), false) = (&cookie_store, explicit_cookie) {
+            match store.cookies(request.url()) {
+                Some(v) => request.headers_mut().insert(COOKIE, v),
+                None => request.headers_mut().remove(COOKIE),
+            };
+        }
+
+        let method = request.method().clone();
+        let version = request.version();
+        let url = request.url().clone();
+        let headers = request.headers().clone();
+        let sent_request = sent_request(&request);
+
+        // Keep a copy in case the next hop needs to re-send the body
+        let retained = if settings.follow { request.try_clone() } else { None };
+
+        // Each hop presents the client certificate configured for its own host
+        let response = tls::targeted(&url, client.execute(request)).await?;
+        if let Some(store) = &cookie_store {
+            let mut set_cookies = response.headers().get_all(SET_COOKIE).iter().peekable();
+            if set_cookies.peek().is_some() {
+                store.set_cookies(&mut set_cookies, &url);
+            }
+        }
+
+        let location = match (settings.follow, redirect_location(&response)) {
+            (true, Some(l)) => l,
+            _ => {
+                return Ok(ExecutedRequest {
+                    response,
+                    redirects,
+                    sent_request,
+                });
+            }
+        };
+
+        if redirects.len() >= settings.max_redirects {
+            return Err(GenericError(format!(
+                "Too many redirects (max {})",
+                settings.max_redirects
+            )));
+        }
+
+        let next_url = url
+            .join(&location)
+            .map_err(|e| GenericError(format!("Invalid redirect location {location:?}: {e}")))?;
+        let next_method = redirect_method(response.status(), &method, settings.rewrite_method);
+        debug!("Following {} redirect to {next_method} {next_url}", response.status());
+
+        redirects.push(HttpResponseRedirect {
+            method: method.to_string(),
+            url: url.to_string(),
+            status: response.status().as_u16() as i32,
+            headers: response
+                .headers()
+                .iter()
+                .map(|(k, v)| HttpResponseHeader {
+                    name: k.as_str().to_string(),
+                    value: v.to_str().unwrap_or_default().to_string(),
+                })
+                .collect(),
+        });
+
+        request = if next_method == method {
+            match retained {
+                Some(r) => r,
+                None => {
+                    return Err(GenericError(format!(
+                        "Cannot re-send the request body when following the redirect to {next_url}"
+                    )));
+                }
+            }
+        } else {
+            // The method changed, so drop the body along with the headers describing it
+            let mut headers = headers;
+            headers.remove(CONTENT_TYPE);
+            headers.remove(CONTENT_LENGTH);
+            headers.remove(TRANSFER_ENCODING);
+            let mut r = Request::new(next_method, next_url.clone());
+            *r.headers_mut() = headers;
+            *r.version_mut() = version;
+            r
+        };
+
+        *request.url_mut() = next_url.clone();
+        remove_sensitive_headers(request.headers_mut(), &url, &next_url);
+    }
+}
+
+fn sent_request(request: &Request) -> HttpSentRequest {
+    let body = request.body().and_then(|b| b.as_bytes());
+    HttpSentRequest {
+        method: request.method().to_string(),
+        url: request.url().to_string(),
+        headers: request
+            .headers()
+            .iter()
+            .map(|(k, v)| HttpResponseHeader {
+                name: k.as_str().to_string(),
+                value: v.to_str().unwrap_or_default().to_string(),
+            })
+            .collect(),
+        body_size: match request.body() {
+            None => Some(0),
+            Some(_) => body.map(|b| b.len() as i32),
+        },
+        body_preview: body
+            .filter(|b| !b.is_empty())
+            .map(|b| String::from_utf8_lossy(&b[..b.len().min(BODY_PREVIEW_BYTES)]).to_string()),
+    }
+}
+
+fn redirect_location(response: &Response) -> Option<String> {
+    match response.status() {
+        StatusCode::MOVED_PERMANENTLY
+        | StatusCode::FOUND
+        | StatusCode::SEE_OTHER
+        | StatusCode::TEMPORARY_REDIRECT
+        | StatusCode::PERMANENT_REDIRECT => {
+            response.headers().get(LOCATION)?.to_str().ok().map(|l| l.to_string())
+        }
+        _ => None,
+    }
+}
+
+/// The method to use for the next hop. 307 and 308 always keep the method, while 301, 302 and
+/// 303 switch to GET the way browsers do, unless rewriting is disabled.
+fn redirect_method(status: StatusCode, method: &Method, rewrite: bool) -> Method {
+    if !rewrite {
+        return method.clone();
+    }
+
+    match status {
+        StatusCode::SEE_OTHER if method != Method::HEAD => Method::GET,
+        StatusCode::MOVED_PERMANENTLY | StatusCode::FOUND if method == Method::POST => Method::GET,
+        _ => method.clone(),
+    }
+}
+
+/// Don't leak credentials to a different origin
+fn remove_sensitive_headers(headers: &mut HeaderMap, previous: &Url, next: &Url) {
+    let same_origin = previous.scheme() == next.scheme()
+        && previous.host_str() == next.host_str()
+        && previous.port_or_known_default() == next.port_or_known_default();
+    if !same_origin {
+        headers.remove(AUTHORIZATION);
+        headers.remove(COOKIE);
+        headers.remove(PROXY_AUTHORIZATION);
+        headers.remove(WWW_AUTHENTICATE);
+    }
+}
+
+#[cfg(test)]
+mod redirects_tests {
+    use crate::client_pool::{ClientSettings, build_client};
+    use crate::redirects::{
+        RedirectSettings, execute_with_redirects, redirect_method, remove_sensitive_headers,
+        sent_request,
+    };
+    use http::header::{AUTHORIZATION, USER_AGENT};
+    use http::{HeaderMap, HeaderValue, StatusCode};
+    use reqwest::{Method, Request, Url};
+    use rustls::client::danger::HandshakeSignatureValid;
+    use rustls::crypto::{CryptoProvider, ring, verify_tls12_signature, verify_tls13_signature};
+    use rustls::pki_types::pem::PemObject;
+    use rustls::pki_types::{CertificateDer, PrivateKeyDer, UnixTime};
+    use rustls::server::danger::{ClientCertVerified, ClientCertVerifier};
+    use rustls::{DigitallySignedStruct, DistinguishedName, ServerConfig, SignatureScheme};
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use tokio_rustls::TlsAcceptor;
+    use yaak_models::models::{ClientCertificate, Workspace};
+    use yaak_proxy::config::ProxyConfig;
+
+    fn testdata(name: &str) -> String {
+        format!("{}/testdata/{name}", env!("CARGO_MANIFEST_DIR"))
+    }
+
+    fn certificate(name: &str) -> CertificateDer<'static> {
+        CertificateDer::from_pem_file(testdata(&format!("{name}.crt"))).unwrap()
+    }
+
+    /// Asks for a client certificate and accepts whichever one is presented
+    #[derive(Debug)]
+    struct AnyClientCert(Arc<CryptoProvider>);
+
+    impl ClientCertVerifier for AnyClientCert {
+        fn root_hint_subjects(&self) -> &[DistinguishedName] {
+            &[]
+        }
+
+        fn verify_client_cert(
+            &self,
+            _end_entity: &CertificateDer<'_>,
+            _intermediates: &[CertificateDer<'_>],
+            _now: UnixTime,
+        ) -> Result<ClientCertVerified, rustls::Error> {
+            Ok(ClientCertVerified::assertion())
+        }
+
+        fn verify_tls12_signature(
+            &self,
+            message: &[u8],
+            cert: &CertificateDer<'_>,
+            dss: &DigitallySignedStruct,
+        ) -> Result<HandshakeSignatureValid, rustls::Error> {
+            verify_tls12_signature(message, cert, dss, &self.0.signature_verification_algorithms)
+        }
+
+        fn verify_tls13_signature(
+            &self,
+            message: &[u8],
+            cert: &CertificateDer<'_>,
+            dss: &DigitallySignedStruct,
+        ) -> Result<HandshakeSignatureValid, rustls::Error> {
+            verify_tls13_signature(message, cert, dss, &self.0.signature_verification_algorithms)
+        }
+
+        fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+            self.0.signature_verification_algorithms.supported_schemes()
+        }
+    }
+
+    /// Answer every request with `response` over TLS, recording the client certificates presented
+    async fn mtls_server(response: String) -> (u16, Arc<Mutex<Vec<CertificateDer<'static>>>>) {
+        let provider = Arc::new(ring::default_provider());
+        let key = PrivateKeyDer::from_pem_file(testdata("server.key")).unwrap();
+        let config = ServerConfig::builder_with_provider(provider.clone())
+            .with_safe_default_protocol_versions()
+            .unwrap()
+            .with_client_cert_verifier(Arc::new(AnyClientCert(provider)))
+            .with_single_cert(vec![certificate("server")], key)
+            .unwrap();
+        let acceptor = TlsAcceptor::from(Arc::new(config));
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let presented = Arc::new(Mutex::new(Vec::new()));
+        let recorded = presented.clone();
+        tokio::spawn(async move {
+            loop {
+                let (stream, _) = listener.accept().await.unwrap();
+                let Ok(mut stream) = acceptor.accept(stream).await else {
+                    continue;
+                };
+                if let Some(certs) = stream.get_ref().1.peer_certificates() {
+                    recorded.lock().unwrap().push(certs[0].clone().into_owned());
+                }
+                let mut buf = [0u8; 1024];
+                let _ = stream.read(&mut buf).await;
+                let _ = stream.write_all(response.as_bytes()).await;
+                let _ = stream.shutdown().await;
+            }
+        });
+        (port, presented)
+    }
+
+    #[test]
+    fn rewrites_like_browsers() {
+        let post = Method::POST;
+        assert_eq!(redirect_method(StatusCode::MOVED_PERMANENTLY, &post, true), Method::GET);
+        assert_eq!(redirect_method(StatusCode::FOUND, &post, true), Method::GET);
+        assert_eq!(redirect_method(StatusCode::SEE_OTHER, &Method::PUT, true), Method::GET);
+        assert_eq!(redirect_method(StatusCode::SEE_OTHER, &Method::HEAD, true), Method::HEAD);
+        assert_eq!(redirect_method(StatusCode::FOUND, &Method::PUT, true), Method::PUT);
+        assert_eq!(redirect_method(StatusCode::TEMPORARY_REDIRECT, &post, true), Method::POST);
+        assert_eq!(redirect_method(StatusCode::PERMANENT_REDIRECT, &post, true), Method::POST);
+    }
+
+    #[test]
+    fn keeps_method_when_rewrite_disabled() {
+        assert_eq!(redirect_method(StatusCode::FOUND, &Method::POST, false), Method::POST);
+        assert_eq!(redirect_method(StatusCode::SEE_OTHER, &Method::POST, false), Method::POST);
+    }
+
+    #[test]
+    fn strips_credentials_across_origins() {
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, HeaderValue::from_static("Bearer abc"));
+        headers.insert(USER_AGENT, HeaderValue::from_static("yaak"));
+
+        let a = Url::parse("https://example.com/a").unwrap();
+        let b = Url::parse("https://example.com:443/b").unwrap();
+        remove_sensitive_headers(&mut headers, &a, &b);
+        assert!(headers.contains_key(AUTHORIZATION));
+
+        let c = Url::parse("https://other.com/").unwrap();
+        remove_sensitive_headers(&mut headers, &b, &c);
+        assert!(!headers.contains_key(AUTHORIZATION));
+        assert!(headers.contains_key(USER_AGENT));
+    }
+
+    #[test]
+    fn records_sent_request() {
+        let url = Url::parse("https://example.com/a?b=c").unwrap();
+        let mut req = Request::new(Method::POST, url);
+        req.headers_mut().insert(AUTHORIZATION, HeaderValue::from_static("Bearer abc"));
+        *req.body_mut() = Some("x".repeat(5000).into());
+
+        let sent = sent_request(&req);
+        assert_eq!(sent.method, "POST");
+        assert_eq!(sent.url, "https://example.com/a?b=c");
+        assert_eq!(sent.headers[0].name, "authorization");
+        assert_eq!(sent.body_size, Some(5000));
+        assert_eq!(sent.body_preview.unwrap().len(), 2048);
+
+        let sent = sent_request(&Request::new(Method::GET, Url::parse("https://a.b").unwrap()));
+        assert_eq!(sent.body_size, Some(0));
+        assert_eq!(sent.body_preview, None);
+    }
+
+    #[tokio::test]
+    async fn presents_the_certificate_of_each_hop() {
+        let ok = "HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+        let (port_b, presented_b) = mtls_server(ok.to_string()).await;
+        let (port_a, presented_a) = mtls_server(format!(
+            "HTTP/1.1 302 Found\r\nLocation: https://127.0.0.1:{port_b}/\r\n\
+             Content-Length: 0\r\nConnection: close\r\n\r\n"
+        ))
+        .await;
+
+        let client_certificate = |host: &str, name: &str| ClientCertificate {
+            host: host.to_string(),
+            crt_file: Some(testdata(&format!("{name}.crt"))),
+            key_file: Some(testdata(&format!("{name}.key"))),
+            enabled: true,
+            ..Default::default()
+        };
+        let workspace = Workspace {
+            setting_validate_certificates: false,
+            setting_client_certificates: vec![
+                client_certificate("localhost", "client-a"),
+                client_certificate("127.0.0.1", "client-b"),
+            ],
+            ..Default::default()
+        };
+        let url = Url::parse(&format!("https://localhost:{port_a}/")).unwrap();
+        let settings = ClientSettings::new(&workspace, ProxyConfig::default());
+        let client = build_client(&settings).unwrap().client;
+        let redirects = RedirectSettings {
+            follow: true,
+            max_redirects: 1,
+            rewrite_method: true,
+        };
+
+        let request = client.get(url).build().unwrap();
+        let executed = execute_with_redirects(&client, request, &redirects, None).await.unwrap();
+        assert_eq!(executed.response.status(), 200);
+        assert_eq!(executed.redirects.len(), 1);
+        assert_eq!(*presented_a.lock().unwrap(), vec![certificate("client-a")]);
+        assert_eq!(*presented_b.lock().unwrap(), vec![certificate("client-b")]);
+    }
+}
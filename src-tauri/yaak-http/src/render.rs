@@ -0,0 +1,152 @@
+use crate::apply_path_placeholders;
+use crate::error::Result;
+use std::collections::BTreeMap;
+use yaak_models::models::{
+    DnsOverride, Environment, HttpRequest, HttpRequestAssertion, HttpRequestHeader,
+    HttpUrlParameter, SseRequest, Workspace,
+};
+use yaak_models::render::{InheritedSettings, make_request_vars_hashmap};
+use yaak_proxy::dns::DnsOverrides;
+use yaak_templates::{TemplateCallback, parse_and_render, render_json_value_raw};
+
+/// Render a request's templates, applying the headers, authentication and variables it inherits
+/// from its workspace and folders
+pub async fn render_http_request<T: TemplateCallback>(
+    r: &HttpRequest,
+    base_environment: &Environment,
+    environment: Option<&Environment>,
+    inherited: &InheritedSettings,
+    cb: &T,
+) -> Result<HttpRequest> {
+    let vars = &make_request_vars_hashmap(base_environment, inherited, environment);
+
+    let mut url_parameters = Vec::new();
+    for p in r.url_parameters.clone() {
+        url_parameters.push(HttpUrlParameter {
+            enabled: p.enabled,
+            name: parse_and_render(p.name.as_str(), vars, cb).await?,
+            value: parse_and_render(p.value.as_str(), vars, cb).await?,
+            id: p.id,
+        })
+    }
+
+    let mut headers = Vec::new();
+    for p in inherited.merge_headers(&r.headers) {
+        headers.push(HttpRequestHeader {
+            enabled: p.enabled,
+            name: parse_and_render(p.name.as_str(), vars, cb).await?,
+            value: parse_and_render(p.value.as_str(), vars, cb).await?,
+            id: p.id,
+        })
+    }
+
+    let mut body = BTreeMap::new();
+    for (k, v) in r.body.clone() {
+        body.insert(k, render_json_value_raw(v, vars, cb).await?);
+    }
+
+    let (authentication_type, unrendered_authentication) =
+        inherited.resolve_authentication(&r.authentication_type, &r.authentication);
+    let mut authentication = BTreeMap::new();
+    for (k, v) in unrendered_authentication {
+        authentication.insert(k, render_json_value_raw(v, vars, cb).await?);
+    }
+
+    let mut assertions = Vec::new();
+    for a in r.assertions.clone() {
+        assertions.push(HttpRequestAssertion {
+            property: parse_and_render(a.property.as_str(), vars, cb).await?,
+            value: parse_and_render(a.value.as_str(), vars, cb).await?,
+            ..a
+        })
+    }
+
+    let url = parse_and_render(r.url.clone().as_str(), vars, cb).await?;
+
+    // This doesn't fit perfectly with the concept of "rendering" but it kind of does
+    let (url, url_parameters) = apply_path_placeholders(&url, url_parameters);
+
+    Ok(HttpRequest {
+        url,
+        url_parameters,
+        headers,
+        body,
+        authentication,
+        authentication_type,
+        assertions,
+        ..r.to_owned()
+    })
+}
+
+/// Render an SSE request's templates, applying what it inherits from its workspace and folders
+pub async fn render_sse_request<T: TemplateCallback>(
+    r: &SseRequest,
+    base_environment: &Environment,
+    environment: Option<&Environment>,
+    inherited: &InheritedSettings,
+    cb: &T,
+) -> Result<SseRequest> {
+    let vars = &make_request_vars_hashmap(base_environment, inherited, environment);
+
+    let mut url_parameters = Vec::new();
+    for p in r.url_parameters.clone() {
+        url_parameters.push(HttpUrlParameter {
+            enabled: p.enabled,
+            name: parse_and_render(p.name.as_str(), vars, cb).await?,
+            value: parse_and_render(p.value.as_str(), vars, cb).await?,
+            id: p.id,
+        })
+    }
+
+    let mut headers = Vec::new();
+    for p in inherited.merge_headers(&r.headers) {
+        headers.push(HttpRequestHeader {
+            enabled: p.enabled,
+            name: parse_and_render(p.name.as_str(), vars, cb).await?,
+            value: parse_and_render(p.value.as_str(), vars, cb).await?,
+            id: p.id,
+        })
+    }
+
+    let (authentication_type, unrendered_authentication) =
+        inherited.resolve_authentication(&r.authentication_type, &r.authentication);
+    let mut authentication = BTreeMap::new();
+    for (k, v) in unrendered_authentication {
+        authentication.insert(k, render_json_value_raw(v, vars, cb).await?);
+    }
+
+    let url = parse_and_render(r.url.clone().as_str(), vars, cb).await?;
+    let (url, url_parameters) = apply_path_placeholders(&url, url_parameters);
+
+    Ok(SseRequest {
+        url,
+        url_parameters,
+        headers,
+        authentication,
+        authentication_type,
+        ..r.to_owned()
+    })
+}
+
+/// Render the workspace's DNS overrides with the variables available to a request. This is shared
+/// by every transport, so each resolves hosts the same way.
+pub async fn render_dns_overrides<T: TemplateCallback>(
+    workspace: &Workspace,
+    base_environment: &Environment,
+    environment: Option<&Environment>,
+    inherited: &InheritedSettings,
+    cb: &T,
+) -> Result<DnsOverrides> {
+    let vars = &make_request_vars_hashmap(base_environment, inherited, environment);
+
+    let mut overrides = Vec::new();
+    for o in workspace.setting_dns_overrides.iter().filter(|o| o.enabled) {
+        overrides.push(DnsOverride {
+            host: parse_and_render(o.host.as_str(), vars, cb).await?,
+            address: parse_and_render(o.address.as_str(), vars, cb).await?,
+            ..o.to_owned()
+        })
+    }
+
+    Ok(DnsOverrides::new(&overrides)?)
+}
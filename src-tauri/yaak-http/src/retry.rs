@@ -0,0 +1,275 @@
+use crate::error::Error::{GenericError, RequestError};
+use crate::error::{Error, Result};
+use crate::redirects::{ExecutedRequest, RedirectSettings, execute_with_redirects};
+use http::header::RETRY_AFTER;
+use log::{debug, warn};
+use rand::Rng;
+use reqwest::{Client, Request, Response};
+use reqwest_cookie_store::CookieStoreMutex;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
+use tokio::sync::watch::Receiver;
+use yaak_models::models::{HttpRequestRetry, HttpResponseAttempt, HttpRetryError};
+
+pub struct RetriedRequest {
+    pub result: Result<ExecutedRequest>,
+    /// Every attempt that was made, including the last one. Empty when retries are disabled.
+    pub attempts: Vec<HttpResponseAttempt>,
+}
+
+/// Execute a request, re-sending it according to the retry policy when it fails with a
+/// retryable status or error. Responses that are retried are discarded without reading them.
+pub async fn execute_with_retries(
+    client: &Client,
+    request: Request,
+    redirect_settings: &RedirectSettings,
+    cookie_store: Option<Arc<CookieStoreMutex>>,
+    retry: &HttpRequestRetry,
+    mut cancelled_rx: Receiver<bool>,
+) -> RetriedRequest {
+    if !retry.enabled || retry.max_attempts <= 1 {
+        let result = execute_with_redirects(client, request, redirect_settings, cookie_store).await;
+        return RetriedRequest {
+            result,
+            attempts: Vec::new(),
+        };
+    }
+
+    let mut attempts = Vec::new();
+    let mut request = request;
+    loop {
+        // Keep a copy for the next attempt, which isn't possible for streamed bodies
+        let retained = request.try_clone();
+        let start = Instant::now();
+        let result =
+            execute_with_redirects(client, request, redirect_settings, cookie_store.clone()).await;
+
+        let mut attempt = HttpResponseAttempt {
+            elapsed: start.elapsed().as_millis() as i32,
+            ..Default::default()
+        };
+        let retryable = match &result {
+            Ok(r) => {
+                attempt.status = r.response.status().as_u16() as i32;
+                retry.status_codes.contains(&attempt.status)
+            }
+            Err(e) => {
+                attempt.error = Some(e.to_string());
+                retry.errors.contains(&error_kind(e))
+            }
+        };
+
+        let is_last = attempts.len() + 1 >= retry.max_attempts as usize;
+        let next = match (retryable && !is_last, retained) {
+            (true, Some(r)) => r,
+            (should_retry, _) => {
+                if should_retry {
+                    let reason = "Not retried, because the request body can't be re-sent";
+                    warn!("{reason}");
+                    attempt.error = Some(match attempt.error {
+                        Some(e) => format!("{e}. {reason}"),
+                        None => reason.to_string(),
+                    });
+                }
+                attempts.push(attempt);
+                return RetriedRequest { result, attempts };
+            }
+        };
+
+        // This attempt hasn't been pushed yet, so the retry number is one more than the count
+        let backoff = backoff_delay(retry, attempts.len() as u32 + 1);
+        // Don't wait longer than allowed, even when the server asks for it
+        let max_delay = Duration::from_millis(retry.max_delay.max(0) as u64);
+        let delay = match result.as_ref().ok().and_then(|r| retry_after(&r.response)) {
+            Some(d) if retry.respect_retry_after => d.min(max_delay),
+            _ => backoff,
+        };
+
+        attempt.delay = delay.as_millis() as i32;
+        attempts.push(attempt);
+        debug!("Retrying request in {delay:?} (attempt {})", attempts.len() + 1);
+
+        // Dropping the result discards the response, along with its connection
+        drop(result);
+        tokio::select! {
+            _ = tokio::time::sleep(delay) => {}
+            _ = cancelled_rx.changed() => {
+                return RetriedRequest {
+                    result: Err(GenericError("Request was cancelled".to_string())),
+                    attempts,
+                };
+            }
+        }
+
+        request = next;
+    }
+}
+
+fn error_kind(e: &Error) -> HttpRetryError {
+    match e {
+        RequestError(e) if e.is_connect() => HttpRetryError::Connect,
+        RequestError(e) if e.is_timeout() => HttpRetryError::Timeout,
+        _ => HttpRetryError::Other,
+    }
+}
+
+/// Exponential backoff with "equal jitter": somewhere between half and all of the
+/// exponential delay, so that clients retrying at the same time spread out.
+fn backoff_delay(retry: &HttpRequestRetry, retries: u32) -> Duration {
+    let initial = retry.initial_delay.max(0) as u64;
+    let max = retry.max_delay.max(0) as u64;
+    let exponential = initial.saturating_mul(2u64.saturating_pow(retries.saturating_sub(1)));
+    let capped = exponential.min(max);
+    Duration::from_millis(capped / 2 + rand::rng().random_range(0..=capped - capped / 2))
+}
+
+/// The delay a response asks for with Retry-After, given either in seconds or as a date
+fn retry_after(response: &Response) -> Option<Duration> {
+    let value = response.headers().get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(date.duration_since(SystemTime::now()).unwrap_or_default())
+}
+
+#[cfg(test)]
+mod retry_tests {
+    use crate::redirects::RedirectSettings;
+    use crate::retry::execute_with_retries;
+    use reqwest::{Client, Method};
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use tokio::sync::watch;
+    use yaak_models::models::HttpRequestRetry;
+
+    /// Serve 503 (with `failure_headers`) for the first `failures` requests and 200 after that,
+    /// recording when each request arrived
+    async fn flaky_server(
+        failures: usize,
+        failure_headers: &'static str,
+    ) -> (String, Arc<Mutex<Vec<Instant>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let arrivals = Arc::new(Mutex::new(Vec::new()));
+        let recorded = arrivals.clone();
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut buf = [0u8; 1024];
+                let _ = stream.read(&mut buf).await;
+                let count = {
+                    let mut arrivals = recorded.lock().unwrap();
+                    arrivals.push(Instant::now());
+                    arrivals.len()
+                };
+                let (status, headers) = match count <= failures {
+                    true => ("503 Service Unavailable", failure_headers),
+                    false => ("200 OK", ""),
+                };
+                let resp = format!(
+                    "HTTP/1.1 {status}\r\n{headers}Content-Length: 0\r\nConnection: close\r\n\r\n"
+                );
+                let _ = stream.write_all(resp.as_bytes()).await;
+            }
+        });
+        (url, arrivals)
+    }
+
+    #[tokio::test]
+    async fn backoff_doubles_between_attempts() {
+        let (url, arrivals) = flaky_server(3, "").await;
+        let retry = HttpRequestRetry {
+            enabled: true,
+            max_attempts: 4,
+            status_codes: vec![503],
+            initial_delay: 100,
+            max_delay: 1000,
+            ..Default::default()
+        };
+        let redirects = RedirectSettings {
+            follow: false,
+            max_redirects: 0,
+            rewrite_method: false,
+        };
+        let (_cancel_tx, cancel_rx) = watch::channel(false);
+
+        let client = Client::new();
+        let request = client.request(Method::GET, &url).build().unwrap();
+        let result =
+            execute_with_retries(&client, request, &redirects, None, &retry, cancel_rx).await;
+
+        assert_eq!(result.result.unwrap().response.status(), 200);
+        let statuses = result.attempts.iter().map(|a| a.status).collect::<Vec<_>>();
+        assert_eq!(statuses, vec![503, 503, 503, 200]);
+
+        let arrivals = arrivals.lock().unwrap();
+        for (i, expected) in [100, 200, 400].into_iter().enumerate() {
+            let delay = result.attempts[i].delay as u64;
+            assert!(delay >= expected / 2 && delay <= expected, "retry {}: {delay}ms", i + 1);
+            let gap = arrivals[i + 1] - arrivals[i];
+            assert!(gap >= Duration::from_millis(delay), "retry {}: {gap:?}", i + 1);
+        }
+    }
+
+    #[tokio::test]
+    async fn records_why_streamed_bodies_are_not_retried() {
+        let (url, arrivals) = flaky_server(1, "").await;
+        let retry = HttpRequestRetry {
+            enabled: true,
+            status_codes: vec![503],
+            initial_delay: 10,
+            ..Default::default()
+        };
+        let redirects = RedirectSettings {
+            follow: false,
+            max_redirects: 0,
+            rewrite_method: false,
+        };
+        let (_cancel_tx, cancel_rx) = watch::channel(false);
+
+        let client = Client::new();
+        let body = reqwest::Body::wrap_stream(futures_util::stream::iter(vec![Ok::<
+            _,
+            std::io::Error,
+        >("hello")]));
+        let request = client.request(Method::POST, &url).body(body).build().unwrap();
+        let result =
+            execute_with_retries(&client, request, &redirects, None, &retry, cancel_rx).await;
+
+        assert_eq!(result.result.unwrap().response.status(), 503);
+        assert_eq!(result.attempts.len(), 1);
+        assert!(result.attempts[0].error.as_deref().unwrap().contains("can't be re-sent"));
+        assert_eq!(arrivals.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn waits_at_most_the_max_delay_for_retry_after() {
+        let (url, arrivals) = flaky_server(1, "Retry-After: 3600\r\n").await;
+        let retry = HttpRequestRetry {
+            enabled: true,
+            status_codes: vec![503],
+            respect_retry_after: true,
+            max_delay: 50,
+            ..Default::default()
+        };
+        let redirects = RedirectSettings {
+            follow: false,
+            max_redirects: 0,
+            rewrite_method: false,
+        };
+        let (_cancel_tx, cancel_rx) = watch::channel(false);
+
+        let client = Client::new();
+        let request = client.request(Method::GET, &url).build().unwrap();
+        let result =
+            execute_with_retries(&client, request, &redirects, None, &retry, cancel_rx).await;
+
+        assert_eq!(result.result.unwrap().response.status(), 200);
+        assert_eq!(result.attempts.len(), 2);
+        assert_eq!(result.attempts[0].delay, 50);
+        assert_eq!(arrivals.lock().unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,247 @@
+use crate::auth::AuthenticationCallback;
+use crate::error::Result;
+use crate::hooks::HttpHooksCallback;
+use crate::sender::{HttpSender, response_err};
+use std::cmp::Ordering;
+use std::time::{Duration, Instant};
+use tokio::sync::watch::Receiver;
+use yaak_models::models::{
+    CollectionRun, CollectionRunResult, CollectionRunState, Environment, Folder, HttpRequest,
+    HttpResponse,
+};
+use yaak_templates::TemplateCallback;
+
+/// Send every HTTP request in the run's folder (or workspace), in sidebar order, updating the
+/// run record after each response so progress can be observed while it runs.
+pub async fn run_collection<
+    T: TemplateCallback,
+    A: AuthenticationCallback,
+    H: HttpHooksCallback,
+>(
+    sender: &HttpSender<T, A, H>,
+    run: &CollectionRun,
+    environment: Option<&Environment>,
+    cancelled_rx: &mut Receiver<bool>,
+) -> Result<CollectionRun> {
+    let qm = &sender.query_manager;
+    let update_source = &sender.update_source;
+    let requests = {
+        let db = qm.connect();
+        let folders = db.list_folders(&run.workspace_id)?;
+        let http_requests = db.list_http_requests(&run.workspace_id)?;
+        collect_http_requests(run.folder_id.as_deref(), &folders, &http_requests)
+    };
+
+    let start = Instant::now();
+    let mut run = qm.connect().upsert_collection_run(
+        &CollectionRun {
+            state: CollectionRunState::Running,
+            results: Vec::new(),
+            ..run.clone()
+        },
+        update_source,
+    )?;
+
+    let result = run_requests(sender, &mut run, &requests, environment, cancelled_rx, start).await;
+
+    // Always finalize the run, even if something failed part way, so it isn't left running
+    run.elapsed = start.elapsed().as_millis() as i32;
+    run.state = match result {
+        Ok(true) => CollectionRunState::Cancelled,
+        _ if *cancelled_rx.borrow() => CollectionRunState::Cancelled,
+        Ok(false) if run.results.iter().all(|r| r.passed) => CollectionRunState::Passed,
+        _ => CollectionRunState::Failed,
+    };
+    let run = qm.connect().upsert_collection_run(&run, update_source)?;
+
+    result?;
+    Ok(run)
+}
+
+/// Send the requests for every iteration, returning whether the run was cancelled
+async fn run_requests<T: TemplateCallback, A: AuthenticationCallback, H: HttpHooksCallback>(
+    sender: &HttpSender<T, A, H>,
+    run: &mut CollectionRun,
+    requests: &[HttpRequest],
+    environment: Option<&Environment>,
+    cancelled_rx: &mut Receiver<bool>,
+    start: Instant,
+) -> Result<bool> {
+    for iteration in 0..run.iterations.max(1) {
+        for (i, request) in requests.iter().enumerate() {
+            if (iteration > 0 || i > 0) && run.delay > 0 {
+                let delay = Duration::from_millis(run.delay as u64);
+                tokio::select! {
+                    _ = tokio::time::sleep(delay) => {},
+                    _ = cancelled_rx.changed() => {},
+                }
+            }
+
+            if *cancelled_rx.borrow() {
+                return Ok(true);
+            }
+
+            let result = match send_request(sender, run, request, environment, cancelled_rx).await {
+                Ok(response) => CollectionRunResult {
+                    request_id: request.id.clone(),
+                    response_id: response.id.clone(),
+                    iteration,
+                    passed: response_passed(&response),
+                    status: response.status,
+                    elapsed: response.elapsed,
+                    error: response.error.clone(),
+                },
+                // Count it as a failed request instead of abandoning the rest of the run
+                Err(e) => CollectionRunResult {
+                    request_id: request.id.clone(),
+                    iteration,
+                    passed: false,
+                    error: Some(e.to_string()),
+                    ..Default::default()
+                },
+            };
+            let passed = result.passed;
+
+            run.results.push(result);
+            run.elapsed = start.elapsed().as_millis() as i32;
+            *run =
+                sender.query_manager.connect().upsert_collection_run(run, &sender.update_source)?;
+
+            if !passed && run.stop_on_failure {
+                return Ok(false);
+            }
+        }
+    }
+
+    Ok(false)
+}
+
+/// Create a response for the request and send it. Send errors are stored on the response, like
+/// they are when sending a single request.
+async fn send_request<T: TemplateCallback, A: AuthenticationCallback, H: HttpHooksCallback>(
+    sender: &HttpSender<T, A, H>,
+    run: &CollectionRun,
+    request: &HttpRequest,
+    environment: Option<&Environment>,
+    cancelled_rx: &mut Receiver<bool>,
+) -> Result<HttpResponse> {
+    let qm = &sender.query_manager;
+    let update_source = &sender.update_source;
+
+    // Fetch the cookie jar each time so cookies set by previous requests are sent
+    let (response, cookie_jar) = {
+        let db = qm.connect();
+        let cookie_jar = match &run.cookie_jar_id {
+            Some(id) => Some(db.get_cookie_jar(id)?),
+            None => None,
+        };
+        let response = db.upsert_http_response(
+            &HttpResponse {
+                request_id: request.id.clone(),
+                workspace_id: request.workspace_id.clone(),
+                ..Default::default()
+            },
+            update_source,
+        )?;
+        (response, cookie_jar)
+    };
+
+    match sender.send(request, &response, environment, cookie_jar, cancelled_rx).await {
+        Ok(response) => Ok(response),
+        Err(e) => Ok(response_err(qm, &response, e.to_string(), update_source)),
+    }
+}
+
+fn response_passed(response: &HttpResponse) -> bool {
+    response.error.is_none()
+        && response.status > 0
+        && response.status < 400
+        && response.assertion_results.iter().all(|a| a.passed)
+}
+
+enum TreeItem<'a> {
+    Folder(&'a Folder),
+    Request(&'a HttpRequest),
+}
+
+/// Flatten the folder tree below `folder_id` (or the workspace root) into the order that
+/// requests appear in the sidebar.
+fn collect_http_requests(
+    folder_id: Option<&str>,
+    folders: &[Folder],
+    http_requests: &[HttpRequest],
+) -> Vec<HttpRequest> {
+    let mut children: Vec<(f64, TreeItem)> = Vec::new();
+    for f in folders.iter().filter(|f| f.folder_id.as_deref() == folder_id) {
+        children.push((f.sort_priority as f64, TreeItem::Folder(f)));
+    }
+    for r in http_requests.iter().filter(|r| r.folder_id.as_deref() == folder_id) {
+        children.push((r.sort_priority, TreeItem::Request(r)));
+    }
+    children.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+
+    let mut requests = Vec::new();
+    for (_, item) in children {
+        match item {
+            TreeItem::Folder(f) => {
+                requests.append(&mut collect_http_requests(Some(&f.id), folders, http_requests))
+            }
+            TreeItem::Request(r) => requests.push(r.clone()),
+        }
+    }
+
+    requests
+}
+
+#[cfg(test)]
+mod runner_tests {
+    use crate::runner::collect_http_requests;
+    use yaak_models::models::{Folder, HttpRequest};
+
+    fn folder(id: &str, folder_id: Option<&str>, sort_priority: f32) -> Folder {
+        Folder {
+            id: id.into(),
+            folder_id: folder_id.map(|s| s.into()),
+            sort_priority,
+            ..Default::default()
+        }
+    }
+
+    fn request(id: &str, folder_id: Option<&str>, sort_priority: f64) -> HttpRequest {
+        HttpRequest {
+            id: id.into(),
+            folder_id: folder_id.map(|s| s.into()),
+            sort_priority,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn collect_in_sidebar_order() {
+        let folders = vec![folder("f1", None, 1.0), folder("f2", Some("f1"), 0.0)];
+        let requests = vec![
+            request("r1", None, 2.0),
+            request("r2", None, 0.0),
+            request("r3", Some("f1"), 1.0),
+            request("r4", Some("f2"), 0.0),
+        ];
+
+        let ids = collect_http_requests(None, &folders, &requests)
+            .into_iter()
+            .map(|r| r.id)
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec!["r2", "r4", "r3", "r1"]);
+    }
+
+    #[test]
+    fn collect_from_folder() {
+        let folders = vec![folder("f1", None, 1.0), folder("f2", Some("f1"), 0.0)];
+        let requests = vec![request("r1", None, 0.0), request("r2", Some("f2"), 0.0)];
+
+        let ids = collect_http_requests(Some("f1"), &folders, &requests)
+            .into_iter()
+            .map(|r| r.id)
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec!["r2"]);
+    }
+}
@@ -0,0 +1,998 @@
+use crate::assertions::evaluate_assertions;
+use crate::auth::{AuthenticationCallback, AuthenticationRequest, AuthenticationRequestType};
+use crate::client_pool::{ClientSettings, HttpClientPool, PooledClient, build_client};
+use crate::error::Error::GenericError;
+use crate::error::Result;
+use crate::hooks::HttpHooksCallback;
+use crate::progress::{PROGRESS_INTERVAL, ProgressThrottle};
+use crate::redirects::{ExecutedRequest, RedirectSettings};
+use crate::render::{render_dns_overrides, render_http_request};
+use crate::retry::{RetriedRequest, execute_with_retries};
+use crate::timing::{ConnectionTimer, timed};
+use crate::unix_socket::{UNIX_SCHEME, UnixSocketTarget};
+use crate::upload::{BUFFERED_BODY_LIMIT, UploadProgress, file_body};
+use futures_util::TryStreamExt;
+use http::header::{ACCEPT, CONTENT_LENGTH, CONTENT_TYPE, USER_AGENT};
+use http::{HeaderMap, HeaderName, HeaderValue};
+use log::{debug, error, warn};
+use mime_guess::Mime;
+use reqwest::Method;
+use reqwest::{Url, multipart};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::fs;
+use tokio::fs::{File, create_dir_all};
+use tokio::io::{AsyncWriteExt, BufWriter};
+use tokio::sync::watch::Receiver;
+use tokio::sync::{Mutex, oneshot};
+use tokio::time::sleep_until;
+use yaak_models::models::{
+    Cookie, CookieJar, Environment, HttpRequest, HttpResponse, HttpResponseEvent,
+    HttpResponseHeader, HttpResponseState, HttpVersion, ProxySetting, Workspace,
+};
+use yaak_models::query_manager::QueryManager;
+use yaak_models::util::UpdateSource;
+use yaak_proxy::config::ProxyConfig;
+use yaak_proxy::dns::DnsOverrides;
+use yaak_sse::parser::EventStreamParser;
+use yaak_templates::TemplateCallback;
+
+/// Size of the buffer that response bodies are written to disk through
+const BODY_BUFFER_SIZE: usize = 256 * 1024;
+
+/// Everything besides the request itself that is needed to send it
+#[derive(Debug, Clone)]
+pub struct SendContext {
+    pub workspace: Workspace,
+    pub proxy: Option<ProxySetting>,
+    pub cookie_jar: Option<CookieJar>,
+    /// Directory that response bodies are written to
+    pub response_dir: PathBuf,
+    pub update_source: UpdateSource,
+    pub client_pool: HttpClientPool,
+    /// Use a new client instead of the pooled one, to get cold connection timings
+    pub fresh_connection: bool,
+    /// Rendered addresses to connect to instead of the ones DNS returns
+    pub dns_overrides: DnsOverrides,
+}
+
+/// Renders and sends requests from the database, using the given callbacks for template
+/// functions, authentication, and pre/post request hooks.
+#[derive(Clone)]
+pub struct HttpSender<T, A, H> {
+    pub query_manager: QueryManager,
+    pub response_dir: PathBuf,
+    pub update_source: UpdateSource,
+    pub client_pool: HttpClientPool,
+    pub fresh_connection: bool,
+    pub template_callback: T,
+    pub auth_callback: A,
+    pub hooks_callback: H,
+}
+
+impl<T: TemplateCallback, A: AuthenticationCallback, H: HttpHooksCallback> HttpSender<T, A, H> {
+    pub async fn send(
+        &self,
+        unrendered_request: &HttpRequest,
+        og_response: &HttpResponse,
+        environment: Option<&Environment>,
+        cookie_jar: Option<CookieJar>,
+        cancelled_rx: &mut Receiver<bool>,
+    ) -> Result<HttpResponse> {
+        let (settings, workspace, base_environment, inherited) = {
+            let db = self.query_manager.connect();
+            let settings = db.get_settings();
+            let base_environment = db.get_base_environment(&unrendered_request.workspace_id)?;
+            let inherited = db.resolve_inherited_settings(
+                &unrendered_request.workspace_id,
+                unrendered_request.folder_id.as_deref(),
+            )?;
+            // Settings come from the workspace, unless the request or one of its folders
+            // overrides them
+            let workspace = inherited.resolve_workspace(
+                &db.get_workspace(&unrendered_request.workspace_id)?,
+                unrendered_request,
+            );
+            (settings, workspace, base_environment, inherited)
+        };
+
+        let request = match render_http_request(
+            unrendered_request,
+            &base_environment,
+            environment,
+            &inherited,
+            &self.template_callback,
+        )
+        .await
+        {
+            Ok(r) => r,
+            Err(e) => {
+                return Ok(response_err(
+                    &self.query_manager,
+                    og_response,
+                    e.to_string(),
+                    &self.update_source,
+                ));
+            }
+        };
+
+        let dns_overrides = match render_dns_overrides(
+            &workspace,
+            &base_environment,
+            environment,
+            &inherited,
+            &self.template_callback,
+        )
+        .await
+        {
+            Ok(o) => o,
+            Err(e) => {
+                return Ok(response_err(
+                    &self.query_manager,
+                    og_response,
+                    e.to_string(),
+                    &self.update_source,
+                ));
+            }
+        };
+
+        let request = match self.hooks_callback.pre_request(request).await {
+            Ok(r) => r,
+            Err(e) => {
+                return Ok(response_err(
+                    &self.query_manager,
+                    og_response,
+                    e.to_string(),
+                    &self.update_source,
+                ));
+            }
+        };
+
+        let ctx = SendContext {
+            workspace,
+            proxy: settings.proxy,
+            cookie_jar,
+            response_dir: self.response_dir.clone(),
+            update_source: self.update_source.clone(),
+            client_pool: self.client_pool.clone(),
+            fresh_connection: self.fresh_connection,
+            dns_overrides,
+        };
+
+        let response = send_http_request(
+            &self.query_manager,
+            &request,
+            og_response,
+            ctx,
+            &self.auth_callback,
+            cancelled_rx,
+        )
+        .await?;
+
+        // A failing hook shouldn't fail a request that was already sent
+        if let Err(e) = self.hooks_callback.post_response(&request, &response).await {
+            warn!("Post-response hook failed for {}: {e}", request.id);
+        }
+
+        Ok(response)
+    }
+}
+
+/// Send an already-rendered request, streaming the response body to disk and keeping the
+/// response model up to date in the database as it progresses.
+pub async fn send_http_request<A: AuthenticationCallback>(
+    query_manager: &QueryManager,
+    request: &HttpRequest,
+    og_response: &HttpResponse,
+    ctx: SendContext,
+    auth: &A,
+    cancelled_rx: &mut Receiver<bool>,
+) -> Result<HttpResponse> {
+    let SendContext {
+        workspace,
+        proxy,
+        cookie_jar,
+        response_dir,
+        update_source,
+        client_pool,
+        fresh_connection,
+        dns_overrides,
+    } = ctx;
+
+    let response_id = og_response.id.clone();
+    let response = Arc::new(Mutex::new(og_response.clone()));
+
+    let mut url_string = ensure_proto(&request.url);
+    debug!("Sending request to {} {url_string}", request.method);
+
+    // Requests to a Unix socket are sent as plain HTTP over it, with the socket set on the client
+    let unix_socket = match UnixSocketTarget::parse(&url_string) {
+        Ok(Some(target)) => {
+            url_string = target.url;
+            Some(target.socket_path)
+        }
+        Ok(None) => None,
+        Err(e) => {
+            return Ok(response_err(
+                query_manager,
+                &*response.lock().await,
+                e.to_string(),
+                &update_source,
+            ));
+        }
+    };
+
+    let url = match Url::from_str(&url_string) {
+        Ok(u) => u,
+        Err(e) => {
+            return Ok(response_err(
+                query_manager,
+                &*response.lock().await,
+                format!("Failed to parse URL \"{}\": {}", url_string, e.to_string()),
+                &update_source,
+            ));
+        }
+    };
+
+    let proxy = match ProxyConfig::load(proxy).await {
+        Ok(p) => p,
+        Err(e) => {
+            return Ok(response_err(
+                query_manager,
+                &*response.lock().await,
+                e.to_string(),
+                &update_source,
+            ));
+        }
+    };
+
+    let redirect_settings = RedirectSettings::from_workspace(&workspace);
+    let max_response_size = match workspace.setting_max_response_size {
+        n if n > 0 => Some(n as usize),
+        _ => None,
+    };
+    let client_settings = ClientSettings {
+        unix_socket,
+        dns_overrides,
+        http_version: request.http_version,
+        ..ClientSettings::new(&workspace, proxy)
+    };
+    if let Err(e) = client_settings.check_proxy(&url) {
+        return Ok(response_err(
+            query_manager,
+            &*response.lock().await,
+            e.to_string(),
+            &update_source,
+        ));
+    }
+    let client = match fresh_connection {
+        true => build_client(&client_settings),
+        false => client_pool.get(&client_settings),
+    };
+    let PooledClient {
+        client,
+        connections,
+    } = match client {
+        Ok(c) => c,
+        Err(e) => {
+            return Ok(response_err(
+                query_manager,
+                &*response.lock().await,
+                e.to_string(),
+                &update_source,
+            ));
+        }
+    };
+
+    // Cookies are sent and stored by execute_with_redirects, rather than by the (shared) client
+    let maybe_cookie_manager = match cookie_jar.clone() {
+        Some(cj) => {
+            // HACK: Can't construct Cookie without serde, so we have to do this
+            let cookies = cj
+                .cookies
+                .iter()
+                .map(|cookie| {
+                    let json_cookie = serde_json::to_value(cookie).unwrap();
+                    serde_json::from_value(json_cookie).expect("Failed to deserialize cookie")
+                })
+                .map(|c| Ok(c))
+                .collect::<Vec<Result<_>>>();
+
+            let store = reqwest_cookie_store::CookieStore::from_cookies(cookies, true)?;
+            let cookie_store = reqwest_cookie_store::CookieStoreMutex::new(store);
+            let cookie_store = Arc::new(cookie_store);
+
+            Some((cookie_store, cj))
+        }
+        None => None,
+    };
+
+    // Render query parameters
+    let mut query_params = Vec::new();
+    for p in request.url_parameters.clone() {
+        if !p.enabled || p.name.is_empty() {
+            continue;
+        }
+        query_params.push((p.name, p.value));
+    }
+
+    let m = Method::from_str(&request.method.to_uppercase())
+        .map_err(|e| GenericError(e.to_string()))?;
+    let mut request_builder = client.request(m, url).query(&query_params);
+
+    // reqwest only sends requests that ask for HTTP/3 over QUIC
+    if request.http_version == HttpVersion::Http3 {
+        request_builder = request_builder.version(reqwest::Version::HTTP_3);
+    }
+
+    let mut headers = HeaderMap::new();
+    headers.insert(USER_AGENT, HeaderValue::from_static("yaak"));
+    headers.insert(ACCEPT, HeaderValue::from_static("*/*"));
+
+    for h in request.headers.clone() {
+        if h.name.is_empty() && h.value.is_empty() {
+            continue;
+        }
+
+        if !h.enabled {
+            continue;
+        }
+
+        let header_name = match HeaderName::from_str(&h.name) {
+            Ok(n) => n,
+            Err(e) => {
+                error!("Failed to create header name: {}", e);
+                continue;
+            }
+        };
+        let header_value = match HeaderValue::from_str(&h.value) {
+            Ok(n) => n,
+            Err(e) => {
+                error!("Failed to create header value: {}", e);
+                continue;
+            }
+        };
+
+        headers.insert(header_name, header_value);
+    }
+
+    // Large files are streamed from disk, rather than read into memory, and their progress reported
+    let (upload_progress, uploaded_rx) = UploadProgress::new();
+    let mut upload_size: Option<u64> = None;
+
+    let request_body = request.body.clone();
+    if let Some(body_type) = &request.body_type {
+        if body_type == "graphql" {
+            let query = get_str_h(&request_body, "query");
+            let variables = get_str_h(&request_body, "variables");
+            let body = if variables.trim().is_empty() {
+                format!(r#"{{"query":{}}}"#, serde_json::to_string(query).unwrap_or_default())
+            } else {
+                format!(
+                    r#"{{"query":{},"variables":{variables}}}"#,
+                    serde_json::to_string(query).unwrap_or_default()
+                )
+            };
+            request_builder = request_builder.body(body.to_owned());
+        } else if body_type == "application/x-www-form-urlencoded"
+            && request_body.contains_key("form")
+        {
+            let mut form_params = Vec::new();
+            let form = request_body.get("form");
+            if let Some(f) = form {
+                match f.as_array() {
+                    None => {}
+                    Some(a) => {
+                        for p in a {
+                            let enabled = get_bool(p, "enabled", true);
+                            let name = get_str(p, "name");
+                            if !enabled || name.is_empty() {
+                                continue;
+                            }
+                            let value = get_str(p, "value");
+                            form_params.push((name, value));
+                        }
+                    }
+                }
+            }
+            request_builder = request_builder.form(&form_params);
+        } else if body_type == "binary" && request_body.contains_key("filePath") {
+            let file_path = request_body
+                .get("filePath")
+                .ok_or(GenericError("filePath not set".to_string()))?
+                .as_str()
+                .unwrap_or_default();
+
+            let body = file_body(file_path, &upload_progress, BUFFERED_BODY_LIMIT).await;
+            match body.map_err(|e| e.to_string()) {
+                Ok((body, size)) => {
+                    // Streamed bodies would otherwise be sent with chunked encoding
+                    if !headers.contains_key(CONTENT_LENGTH) {
+                        headers.insert(CONTENT_LENGTH, HeaderValue::from(size));
+                    }
+                    upload_size = Some(size);
+                    request_builder = request_builder.body(body);
+                }
+                Err(e) => {
+                    return Ok(response_err(
+                        query_manager,
+                        &*response.lock().await,
+                        e,
+                        &update_source,
+                    ));
+                }
+            }
+        } else if body_type == "multipart/form-data" && request_body.contains_key("form") {
+            let mut multipart_form = multipart::Form::new();
+            // Files are buffered until they add up to the limit, and streamed after that
+            let mut buffer_budget = BUFFERED_BODY_LIMIT;
+            let mut streamed = false;
+            if let Some(form_definition) = request_body.get("form") {
+                match form_definition.as_array() {
+                    None => {}
+                    Some(fd) => {
+                        for p in fd {
+                            let enabled = get_bool(p, "enabled", true);
+                            let name = get_str(p, "name").to_string();
+
+                            if !enabled || name.is_empty() {
+                                continue;
+                            }
+
+                            let file_path = get_str(p, "file").to_owned();
+                            let value = get_str(p, "value").to_owned();
+
+                            let mut part = if file_path.is_empty() {
+                                multipart::Part::text(value.clone())
+                            } else {
+                                match file_body(&file_path, &upload_progress, buffer_budget).await {
+                                    Ok((body, size)) => {
+                                        // The file may have grown past the budget since its
+                                        // size was checked
+                                        match body.as_bytes() {
+                                            Some(_) => {
+                                                buffer_budget = buffer_budget.saturating_sub(size)
+                                            }
+                                            None => streamed = true,
+                                        }
+                                        upload_size = Some(upload_size.unwrap_or_default() + size);
+                                        multipart::Part::stream_with_length(body, size)
+                                    }
+                                    Err(e) => {
+                                        return Ok(response_err(
+                                            query_manager,
+                                            &*response.lock().await,
+                                            e.to_string(),
+                                            &update_source,
+                                        ));
+                                    }
+                                }
+                            };
+
+                            let content_type = get_str(p, "contentType");
+
+                            // Set or guess mimetype
+                            if !content_type.is_empty() {
+                                part = match part.mime_str(content_type) {
+                                    Ok(p) => p,
+                                    Err(e) => {
+                                        return Ok(response_err(
+                                            query_manager,
+                                            &*response.lock().await,
+                                            format!("Invalid mime for multi-part entry {e:?}"),
+                                            &update_source,
+                                        ));
+                                    }
+                                };
+                            } else if !file_path.is_empty() {
+                                let default_mime =
+                                    Mime::from_str("application/octet-stream").unwrap();
+                                let mime =
+                                    mime_guess::from_path(file_path.clone()).first_or(default_mime);
+                                part = match part.mime_str(mime.essence_str()) {
+                                    Ok(p) => p,
+                                    Err(e) => {
+                                        return Ok(response_err(
+                                            query_manager,
+                                            &*response.lock().await,
+                                            format!("Invalid mime for multi-part entry {e:?}"),
+                                            &update_source,
+                                        ));
+                                    }
+                                };
+                            }
+
+                            // Set file path if it is not empty
+                            if !file_path.is_empty() {
+                                let filename = PathBuf::from(file_path)
+                                    .file_name()
+                                    .unwrap_or_default()
+                                    .to_string_lossy()
+                                    .to_string();
+                                part = part.file_name(filename);
+                            }
+
+                            multipart_form = multipart_form.part(name, part);
+                        }
+                    }
+                }
+            }
+            headers.remove("Content-Type"); // reqwest will add this automatically
+            if streamed {
+                request_builder = request_builder.multipart(multipart_form);
+            } else {
+                // reqwest always streams forms, so encode it up front to keep the request cloneable
+                let content_type =
+                    format!("multipart/form-data; boundary={}", multipart_form.boundary());
+                let body = multipart_form
+                    .into_stream()
+                    .try_fold(Vec::new(), |mut body, chunk| async move {
+                        body.extend_from_slice(&chunk);
+                        Ok(body)
+                    })
+                    .await;
+                match body {
+                    Ok(body) => {
+                        headers.insert(CONTENT_TYPE, HeaderValue::from_str(&content_type).unwrap());
+                        request_builder = request_builder.body(body);
+                    }
+                    Err(e) => {
+                        return Ok(response_err(
+                            query_manager,
+                            &*response.lock().await,
+                            format!("Failed to encode multi-part form {e:?}"),
+                            &update_source,
+                        ));
+                    }
+                }
+            }
+        } else if request_body.contains_key("text") {
+            let body = get_str_h(&request_body, "text");
+            request_builder = request_builder.body(body.to_owned());
+        } else {
+            warn!("Unsupported body type: {}", body_type);
+        }
+    } else {
+        // No body set
+        let method = request.method.to_ascii_lowercase();
+        let is_body_method = method == "post" || method == "put" || method == "patch";
+        // Add Content-Length for methods that commonly accept a body because some servers
+        // will error if they don't receive it.
+        if is_body_method && !headers.contains_key("content-length") {
+            headers.insert("Content-Length", HeaderValue::from_static("0"));
+        }
+    }
+
+    // Add headers last, because previous steps may modify them
+    request_builder = request_builder.headers(headers);
+
+    let mut sendable_req = match request_builder.build() {
+        Ok(r) => r,
+        Err(e) => {
+            warn!("Failed to build request builder {e:?}");
+            return Ok(response_err(
+                query_manager,
+                &*response.lock().await,
+                e.to_string(),
+                &update_source,
+            ));
+        }
+    };
+
+    // Apply authentication
+
+    if let Some(auth_name) = request.authentication_type.to_owned() {
+        let req = AuthenticationRequest::from_request(
+            format!("{:x}", md5::compute(request.id.clone())),
+            AuthenticationRequestType::Http,
+            request.authentication.clone(),
+            &sendable_req,
+        );
+        let applied = match auth.authenticate(&auth_name, req).await {
+            Ok(r) => r.apply(&mut sendable_req),
+            Err(e) => Err(e),
+        };
+        if let Err(e) = applied {
+            return Ok(response_err(
+                query_manager,
+                &*response.lock().await,
+                e.to_string(),
+                &update_source,
+            ));
+        }
+    }
+
+    let (resp_tx, resp_rx) = oneshot::channel::<RetriedRequest>();
+    let (done_tx, done_rx) = oneshot::channel::<HttpResponse>();
+
+    let start = Instant::now();
+
+    // Only the streamed bodies hold on to the progress now, so updates stop once they're sent
+    drop(upload_progress);
+    let upload_task = match upload_size {
+        None => None,
+        Some(size) => {
+            let mut r = response.lock().await;
+            r.upload_size = Some(size as i64);
+            query_manager.connect().update_http_response_if_id(&r, &update_source)?;
+
+            let query_manager = query_manager.clone();
+            let response = response.clone();
+            let update_source = update_source.clone();
+            let mut uploaded_rx = uploaded_rx.clone();
+            Some(tokio::spawn(async move {
+                while uploaded_rx.changed().await.is_ok() {
+                    let mut r = response.lock().await;
+                    r.bytes_uploaded = *uploaded_rx.borrow_and_update() as i64;
+                    r.elapsed = start.elapsed().as_millis() as i32;
+                    if let Err(e) =
+                        query_manager.connect().update_http_response_if_id(&r, &update_source)
+                    {
+                        warn!("Failed to update upload progress: {e}");
+                    }
+                    drop(r);
+                    // Updates that arrive in the meantime are coalesced by the channel
+                    tokio::time::sleep(PROGRESS_INTERVAL).await;
+                }
+            }))
+        }
+    };
+
+    // Cookie headers are set by execute_with_redirects so they show up on the sent request
+    let cookie_store = maybe_cookie_manager.as_ref().map(|(s, _)| Arc::clone(s));
+    let retry = request.retry.clone();
+    let retry_cancelled_rx = cancelled_rx.clone();
+    let timer = ConnectionTimer::new();
+    let send_timer = timer.clone();
+    tokio::spawn(async move {
+        let execute = execute_with_retries(
+            &client,
+            sendable_req,
+            &redirect_settings,
+            cookie_store,
+            &retry,
+            retry_cancelled_rx,
+        );
+        let result = timed(&send_timer, execute).await;
+        let _ = resp_tx.send(result);
+    });
+
+    let RetriedRequest {
+        result: raw_response,
+        attempts,
+    } = tokio::select! {
+        Ok(r) = resp_rx => r,
+        _ = cancelled_rx.changed() => {
+            if let Some(t) = upload_task {
+                t.abort();
+            }
+            let mut r = response.lock().await;
+            r.elapsed_headers = start.elapsed().as_millis() as i32;
+            r.elapsed = start.elapsed().as_millis() as i32;
+            return Ok(response_err(query_manager, &r, "Request was cancelled".to_string(), &update_source));
+        }
+    };
+
+    // Stop reporting progress, so it can't overwrite the response once it's finished
+    if let Some(t) = upload_task {
+        t.abort();
+        response.lock().await.bytes_uploaded = *uploaded_rx.borrow() as i64;
+    }
+
+    {
+        let query_manager = query_manager.clone();
+        let cancelled_rx = cancelled_rx.clone();
+        let response_id = response_id.clone();
+        let response = response.clone();
+        let update_source = update_source.clone();
+        let assertions = request.assertions.clone();
+        tokio::spawn(async move {
+            match raw_response {
+                Ok(ExecutedRequest {
+                    response: mut v,
+                    redirects,
+                    sent_request,
+                }) => {
+                    let content_length = v.content_length();
+                    let response_headers = v.headers().clone();
+                    create_dir_all(response_dir.clone())
+                        .await
+                        .expect("Failed to create responses dir");
+                    let body_path = if response_id.is_empty() {
+                        response_dir.join(uuid::Uuid::new_v4().to_string())
+                    } else {
+                        response_dir.join(response_id.clone())
+                    };
+
+                    let headers_at = Instant::now();
+                    {
+                        let mut r = response.lock().await;
+                        r.body_path = Some(body_path.to_str().unwrap().to_string());
+                        r.elapsed_headers = start.elapsed().as_millis() as i32;
+                        r.elapsed = start.elapsed().as_millis() as i32;
+                        r.timings = timer.timings(start, Some(headers_at), headers_at);
+                        r.tls_info = connections.tls_info(&v);
+                        r.status = v.status().as_u16() as i32;
+                        r.status_reason = v.status().canonical_reason().map(|s| s.to_string());
+                        r.headers = response_headers
+                            .iter()
+                            .map(|(k, v)| HttpResponseHeader {
+                                name: k.as_str().to_string(),
+                                value: v.to_str().unwrap_or_default().to_string(),
+                            })
+                            .collect();
+                        r.url = v.url().to_string();
+                        r.redirects = redirects;
+                        r.attempts = attempts;
+                        r.sent_request = Some(sent_request);
+                        r.remote_addr = v.remote_addr().map(|a| a.to_string());
+                        r.version = match v.version() {
+                            reqwest::Version::HTTP_09 => Some("HTTP/0.9".to_string()),
+                            reqwest::Version::HTTP_10 => Some("HTTP/1.0".to_string()),
+                            reqwest::Version::HTTP_11 => Some("HTTP/1.1".to_string()),
+                            reqwest::Version::HTTP_2 => Some("HTTP/2".to_string()),
+                            reqwest::Version::HTTP_3 => Some("HTTP/3".to_string()),
+                            _ => None,
+                        };
+
+                        r.state = HttpResponseState::Connected;
+                        query_manager
+                            .connect()
+                            .update_http_response_if_id(&r, &update_source)
+                            .expect("Failed to update response after connected");
+                    }
+
+                    // Write body to FS, buffered because chunks tend to be small
+                    let f = File::options()
+                        .create(true)
+                        .truncate(true)
+                        .write(true)
+                        .open(&body_path)
+                        .await
+                        .expect("Failed to open file");
+                    let mut f = BufWriter::with_capacity(BODY_BUFFER_SIZE, f);
+
+                    // Progress is reported in batches, flushing the file first so that the
+                    // body on disk is at least as long as the reported content length.
+                    let mut throttle = ProgressThrottle::new();
+                    let mut written_bytes: usize = 0;
+                    let mut truncated = false;
+
+                    // Server-sent events are parsed and stored as they arrive, unless the
+                    // response is ephemeral and has nowhere to store them.
+                    let is_event_stream = response_headers
+                        .get(CONTENT_TYPE)
+                        .and_then(|v| v.to_str().ok())
+                        .is_some_and(|v| v.to_lowercase().starts_with("text/event-stream"));
+                    let mut sse_parser = match is_event_stream && !response_id.is_empty() {
+                        true => Some(EventStreamParser::new()),
+                        false => None,
+                    };
+                    loop {
+                        let deadline = throttle.deadline();
+                        let chunk = tokio::select! {
+                            c = v.chunk() => Some(c),
+                            _ = sleep_until(deadline.unwrap_or_else(Instant::now).into()),
+                                if deadline.is_some() => None,
+                        };
+                        if *cancelled_rx.borrow() {
+                            // Request was canceled
+                            return;
+                        }
+                        match chunk {
+                            // Nothing arrived in time, so report what's pending
+                            None => {}
+                            Some(Ok(Some(bytes))) => {
+                                // Keep what fits, and stop downloading once the body is too long
+                                let bytes = match max_response_size {
+                                    Some(max) if written_bytes + bytes.len() > max => {
+                                        truncated = true;
+                                        bytes.slice(..max - written_bytes)
+                                    }
+                                    _ => bytes,
+                                };
+                                f.write_all(&bytes).await.expect("Failed to write to file");
+                                written_bytes += bytes.len();
+                                throttle.record(bytes.len());
+                                if let Some(parser) = sse_parser.as_mut() {
+                                    let r = response.lock().await;
+                                    for e in parser.feed(&bytes) {
+                                        let event = HttpResponseEvent {
+                                            workspace_id: r.workspace_id.clone(),
+                                            request_id: r.request_id.clone(),
+                                            response_id: r.id.clone(),
+                                            data: e.data,
+                                            event_id: e.id,
+                                            event_type: e.event_type,
+                                            retry: e.retry.map(|r| r as i64),
+                                            ..Default::default()
+                                        };
+                                        if let Err(e) = query_manager
+                                            .connect()
+                                            .upsert_http_response_event(&event, &update_source)
+                                        {
+                                            warn!("Failed to store server-sent event: {e}");
+                                        }
+                                    }
+                                }
+                                if truncated {
+                                    debug!("Truncated response body at {written_bytes} bytes");
+                                    break;
+                                }
+                            }
+                            Some(Ok(None)) => {
+                                break;
+                            }
+                            Some(Err(e)) => {
+                                response_err(
+                                    &query_manager,
+                                    &*response.lock().await,
+                                    e.to_string(),
+                                    &update_source,
+                                );
+                                break;
+                            }
+                        }
+
+                        if throttle.is_due() {
+                            f.flush().await.expect("Failed to flush file");
+                            let mut r = response.lock().await;
+                            r.elapsed = start.elapsed().as_millis() as i32;
+                            r.content_length = Some(written_bytes as i32);
+                            query_manager
+                                .connect()
+                                .update_http_response_if_id(&r, &update_source)
+                                .expect("Failed to update response");
+                            throttle.reset();
+                        }
+                    }
+                    f.flush().await.expect("Failed to flush file");
+
+                    // Set the final content length
+                    {
+                        let mut r = response.lock().await;
+                        r.elapsed = start.elapsed().as_millis() as i32;
+                        r.truncated = truncated;
+                        r.content_length = match content_length {
+                            Some(l) if !truncated => Some(l as i32),
+                            _ => Some(written_bytes as i32),
+                        };
+                        r.timings = timer.timings(start, Some(headers_at), Instant::now());
+
+                        // Check assertions now that the full body is on disk
+                        if r.error.is_none() && !assertions.is_empty() {
+                            let body = fs::read(&body_path).await.unwrap_or_default();
+                            r.assertion_results = evaluate_assertions(&assertions, &r, &body);
+                        }
+
+                        r.state = HttpResponseState::Closed;
+                        query_manager
+                            .connect()
+                            .update_http_response_if_id(&r, &update_source)
+                            .expect("Failed to update response");
+                    };
+
+                    // Add cookie store if specified
+                    if let Some((cookie_store, mut cookie_jar)) = maybe_cookie_manager {
+                        let json_cookies: Vec<Cookie> = cookie_store
+                            .lock()
+                            .unwrap()
+                            .iter_any()
+                            .map(|c| {
+                                let json_cookie =
+                                    serde_json::to_value(&c).expect("Failed to serialize cookie");
+                                serde_json::from_value(json_cookie)
+                                    .expect("Failed to deserialize cookie")
+                            })
+                            .collect::<Vec<_>>();
+                        cookie_jar.cookies = json_cookies;
+                        if let Err(e) =
+                            query_manager.connect().upsert_cookie_jar(&cookie_jar, &update_source)
+                        {
+                            error!("Failed to update cookie jar: {}", e);
+                        };
+                    }
+                }
+                Err(e) => {
+                    warn!("Failed to execute request {e}");
+                    let mut r = response.lock().await;
+                    r.attempts = attempts;
+                    // Keep what we know about the connection, which helps diagnose TLS errors
+                    r.timings = timer.timings(start, None, Instant::now());
+                    r.tls_info = timer.tls_info();
+                    response_err(&query_manager, &r, format!("{e} → {e:?}"), &update_source);
+                }
+            };
+
+            let r = response.lock().await.clone();
+            done_tx.send(r).unwrap();
+        });
+    };
+
+    Ok(tokio::select! {
+        Ok(r) = done_rx => r,
+        _ = cancelled_rx.changed() => {
+            match query_manager.connect().get_http_response(&response_id) {
+                Ok(mut r) => {
+                    r.state = HttpResponseState::Closed;
+                    r.elapsed = start.elapsed().as_millis() as i32;
+                    r.elapsed_headers = start.elapsed().as_millis() as i32;
+                    query_manager.connect().update_http_response_if_id(&r, &update_source)
+                        .expect("Failed to update response")
+                },
+                _ => {
+                    response_err(query_manager, &*response.lock().await, "Ephemeral request was cancelled".to_string(), &update_source)
+                }.clone(),
+            }
+        }
+    })
+}
+
+/// Mark a response as closed with an error and persist it
+pub fn response_err(
+    query_manager: &QueryManager,
+    response: &HttpResponse,
+    error: String,
+    update_source: &UpdateSource,
+) -> HttpResponse {
+    warn!("Failed to send request: {error:?}");
+    let mut response = response.clone();
+    response.state = HttpResponseState::Closed;
+    response.error = Some(error.clone());
+    response = query_manager
+        .connect()
+        .update_http_response_if_id(&response, update_source)
+        .expect("Failed to update response");
+    response
+}
+
+pub(crate) fn ensure_proto(url_str: &str) -> String {
+    if url_str.starts_with("http://")
+        || url_str.starts_with("https://")
+        || url_str.starts_with(UNIX_SCHEME)
+    {
+        return url_str.to_string();
+    }
+
+    // Url::from_str will fail without a proto, so add one
+    let parseable_url = format!("http://{}", url_str);
+    if let Ok(u) = Url::from_str(parseable_url.as_str()) {
+        match u.host() {
+            Some(host) => {
+                let h = host.to_string();
+                // These TLDs force HTTPS
+                if h.ends_with(".app") || h.ends_with(".dev") || h.ends_with(".page") {
+                    return format!("https://{url_str}");
+                }
+            }
+            None => {}
+        }
+    }
+
+    format!("http://{url_str}")
+}
+
+fn get_bool(v: &Value, key: &str, fallback: bool) -> bool {
+    match v.get(key) {
+        None => fallback,
+        Some(v) => v.as_bool().unwrap_or(fallback),
+    }
+}
+
+fn get_str<'a>(v: &'a Value, key: &str) -> &'a str {
+    match v.get(key) {
+        None => "",
+        Some(v) => v.as_str().unwrap_or_default(),
+    }
+}
+
+fn get_str_h<'a>(v: &'a BTreeMap<String, Value>, key: &str) -> &'a str {
+    match v.get(key) {
+        None => "",
+        Some(v) => v.as_str().unwrap_or_default(),
+    }
+}
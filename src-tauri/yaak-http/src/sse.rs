@@ -0,0 +1,342 @@
+use crate::auth::{AuthenticationCallback, AuthenticationRequest, AuthenticationRequestType};
+use crate::client_pool::{ClientSettings, HttpClientPool};
+use crate::error::Error::GenericError;
+use crate::error::Result;
+use crate::redirects::{RedirectSettings, execute_with_redirects};
+use crate::render::{render_dns_overrides, render_sse_request};
+use crate::sender::ensure_proto;
+use crate::unix_socket::UnixSocketTarget;
+use http::header::{ACCEPT, CACHE_CONTROL, CONTENT_TYPE, USER_AGENT};
+use http::{HeaderMap, HeaderName, HeaderValue, StatusCode};
+use log::{debug, warn};
+use reqwest::{Client, Method, Request, Url};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use tokio::sync::watch::Receiver;
+use yaak_models::models::{
+    Environment, HttpResponseHeader, SseConnection, SseConnectionState, SseEvent, SseRequest,
+};
+use yaak_models::query_manager::QueryManager;
+use yaak_models::util::UpdateSource;
+use yaak_proxy::config::ProxyConfig;
+use yaak_sse::parser::EventStreamParser;
+use yaak_templates::TemplateCallback;
+
+/// How long to wait before reconnecting, until the server asks for another delay with `retry`
+pub const DEFAULT_RECONNECT_DELAY: Duration = Duration::from_secs(3);
+
+const LAST_EVENT_ID: HeaderName = HeaderName::from_static("last-event-id");
+
+/// Renders SSE requests from the database and keeps their connections open, storing every event
+/// as it arrives. Dropped streams are re-established like browsers do, after the delay the
+/// server asked for and resuming from the last event ID it sent.
+#[derive(Clone)]
+pub struct SseConnector<T, A> {
+    pub query_manager: QueryManager,
+    pub update_source: UpdateSource,
+    pub client_pool: HttpClientPool,
+    pub template_callback: T,
+    pub auth_callback: A,
+}
+
+impl<T: TemplateCallback, A: AuthenticationCallback> SseConnector<T, A> {
+    /// Stay connected until `closed_rx` changes, or until the server refuses the connection
+    pub async fn connect(
+        &self,
+        unrendered_request: &SseRequest,
+        og_connection: &SseConnection,
+        environment: Option<&Environment>,
+        closed_rx: &mut Receiver<bool>,
+    ) -> Result<SseConnection> {
+        let (settings, workspace, base_environment, inherited) = {
+            let db = self.query_manager.connect();
+            let settings = db.get_settings();
+            let base_environment = db.get_base_environment(&unrendered_request.workspace_id)?;
+            let inherited = db.resolve_inherited_settings(
+                &unrendered_request.workspace_id,
+                unrendered_request.folder_id.as_deref(),
+            )?;
+            let workspace = inherited.resolve_workspace(
+                &db.get_workspace(&unrendered_request.workspace_id)?,
+                unrendered_request,
+            );
+            (settings, workspace, base_environment, inherited)
+        };
+
+        let request = match render_sse_request(
+            unrendered_request,
+            &base_environment,
+            environment,
+            &inherited,
+            &self.template_callback,
+        )
+        .await
+        {
+            Ok(r) => r,
+            Err(e) => return self.connection_err(og_connection, e.to_string()),
+        };
+
+        let dns_overrides = match render_dns_overrides(
+            &workspace,
+            &base_environment,
+            environment,
+            &inherited,
+            &self.template_callback,
+        )
+        .await
+        {
+            Ok(o) => o,
+            Err(e) => return self.connection_err(og_connection, e.to_string()),
+        };
+
+        let mut url_string = ensure_proto(&request.url);
+        let unix_socket = match UnixSocketTarget::parse(&url_string) {
+            Ok(Some(target)) => {
+                url_string = target.url;
+                Some(target.socket_path)
+            }
+            Ok(None) => None,
+            Err(e) => return self.connection_err(og_connection, e.to_string()),
+        };
+        let mut url = match Url::from_str(&url_string) {
+            Ok(u) => u,
+            Err(e) => {
+                let error = format!("Failed to parse URL \"{url_string}\": {e}");
+                return self.connection_err(og_connection, error);
+            }
+        };
+        let query_params = request
+            .url_parameters
+            .iter()
+            .filter(|p| p.enabled && !p.name.is_empty())
+            .collect::<Vec<_>>();
+        // NOTE: Only mutate query pairs if there are any, or it will append an empty `?` to the URL
+        if !query_params.is_empty() {
+            let mut pairs = url.query_pairs_mut();
+            for p in query_params {
+                pairs.append_pair(&p.name, &p.value);
+            }
+        }
+
+        let proxy = match ProxyConfig::load(settings.proxy).await {
+            Ok(p) => p,
+            Err(e) => return self.connection_err(og_connection, e.to_string()),
+        };
+
+        // The stream stays open indefinitely, so the timeout only applies until headers arrive
+        let client_settings = ClientSettings::new(&workspace, proxy);
+        let timeout = client_settings.timeout;
+        let client_settings = ClientSettings {
+            timeout: None,
+            unix_socket,
+            dns_overrides,
+            ..client_settings
+        };
+        if let Err(e) = client_settings.check_proxy(&url) {
+            return self.connection_err(og_connection, e.to_string());
+        }
+        let client = match self.client_pool.get(&client_settings) {
+            Ok(c) => c.client,
+            Err(e) => return self.connection_err(og_connection, e.to_string()),
+        };
+        let redirect_settings = RedirectSettings::from_workspace(&workspace);
+
+        let mut connection = og_connection.clone();
+        connection.url = url.to_string();
+        let start = Instant::now();
+        let mut reconnect_delay = DEFAULT_RECONNECT_DELAY;
+
+        // Ends with the error that failed the connection, if any
+        let failure = 'connection: loop {
+            let sendable_req = match self
+                .build_request(&client, &request, &url, connection.last_event_id.as_deref())
+                .await
+            {
+                Ok(r) => r,
+                Err(e) => break Some(e.to_string()),
+            };
+
+            let execute = execute_with_redirects(&client, sendable_req, &redirect_settings, None);
+            let response = tokio::select! {
+                r = execute => Some(r),
+                _ = sleep_or_pending(timeout) => None,
+                _ = closed_rx.changed() => break None,
+            };
+
+            let interruption = match response {
+                None => format!("Timed out after {}ms", timeout.unwrap_or_default().as_millis()),
+                Some(Err(e)) => e.to_string(),
+                Some(Ok(executed)) => {
+                    let mut v = executed.response;
+                    connection.status = v.status().as_u16() as i32;
+                    connection.url = v.url().to_string();
+                    connection.headers = v
+                        .headers()
+                        .iter()
+                        .map(|(k, v)| HttpResponseHeader {
+                            name: k.as_str().to_string(),
+                            value: v.to_str().unwrap_or_default().to_string(),
+                        })
+                        .collect();
+
+                    // Anything but a 200 event stream fails the connection for good, rather than
+                    // being retried, so that servers can tell clients to stop reconnecting
+                    let content_type = v
+                        .headers()
+                        .get(CONTENT_TYPE)
+                        .and_then(|v| v.to_str().ok())
+                        .unwrap_or_default()
+                        .to_string();
+                    if v.status() != StatusCode::OK {
+                        break Some(format!("Server responded with status {}", v.status()));
+                    }
+                    if !content_type.to_lowercase().starts_with("text/event-stream") {
+                        break Some(format!(
+                            "Expected a text/event-stream response, but got \"{content_type}\""
+                        ));
+                    }
+
+                    connection.state = SseConnectionState::Connected;
+                    connection.error = None;
+                    connection.elapsed = start.elapsed().as_millis() as i32;
+                    connection = self.upsert_connection(&connection)?;
+
+                    // Each attempt starts a new stream, so nothing of a partial event carries over
+                    let mut parser = EventStreamParser::new();
+                    loop {
+                        let chunk = tokio::select! {
+                            c = v.chunk() => c,
+                            _ = closed_rx.changed() => break 'connection None,
+                        };
+                        let bytes = match chunk {
+                            Ok(Some(bytes)) => bytes,
+                            Ok(None) => break "Server closed the stream".to_string(),
+                            Err(e) => break e.to_string(),
+                        };
+
+                        for e in parser.feed(&bytes) {
+                            let event = SseEvent {
+                                workspace_id: connection.workspace_id.clone(),
+                                request_id: connection.request_id.clone(),
+                                connection_id: connection.id.clone(),
+                                data: e.data,
+                                event_id: e.id,
+                                event_type: e.event_type,
+                                retry: e.retry.map(|r| r as i64),
+                                ..Default::default()
+                            };
+                            if let Err(e) = self
+                                .query_manager
+                                .connect()
+                                .upsert_sse_event(&event, &self.update_source)
+                            {
+                                warn!("Failed to store server-sent event: {e}");
+                            }
+                        }
+
+                        if let Some(ms) = parser.reconnection_time() {
+                            reconnect_delay = Duration::from_millis(ms);
+                        }
+                        let last_event_id = parser.last_event_id();
+                        if last_event_id.is_some()
+                            && last_event_id != connection.last_event_id.as_deref()
+                        {
+                            connection.last_event_id = last_event_id.map(|id| id.to_string());
+                            connection.elapsed = start.elapsed().as_millis() as i32;
+                            connection = self.upsert_connection(&connection)?;
+                        }
+                    }
+                }
+            };
+
+            debug!("SSE stream interrupted, reconnecting in {reconnect_delay:?}: {interruption}");
+            connection.state = SseConnectionState::Reconnecting;
+            connection.error = Some(interruption);
+            connection.reconnects += 1;
+            connection.elapsed = start.elapsed().as_millis() as i32;
+            connection = self.upsert_connection(&connection)?;
+
+            tokio::select! {
+                _ = tokio::time::sleep(reconnect_delay) => {}
+                _ = closed_rx.changed() => break None,
+            }
+        };
+
+        if let Some(e) = failure {
+            warn!("SSE connection failed: {e}");
+            connection.error = Some(e);
+        }
+        connection.state = SseConnectionState::Closed;
+        connection.elapsed = start.elapsed().as_millis() as i32;
+        self.upsert_connection(&connection)
+    }
+
+    /// Build the request for a connection attempt. Authentication is applied to every attempt,
+    /// so that credentials which expired in the meantime can be refreshed.
+    async fn build_request(
+        &self,
+        client: &Client,
+        request: &SseRequest,
+        url: &Url,
+        last_event_id: Option<&str>,
+    ) -> Result<Request> {
+        let mut headers = HeaderMap::new();
+        headers.insert(USER_AGENT, HeaderValue::from_static("yaak"));
+        headers.insert(ACCEPT, HeaderValue::from_static("text/event-stream"));
+        headers.insert(CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+
+        for h in request.headers.clone() {
+            if !h.enabled || h.name.is_empty() {
+                continue;
+            }
+            match (HeaderName::from_str(&h.name), HeaderValue::from_str(&h.value)) {
+                (Ok(name), Ok(value)) => {
+                    headers.insert(name, value);
+                }
+                _ => warn!("Skipping invalid header {:?}", h.name),
+            }
+        }
+
+        // An empty ID resets it, in which case the header isn't sent
+        if let Some(id) = last_event_id.filter(|id| !id.is_empty()) {
+            let value = HeaderValue::from_str(id)
+                .map_err(|e| GenericError(format!("Invalid last event ID {id:?}: {e}")))?;
+            headers.insert(LAST_EVENT_ID, value);
+        }
+
+        let mut sendable_req = client.request(Method::GET, url.clone()).headers(headers).build()?;
+
+        if let Some(auth_name) = request.authentication_type.to_owned() {
+            let req = AuthenticationRequest::from_request(
+                format!("{:x}", md5::compute(request.id.clone())),
+                AuthenticationRequestType::Sse,
+                request.authentication.clone(),
+                &sendable_req,
+            );
+            self.auth_callback.authenticate(&auth_name, req).await?.apply(&mut sendable_req)?;
+        }
+
+        Ok(sendable_req)
+    }
+
+    fn upsert_connection(&self, connection: &SseConnection) -> Result<SseConnection> {
+        Ok(self.query_manager.connect().upsert_sse_connection(connection, &self.update_source)?)
+    }
+
+    /// Mark a connection that couldn't be started as closed with an error and persist it
+    fn connection_err(&self, connection: &SseConnection, error: String) -> Result<SseConnection> {
+        warn!("Failed to connect to SSE endpoint: {error:?}");
+        self.upsert_connection(&SseConnection {
+            state: SseConnectionState::Closed,
+            error: Some(error),
+            ..connection.clone()
+        })
+    }
+}
+
+async fn sleep_or_pending(duration: Option<Duration>) {
+    match duration {
+        Some(d) => tokio::time::sleep(d).await,
+        None => std::future::pending().await,
+    }
+}
@@ -0,0 +1,407 @@
+use crate::tls;
+use hyper_util::client::legacy::connect::{Connection, HttpInfo};
+use reqwest::Response;
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::future::{Future, poll_fn};
+use std::net::SocketAddr;
+use std::pin::{Pin, pin};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::thread::LocalKey;
+use std::time::Instant;
+use tower_layer::Layer;
+use tower_service::Service;
+use yaak_models::models::{HttpResponseTimings, HttpResponseTlsInfo};
+
+/// How many connections of a client to remember the TLS details of
+const MAX_KNOWN_CONNECTIONS: usize = 64;
+
+/// Points in time reached while opening the most recent connection
+#[derive(Debug, Default, Clone)]
+struct ConnectionMarks {
+    connect_start: Option<Instant>,
+    dns_start: Option<Instant>,
+    dns_end: Option<Instant>,
+    tls_start: Option<Instant>,
+    connect_end: Option<Instant>,
+    tls_info: Option<HttpResponseTlsInfo>,
+}
+
+/// Collects timings and TLS details of the connections a send opens, from the resolver, connector
+/// and TLS hooks, none of which reqwest reports on its own. Each send gets its own timer (see
+/// [`timed`]), so a send that reuses a connection has no connection phases.
+#[derive(Debug, Default, Clone)]
+pub struct ConnectionTimer {
+    marks: Arc<Mutex<ConnectionMarks>>,
+}
+
+impl ConnectionTimer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn update(&self, f: impl FnOnce(&mut ConnectionMarks)) {
+        if let Ok(mut marks) = self.marks.lock() {
+            f(&mut marks);
+        }
+    }
+
+    /// A new connection is being opened, so forget about the previous one
+    pub(crate) fn connect_started(&self) {
+        self.update(|m| {
+            *m = ConnectionMarks {
+                connect_start: Some(Instant::now()),
+                ..Default::default()
+            }
+        });
+    }
+
+    pub(crate) fn connect_finished(&self) {
+        self.update(|m| m.connect_end = Some(Instant::now()));
+    }
+
+    pub(crate) fn tls_started(&self) {
+        self.update(|m| {
+            m.tls_start.get_or_insert_with(Instant::now);
+        });
+    }
+
+    pub(crate) fn set_tls_suite(&self, version: &str, cipher: &str) {
+        self.update(|m| {
+            let info = m.tls_info.get_or_insert_with(Default::default);
+            info.version = Some(version.into());
+            info.cipher = Some(cipher.into());
+        });
+    }
+
+    pub(crate) fn set_tls_certificates(&self, certificates: Vec<String>) {
+        self.update(|m| {
+            m.tls_info.get_or_insert_with(Default::default).certificates = certificates
+        });
+    }
+
+    pub fn tls_info(&self) -> Option<HttpResponseTlsInfo> {
+        self.marks.lock().ok().and_then(|m| m.tls_info.clone())
+    }
+
+    /// Break the time between `start` and `end` into phases. Pass `None` for `headers_at` while
+    /// the response headers haven't arrived yet.
+    pub fn timings(
+        &self,
+        start: Instant,
+        headers_at: Option<Instant>,
+        end: Instant,
+    ) -> HttpResponseTimings {
+        let marks = self.marks.lock().map(|m| m.clone()).unwrap_or_default();
+        compute_timings(&marks, start, headers_at, end)
+    }
+}
+
+thread_local! {
+    /// Timer of the send whose connections are being opened on this thread
+    static CURRENT_TIMER: RefCell<Option<ConnectionTimer>> = const { RefCell::new(None) };
+}
+
+/// Run `f` with `key` set to `value`, restoring the previous value afterwards (even on panic)
+pub(crate) fn scoped<V: 'static, T>(
+    key: &'static LocalKey<RefCell<Option<V>>>,
+    value: Option<V>,
+    f: impl FnOnce() -> T,
+) -> T {
+    struct Restore<V: 'static>(&'static LocalKey<RefCell<Option<V>>>, Option<V>);
+    impl<V> Drop for Restore<V> {
+        fn drop(&mut self) {
+            self.0.with(|v| *v.borrow_mut() = self.1.take());
+        }
+    }
+
+    let _restore = Restore(key, key.with(|v| v.replace(value)));
+    f()
+}
+
+/// Run `f` with `timer` as the current timer, which the connection hooks report to
+pub(crate) fn with_timer<T>(timer: Option<&ConnectionTimer>, f: impl FnOnce() -> T) -> T {
+    scoped(&CURRENT_TIMER, timer.cloned(), f)
+}
+
+/// Apply `f` to the current timer, if there is one
+pub(crate) fn with_current_timer(f: impl FnOnce(&ConnectionTimer)) {
+    CURRENT_TIMER.with(|t| {
+        if let Some(timer) = t.borrow().as_ref() {
+            f(timer);
+        }
+    });
+}
+
+/// Drive `fut`, recording the connections it opens with `timer`. Connections are opened while the
+/// request future is polled, so the connector picks up the timer of the send polling it.
+pub async fn timed<F: Future>(timer: &ConnectionTimer, fut: F) -> F::Output {
+    let mut fut = pin!(fut);
+    poll_fn(|cx| with_timer(Some(timer), || fut.as_mut().poll(cx))).await
+}
+
+fn compute_timings(
+    marks: &ConnectionMarks,
+    start: Instant,
+    headers_at: Option<Instant>,
+    end: Instant,
+) -> HttpResponseTimings {
+    let millis = |from: Instant, to: Instant| to.saturating_duration_since(from).as_millis() as i32;
+
+    // Marks from a connection opened before this request started belong to an earlier request
+    let connection = match (marks.connect_start, marks.connect_end) {
+        (Some(s), Some(e)) if s >= start => Some((s, e)),
+        _ => None,
+    };
+
+    let (dns, connect, tls, ready_at) = match connection {
+        None => (None, None, None, start),
+        Some((connect_start, connect_end)) => {
+            let dns = match (marks.dns_start, marks.dns_end) {
+                (Some(s), Some(e)) => Some(millis(s, e)),
+                _ => None,
+            };
+            let tcp_start = marks.dns_end.unwrap_or(connect_start);
+            let tcp_end = marks.tls_start.unwrap_or(connect_end);
+            let tls = marks.tls_start.map(|s| millis(s, connect_end));
+            (dns, Some(millis(tcp_start, tcp_end)), tls, connect_end)
+        }
+    };
+
+    let (ttfb, download) = match headers_at {
+        Some(h) => (millis(ready_at, h), millis(h, end)),
+        None => (millis(ready_at, end), 0),
+    };
+
+    HttpResponseTimings {
+        dns,
+        connect,
+        tls,
+        ttfb,
+        download,
+    }
+}
+
+/// Resolves hostnames using the system resolver, recording how long the lookup took
+#[derive(Clone, Default)]
+pub struct TimingResolver;
+
+impl TimingResolver {
+    pub fn new() -> Self {
+        TimingResolver
+    }
+}
+
+impl Resolve for TimingResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            with_current_timer(|t| t.update(|m| m.dns_start = Some(Instant::now())));
+            let addrs =
+                tokio::net::lookup_host((name.as_str(), 0)).await?.collect::<Vec<SocketAddr>>();
+            with_current_timer(|t| t.update(|m| m.dns_end = Some(Instant::now())));
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+/// TLS details of the connections a client opened, by local address, so that sends reusing a
+/// connection can still report how it's secured.
+#[derive(Debug, Default, Clone)]
+pub struct KnownConnections {
+    connections: Arc<Mutex<VecDeque<(SocketAddr, HttpResponseTlsInfo)>>>,
+}
+
+impl KnownConnections {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn insert(&self, local_addr: SocketAddr, tls_info: HttpResponseTlsInfo) {
+        if let Ok(mut connections) = self.connections.lock() {
+            // The address is free again once its previous connection closed
+            connections.retain(|(a, _)| *a != local_addr);
+            if connections.len() >= MAX_KNOWN_CONNECTIONS {
+                connections.pop_front();
+            }
+            connections.push_back((local_addr, tls_info));
+        }
+    }
+
+    /// TLS details of the connection the response came in on
+    pub fn tls_info(&self, response: &Response) -> Option<HttpResponseTlsInfo> {
+        let local_addr = response.extensions().get::<HttpInfo>()?.local_addr();
+        let connections = self.connections.lock().ok()?;
+        connections.iter().find(|(a, _)| *a == local_addr).map(|(_, info)| info.clone())
+    }
+}
+
+/// Connector layer that records when connecting starts and when the connection (including the
+/// TLS handshake) is ready, with the timer of the send that opened it.
+#[derive(Clone)]
+pub struct TimingLayer {
+    connections: KnownConnections,
+}
+
+impl TimingLayer {
+    pub fn new(connections: &KnownConnections) -> Self {
+        TimingLayer {
+            connections: connections.clone(),
+        }
+    }
+}
+
+impl<S> Layer<S> for TimingLayer {
+    type Service = TimingService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        TimingService {
+            inner,
+            connections: self.connections.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct TimingService<S> {
+    inner: S,
+    connections: KnownConnections,
+}
+
+impl<S, Req> Service<Req> for TimingService<S>
+where
+    S: Service<Req>,
+    S::Response: Connection,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<S::Response, S::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Req) -> Self::Future {
+        // The connection may finish opening in the background, after the send that started it
+        // got another one, so hold on to the timer and target instead of looking them up on
+        // every poll
+        let timer = CURRENT_TIMER.with(|t| t.borrow().clone());
+        let target = tls::current_target();
+        if let Some(t) = &timer {
+            t.connect_started();
+        }
+        let connections = self.connections.clone();
+        let mut fut = Box::pin(self.inner.call(req));
+        Box::pin(async move {
+            // The TLS handshake runs while this is polled, so the TLS hooks can find the timer and
+            // the client certificate resolver can find the target
+            let result = poll_fn(|cx| {
+                with_timer(timer.as_ref(), || {
+                    tls::with_target(target.clone(), || fut.as_mut().poll(cx))
+                })
+            })
+            .await;
+            if let Some(t) = &timer {
+                t.connect_finished();
+            }
+
+            let tls_info = timer.as_ref().and_then(|t| t.tls_info());
+            if let (Ok(conn), Some(tls_info)) = (&result, tls_info) {
+                let mut extensions = http::Extensions::new();
+                conn.connected().get_extras(&mut extensions);
+                if let Some(info) = extensions.get::<HttpInfo>() {
+                    connections.insert(info.local_addr(), tls_info);
+                }
+            }
+            result
+        })
+    }
+}
+
+#[cfg(test)]
+mod timing_tests {
+    use crate::client_pool::{ClientSettings, build_client};
+    use crate::timing::{ConnectionMarks, ConnectionTimer, compute_timings, timed};
+    use reqwest::Url;
+    use std::time::{Duration, Instant};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use yaak_models::models::Workspace;
+    use yaak_proxy::config::ProxyConfig;
+
+    #[test]
+    fn splits_connection_phases() {
+        let start = Instant::now();
+        let at = |ms: u64| start + Duration::from_millis(ms);
+        let marks = ConnectionMarks {
+            connect_start: Some(at(1)),
+            dns_start: Some(at(1)),
+            dns_end: Some(at(11)),
+            tls_start: Some(at(31)),
+            connect_end: Some(at(61)),
+            tls_info: None,
+        };
+
+        let t = compute_timings(&marks, start, Some(at(161)), at(171));
+        assert_eq!(t.dns, Some(10));
+        assert_eq!(t.connect, Some(20));
+        assert_eq!(t.tls, Some(30));
+        assert_eq!(t.ttfb, 100);
+        assert_eq!(t.download, 10);
+    }
+
+    #[test]
+    fn reused_connection_has_no_connection_phases() {
+        let start = Instant::now();
+        let earlier = ConnectionMarks {
+            connect_start: start.checked_sub(Duration::from_millis(500)),
+            connect_end: start.checked_sub(Duration::from_millis(400)),
+            ..Default::default()
+        };
+
+        let t = compute_timings(&earlier, start, None, start + Duration::from_millis(50));
+        assert_eq!((t.dns, t.connect, t.tls), (None, None, None));
+        assert_eq!(t.ttfb, 50);
+        assert_eq!(t.download, 0);
+    }
+
+    /// Answer every request on the first connection, keeping it alive
+    async fn keep_alive_server() -> Url {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap();
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buf = [0u8; 1024];
+            while stream.read(&mut buf).await.unwrap_or(0) > 0 {
+                let resp = "HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n";
+                let _ = stream.write_all(resp.as_bytes()).await;
+            }
+        });
+        url
+    }
+
+    #[tokio::test]
+    async fn sends_only_record_connections_they_open() {
+        let (a, b) = (keep_alive_server().await, keep_alive_server().await);
+        let settings = ClientSettings::new(&Workspace::default(), ProxyConfig::default());
+        let client = build_client(&settings).unwrap().client;
+        let send = async |timer: &ConnectionTimer, url: &Url| {
+            timed(timer, client.get(url.clone()).send()).await.unwrap();
+        };
+
+        let first = ConnectionTimer::new();
+        let start = Instant::now();
+        send(&first, &a).await;
+        assert!(first.timings(start, None, Instant::now()).connect.is_some());
+
+        // Another send opens a connection while this one reuses the first, like concurrent sends
+        let start = Instant::now();
+        send(&ConnectionTimer::new(), &b).await;
+        let second = ConnectionTimer::new();
+        send(&second, &a).await;
+        let t = second.timings(start, None, Instant::now());
+        assert_eq!((t.dns, t.connect, t.tls), (None, None, None));
+    }
+}
@@ -0,0 +1,382 @@
+use crate::timing::{scoped, with_current_timer};
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use reqwest::Url;
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::client::{
+    ClientSessionMemoryCache, ClientSessionStore, ResolvesClientCert, Resumption,
+    Tls12ClientSessionValue, Tls13ClientSessionValue,
+};
+use rustls::crypto::cipher::{
+    AeadKey, Iv, KeyBlockShape, MessageDecrypter, MessageEncrypter, Tls12AeadAlgorithm,
+    Tls13AeadAlgorithm, UnsupportedOperationError,
+};
+use rustls::crypto::{CryptoProvider, ring};
+use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use rustls::sign::CertifiedKey;
+use rustls::{
+    CipherSuite, CipherSuiteCommon, ClientConfig, ConnectionTrafficSecrets, DigitallySignedStruct,
+    Error, NamedGroup, SignatureScheme, SupportedCipherSuite, Tls12CipherSuite, Tls13CipherSuite,
+};
+use std::cell::RefCell;
+use std::future::{Future, poll_fn};
+use std::pin::pin;
+use std::sync::{Arc, OnceLock};
+use yaak_tls::certificates::ClientIdentities;
+use yaak_tls::config::{TlsSettings, platform_verifier};
+
+/// Host and port of a connection being opened
+pub(crate) type ConnectTarget = (String, Option<u16>);
+
+/// Build the TLS config for a client, hooking into the handshake so the timer of the send opening
+/// the connection can record the negotiated cipher suite, the server's certificates and how long
+/// the handshake took. The client certificate is picked for each connection by its host.
+pub fn client_config(settings: &TlsSettings) -> yaak_tls::error::Result<ClientConfig> {
+    let provider = Arc::new(CryptoProvider {
+        cipher_suites: recording_cipher_suites().clone(),
+        ..ring::default_provider()
+    });
+    let inner = match settings.validate_certificates {
+        // Use platform-native verifier (plus any custom CAs) to validate certificates
+        true => Some(platform_verifier(settings, provider.clone())?),
+        false => None,
+    };
+
+    let verifier = RecordingVerifier {
+        inner,
+        provider: provider.clone(),
+    };
+
+    let resolver = TargetCertResolver {
+        identities: ClientIdentities::load(&settings.client_certificates, &provider)?,
+    };
+
+    let mut config = ClientConfig::builder_with_provider(provider)
+        .with_safe_default_protocol_versions()?
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(verifier))
+        .with_client_cert_resolver(Arc::new(resolver));
+
+    if !settings.validate_certificates {
+        // Match what reqwest configures when it builds the TLS config itself
+        config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+    }
+
+    config.resumption = Resumption::store(Arc::new(HandshakeStartStore {
+        inner: ClientSessionMemoryCache::new(256),
+    }));
+
+    Ok(config)
+}
+
+thread_local! {
+    /// Where the connection being opened on this thread goes
+    static CONNECT_TARGET: RefCell<Option<ConnectTarget>> = const { RefCell::new(None) };
+}
+
+/// Run `f` with `target` as the target of the connection being opened
+pub(crate) fn with_target<T>(target: Option<ConnectTarget>, f: impl FnOnce() -> T) -> T {
+    scoped(&CONNECT_TARGET, target, f)
+}
+
+pub(crate) fn current_target() -> Option<ConnectTarget> {
+    CONNECT_TARGET.with(|t| t.borrow().clone())
+}
+
+/// Drive `fut`, which sends a request to `url`, so that a connection it opens presents the client
+/// certificate configured for that URL rather than for whichever host the client was built for
+pub async fn targeted<F: Future>(url: &Url, fut: F) -> F::Output {
+    let target = (url.host_str().unwrap_or_default().to_string(), url.port_or_known_default());
+    let mut fut = pin!(fut);
+    poll_fn(|cx| with_target(Some(target.clone()), || fut.as_mut().poll(cx))).await
+}
+
+/// Presents the client certificate configured for the host of the connection being opened. rustls
+/// doesn't say which server is asking, so it's looked up from the connection target.
+#[derive(Debug)]
+struct TargetCertResolver {
+    identities: ClientIdentities,
+}
+
+impl ResolvesClientCert for TargetCertResolver {
+    fn resolve(
+        &self,
+        _root_hint_subjects: &[&[u8]],
+        _sigschemes: &[SignatureScheme],
+    ) -> Option<Arc<CertifiedKey>> {
+        let (host, port) = current_target()?;
+        self.identities.find(&host, port)
+    }
+
+    fn has_certs(&self) -> bool {
+        !self.identities.is_empty()
+    }
+}
+
+fn record_suite(version: &str, suite: CipherSuite) {
+    with_current_timer(|timer| {
+        let cipher = suite.as_str().map(String::from).unwrap_or_else(|| format!("{suite:?}"));
+        timer.set_tls_suite(version, &cipher);
+    });
+}
+
+/// The provider's cipher suites, wrapped to record which one a connection negotiated when its
+/// keys are installed. That happens on every handshake, including resumed ones, unlike
+/// certificate verification. rustls only takes `'static` suites, so they're made once.
+fn recording_cipher_suites() -> &'static Vec<SupportedCipherSuite> {
+    static SUITES: OnceLock<Vec<SupportedCipherSuite>> = OnceLock::new();
+    SUITES.get_or_init(|| {
+        ring::default_provider()
+            .cipher_suites
+            .into_iter()
+            .map(|suite| match suite {
+                SupportedCipherSuite::Tls13(s) => {
+                    let aead_alg = Box::leak(Box::new(RecordingTls13Aead {
+                        inner: s.aead_alg,
+                        suite: s.common.suite,
+                    }));
+                    SupportedCipherSuite::Tls13(Box::leak(Box::new(Tls13CipherSuite {
+                        common: copy_common(&s.common),
+                        aead_alg,
+                        ..*s
+                    })))
+                }
+                SupportedCipherSuite::Tls12(s) => {
+                    let aead_alg = Box::leak(Box::new(RecordingTls12Aead {
+                        inner: s.aead_alg,
+                        suite: s.common.suite,
+                    }));
+                    SupportedCipherSuite::Tls12(Box::leak(Box::new(Tls12CipherSuite {
+                        common: copy_common(&s.common),
+                        aead_alg,
+                        ..*s
+                    })))
+                }
+            })
+            .collect()
+    })
+}
+
+fn copy_common(common: &CipherSuiteCommon) -> CipherSuiteCommon {
+    CipherSuiteCommon {
+        suite: common.suite,
+        hash_provider: common.hash_provider,
+        confidentiality_limit: common.confidentiality_limit,
+    }
+}
+
+struct RecordingTls13Aead {
+    inner: &'static dyn Tls13AeadAlgorithm,
+    suite: CipherSuite,
+}
+
+impl Tls13AeadAlgorithm for RecordingTls13Aead {
+    fn encrypter(&self, key: AeadKey, iv: Iv) -> Box<dyn MessageEncrypter> {
+        record_suite("TLSv1.3", self.suite);
+        self.inner.encrypter(key, iv)
+    }
+
+    fn decrypter(&self, key: AeadKey, iv: Iv) -> Box<dyn MessageDecrypter> {
+        self.inner.decrypter(key, iv)
+    }
+
+    fn key_len(&self) -> usize {
+        self.inner.key_len()
+    }
+
+    fn extract_keys(
+        &self,
+        key: AeadKey,
+        iv: Iv,
+    ) -> Result<ConnectionTrafficSecrets, UnsupportedOperationError> {
+        self.inner.extract_keys(key, iv)
+    }
+
+    fn fips(&self) -> bool {
+        self.inner.fips()
+    }
+}
+
+struct RecordingTls12Aead {
+    inner: &'static dyn Tls12AeadAlgorithm,
+    suite: CipherSuite,
+}
+
+impl Tls12AeadAlgorithm for RecordingTls12Aead {
+    fn encrypter(&self, key: AeadKey, iv: &[u8], extra: &[u8]) -> Box<dyn MessageEncrypter> {
+        record_suite("TLSv1.2", self.suite);
+        self.inner.encrypter(key, iv, extra)
+    }
+
+    fn decrypter(&self, key: AeadKey, iv: &[u8]) -> Box<dyn MessageDecrypter> {
+        self.inner.decrypter(key, iv)
+    }
+
+    fn key_block_shape(&self) -> KeyBlockShape {
+        self.inner.key_block_shape()
+    }
+
+    fn extract_keys(
+        &self,
+        key: AeadKey,
+        iv: &[u8],
+        explicit: &[u8],
+    ) -> Result<ConnectionTrafficSecrets, UnsupportedOperationError> {
+        self.inner.extract_keys(key, iv, explicit)
+    }
+
+    fn fips(&self) -> bool {
+        self.inner.fips()
+    }
+}
+
+fn to_pem(cert: &CertificateDer<'_>) -> String {
+    let encoded = BASE64_STANDARD.encode(cert.as_ref());
+    let lines = encoded
+        .as_bytes()
+        .chunks(64)
+        .map(|c| String::from_utf8_lossy(c).to_string())
+        .collect::<Vec<String>>();
+    format!("-----BEGIN CERTIFICATE-----\n{}\n-----END CERTIFICATE-----\n", lines.join("\n"))
+}
+
+/// Records what the server presented, then defers to the real verifier. Without one (when
+/// certificate validation is disabled), everything is accepted.
+#[derive(Debug)]
+struct RecordingVerifier {
+    inner: Option<Arc<dyn ServerCertVerifier>>,
+    provider: Arc<CryptoProvider>,
+}
+
+impl ServerCertVerifier for RecordingVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> Result<ServerCertVerified, Error> {
+        let certificates = std::iter::once(end_entity).chain(intermediates).map(to_pem).collect();
+        with_current_timer(|t| t.set_tls_certificates(certificates));
+
+        match &self.inner {
+            Some(v) => {
+                v.verify_server_cert(end_entity, intermediates, server_name, ocsp_response, now)
+            }
+            None => Ok(ServerCertVerified::assertion()),
+        }
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, Error> {
+        match &self.inner {
+            Some(v) => v.verify_tls12_signature(message, cert, dss),
+            None => Ok(HandshakeSignatureValid::assertion()),
+        }
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, Error> {
+        match &self.inner {
+            Some(v) => v.verify_tls13_signature(message, cert, dss),
+            None => Ok(HandshakeSignatureValid::assertion()),
+        }
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        match &self.inner {
+            Some(v) => v.supported_verify_schemes(),
+            None => self.provider.signature_verification_algorithms.supported_schemes(),
+        }
+    }
+}
+
+/// rustls looks up a session to resume while building the ClientHello, which is the closest
+/// thing there is to a "handshake started" event.
+#[derive(Debug)]
+struct HandshakeStartStore {
+    inner: ClientSessionMemoryCache,
+}
+
+impl ClientSessionStore for HandshakeStartStore {
+    fn set_kx_hint(&self, server_name: ServerName<'static>, group: NamedGroup) {
+        self.inner.set_kx_hint(server_name, group)
+    }
+
+    fn kx_hint(&self, server_name: &ServerName<'_>) -> Option<NamedGroup> {
+        self.inner.kx_hint(server_name)
+    }
+
+    fn set_tls12_session(&self, server_name: ServerName<'static>, value: Tls12ClientSessionValue) {
+        self.inner.set_tls12_session(server_name, value)
+    }
+
+    fn tls12_session(&self, server_name: &ServerName<'_>) -> Option<Tls12ClientSessionValue> {
+        with_current_timer(|t| t.tls_started());
+        self.inner.tls12_session(server_name)
+    }
+
+    fn remove_tls12_session(&self, server_name: &ServerName<'static>) {
+        self.inner.remove_tls12_session(server_name)
+    }
+
+    fn insert_tls13_ticket(
+        &self,
+        server_name: ServerName<'static>,
+        value: Tls13ClientSessionValue,
+    ) {
+        self.inner.insert_tls13_ticket(server_name, value)
+    }
+
+    fn take_tls13_ticket(
+        &self,
+        server_name: &ServerName<'static>,
+    ) -> Option<Tls13ClientSessionValue> {
+        with_current_timer(|t| t.tls_started());
+        self.inner.take_tls13_ticket(server_name)
+    }
+}
+
+#[cfg(test)]
+mod tls_tests {
+    use crate::timing::{ConnectionTimer, with_timer};
+    use crate::tls::recording_cipher_suites;
+    use rustls::crypto::cipher::{AeadKey, Iv};
+    use rustls::{CipherSuite, SupportedCipherSuite};
+
+    #[test]
+    fn records_suite_of_connection_being_opened() {
+        let suite = recording_cipher_suites()
+            .iter()
+            .find_map(|s| match s {
+                SupportedCipherSuite::Tls13(s)
+                    if s.common.suite == CipherSuite::TLS13_AES_256_GCM_SHA384 =>
+                {
+                    Some(*s)
+                }
+                _ => None,
+            })
+            .unwrap();
+
+        // Keys installed outside a handshake belong to no connection
+        let timer = ConnectionTimer::new();
+        suite.aead_alg.encrypter(AeadKey::from([0; 32]), Iv::new([0; 12]));
+        assert!(timer.tls_info().is_none());
+
+        with_timer(Some(&timer), || {
+            suite.aead_alg.encrypter(AeadKey::from([0; 32]), Iv::new([0; 12]))
+        });
+        let info = timer.tls_info().unwrap();
+        assert_eq!(info.version.as_deref(), Some("TLSv1.3"));
+        assert_eq!(info.cipher.as_deref(), Some("TLS13_AES_256_GCM_SHA384"));
+    }
+}
@@ -0,0 +1,81 @@
+use crate::error::Error::GenericError;
+use crate::error::Result;
+use std::path::PathBuf;
+
+/// Scheme of URLs that are sent over a Unix domain socket, rather than TCP. The socket path and
+/// the path to request from it are separated by the first `:`, as in
+/// `unix:///var/run/docker.sock:/v1.43/containers/json`.
+pub const UNIX_SCHEME: &str = "unix://";
+
+/// A request target that's reached through a Unix domain socket
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnixSocketTarget {
+    pub socket_path: PathBuf,
+    /// The HTTP URL to request over the socket. Its host only ends up in the `Host` header.
+    pub url: String,
+}
+
+impl UnixSocketTarget {
+    /// Split a `unix://` URL into its socket path and the HTTP URL to request over it, or return
+    /// `None` for URLs with any other scheme
+    pub fn parse(url: &str) -> Result<Option<UnixSocketTarget>> {
+        let rest = match url.strip_prefix(UNIX_SCHEME) {
+            Some(rest) => rest,
+            None => return Ok(None),
+        };
+
+        let (socket_path, path) = match rest.split_once(':') {
+            Some((socket_path, path)) => (socket_path, path),
+            None => (rest, "/"),
+        };
+        if socket_path.is_empty() {
+            return Err(GenericError(format!("Missing socket path in URL \"{url}\"")));
+        }
+
+        let separator = if path.starts_with('/') { "" } else { "/" };
+        Ok(Some(UnixSocketTarget {
+            socket_path: PathBuf::from(socket_path),
+            url: format!("http://localhost{separator}{path}"),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod unix_socket_tests {
+    use crate::sender::ensure_proto;
+    use crate::unix_socket::UnixSocketTarget;
+    use std::path::PathBuf;
+
+    #[test]
+    fn splits_socket_path_from_request_path() {
+        let target =
+            UnixSocketTarget::parse("unix:///var/run/docker.sock:/v1.43/containers/json?all=1")
+                .unwrap()
+                .unwrap();
+        assert_eq!(target.socket_path, PathBuf::from("/var/run/docker.sock"));
+        assert_eq!(target.url, "http://localhost/v1.43/containers/json?all=1");
+    }
+
+    #[test]
+    fn defaults_to_root_path() {
+        let target = UnixSocketTarget::parse("unix:///tmp/app.sock").unwrap().unwrap();
+        assert_eq!(target.socket_path, PathBuf::from("/tmp/app.sock"));
+        assert_eq!(target.url, "http://localhost/");
+
+        let target = UnixSocketTarget::parse("unix:///tmp/app.sock:health").unwrap().unwrap();
+        assert_eq!(target.url, "http://localhost/health");
+    }
+
+    #[test]
+    fn ignores_other_schemes() {
+        assert_eq!(UnixSocketTarget::parse("http://localhost:8080/unix").unwrap(), None);
+        assert!(UnixSocketTarget::parse("unix://:/containers").is_err());
+    }
+
+    #[test]
+    fn keeps_unix_scheme_when_ensuring_proto() {
+        let url = "unix:///var/run/docker.sock:/_ping";
+        assert_eq!(ensure_proto(url), url);
+        assert_eq!(ensure_proto("localhost:8080"), "http://localhost:8080");
+    }
+}
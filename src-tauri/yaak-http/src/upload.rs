@@ -0,0 +1,128 @@
+use futures_util::{Stream, StreamExt, TryStreamExt, stream};
+use reqwest::Body;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tokio::fs::File;
+use tokio::io::AsyncReadExt;
+use tokio::sync::watch;
+use tokio_util::io::ReaderStream;
+
+/// Shared count of the request body bytes that were handed to the client so far. Every streamed
+/// file of a request adds to the same counter, so multipart forms report their total progress.
+#[derive(Clone)]
+pub struct UploadProgress {
+    tx: Arc<watch::Sender<u64>>,
+}
+
+impl UploadProgress {
+    pub fn new() -> (Self, watch::Receiver<u64>) {
+        let (tx, rx) = watch::channel(0);
+        (Self { tx: Arc::new(tx) }, rx)
+    }
+
+    fn add(&self, n: u64) {
+        self.tx.send_modify(|uploaded| *uploaded += n);
+    }
+}
+
+/// Files are read into memory up to this many bytes in total, so the requests sending them can
+/// still be cloned. That's needed to retry them, re-send them on 307 and 308 redirects, and pass
+/// their body to auth plugins for signing.
+pub const BUFFERED_BODY_LIMIT: u64 = 8 * 1024 * 1024;
+
+/// A body with the contents of a file. Files up to `buffer_limit` bytes are read into memory,
+/// while larger ones are read from disk in chunks as they're sent. Also returns the size of the
+/// file, so it can be set as the content length.
+pub async fn file_body(
+    path: impl AsRef<Path>,
+    progress: &UploadProgress,
+    buffer_limit: u64,
+) -> io::Result<(Body, u64)> {
+    let mut file = File::open(path).await?;
+    let size = file.metadata().await?.len();
+    if size <= buffer_limit {
+        let mut bytes = Vec::with_capacity(size as usize);
+        file.read_to_end(&mut bytes).await?;
+        // The client gets the whole body at once, so it all counts as handed over
+        let size = bytes.len() as u64;
+        progress.add(size);
+        return Ok((Body::from(bytes), size));
+    }
+
+    Ok((Body::wrap_stream(sized_stream(file, size, progress)), size))
+}
+
+/// Stream exactly `size` bytes of the file, since that's the content length that was set. A file
+/// that grew since is cut off there, and one that shrank fails the request instead of leaving it
+/// short.
+fn sized_stream(
+    file: File,
+    size: u64,
+    progress: &UploadProgress,
+) -> impl Stream<Item = io::Result<bytes::Bytes>> + use<> {
+    let progress = progress.clone();
+    let sent = Arc::new(AtomicU64::new(0));
+    let counted = sent.clone();
+    let chunks = ReaderStream::new(file.take(size)).inspect_ok(move |chunk| {
+        counted.fetch_add(chunk.len() as u64, Ordering::Relaxed);
+        progress.add(chunk.len() as u64);
+    });
+    let end = stream::once(async move {
+        match sent.load(Ordering::Relaxed) {
+            n if n < size => Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                format!("File changed while being sent, from {size} to {n} bytes"),
+            )),
+            _ => Ok(Default::default()),
+        }
+    });
+    chunks.chain(end)
+}
+
+#[cfg(test)]
+mod upload_tests {
+    use crate::upload::{UploadProgress, file_body, sized_stream};
+    use futures_util::TryStreamExt;
+    use tokio::fs::File;
+
+    #[tokio::test]
+    async fn buffers_small_files_and_streams_large_ones() {
+        let path = std::env::temp_dir().join(format!("yaak-upload-{}", uuid::Uuid::new_v4()));
+        std::fs::write(&path, "hello world").unwrap();
+        let (progress, rx) = UploadProgress::new();
+
+        let (body, size) = file_body(&path, &progress, 11).await.unwrap();
+        assert_eq!(size, 11);
+        assert_eq!(body.as_bytes(), Some(&b"hello world"[..]));
+        assert_eq!(*rx.borrow(), 11);
+
+        let (body, size) = file_body(&path, &progress, 10).await.unwrap();
+        assert_eq!(size, 11);
+        assert_eq!(body.as_bytes(), None);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn streams_the_size_the_file_had_when_opened() {
+        let path = std::env::temp_dir().join(format!("yaak-upload-{}", uuid::Uuid::new_v4()));
+        let (progress, _rx) = UploadProgress::new();
+        let sent = async |size| {
+            let chunks = sized_stream(File::open(&path).await.unwrap(), size, &progress);
+            let chunks = chunks.try_collect::<Vec<_>>().await?;
+            Ok::<_, std::io::Error>(chunks.iter().map(|c| c.len()).sum::<usize>())
+        };
+
+        // Grew since its size was checked
+        std::fs::write(&path, "hello world").unwrap();
+        assert_eq!(sent(5).await.unwrap(), 5);
+
+        // Shrank since its size was checked
+        let err = sent(20).await.unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -1,6 +1,21 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type AnyModel = CookieJar | Environment | Folder | GrpcConnection | GrpcEvent | GrpcRequest | HttpRequest | HttpResponse | KeyValue | Plugin | Settings | SyncState | WebsocketConnection | WebsocketEvent | WebsocketRequest | Workspace | WorkspaceMeta;
+export type AnyModel = CollectionRun | CookieJar | Environment | Folder | GrpcConnection | GrpcEvent | GrpcRequest | HttpRequest | HttpResponse | HttpResponseEvent | KeyValue | Plugin | Settings | SseConnection | SseEvent | SseRequest | SyncState | WebsocketConnection | WebsocketEvent | WebsocketRequest | Workspace | WorkspaceMeta;
+
+/**
+ * A client certificate, either as PEM certificate and key files or as a single PKCS#12 file
+ */
+export type ClientCertificate = { enabled?: boolean, 
+/**
+ * Host to use the certificate for, like `api.example.com`, `*.example.com` or `localhost:8443`
+ */
+host: string, crtFile: string | null, keyFile: string | null, pfxFile: string | null, passphrase: string | null, };
+
+export type CollectionRun = { model: "collection_run", id: string, createdAt: string, updatedAt: string, workspaceId: string, folderId: string | null, environmentId: string | null, cookieJarId: string | null, delay: number, elapsed: number, iterations: number, results: Array<CollectionRunResult>, state: CollectionRunState, stopOnFailure: boolean, };
+
+export type CollectionRunResult = { requestId: string, responseId: string, iteration: number, passed: boolean, status: number, elapsed: number, error: string | null, };
+
+export type CollectionRunState = "running" | "passed" | "failed" | "cancelled";
 
 export type Cookie = { raw_cookie: string, domain: CookieDomain, expires: CookieExpires, path: [string, boolean], };
 
@@ -10,6 +25,20 @@ export type CookieExpires = { "AtUtc": string } | "SessionEnd";
 
 export type CookieJar = { model: "cookie_jar", id: string, createdAt: string, updatedAt: string, workspaceId: string, cookies: Array<Cookie>, name: string, };
 
+/**
+ * An address to connect to for a host, instead of the one from DNS, like curl's `--resolve`.
+ * Both fields may contain templates.
+ */
+export type DnsOverride = { enabled?: boolean, 
+/**
+ * Hostname to override, like `api.example.com`
+ */
+host: string, 
+/**
+ * IPv4 or IPv6 address to connect to instead
+ */
+address: string, id?: string, };
+
 export type EditorKeymap = "default" | "vim" | "vscode" | "emacs";
 
 export type EncryptedKey = { encryptedKey: string, };
@@ -18,7 +47,7 @@ export type Environment = { model: "environment", id: string, workspaceId: strin
 
 export type EnvironmentVariable = { enabled?: boolean, name: string, value: string, id?: string, };
 
-export type Folder = { model: "folder", id: string, createdAt: string, updatedAt: string, workspaceId: string, folderId: string | null, name: string, description: string, sortPriority: number, };
+export type Folder = { model: "folder", id: string, createdAt: string, updatedAt: string, workspaceId: string, folderId: string | null, authentication: Record<string, any>, authenticationType: string | null, description: string, headers: Array<HttpRequestHeader>, name: string, sortPriority: number, variables: Array<EnvironmentVariable>, settingFollowRedirects: boolean | null, settingMaxResponseSize: number | null, settingRequestTimeout: number | null, settingValidateCertificates: boolean | null, };
 
 export type GrpcConnection = { model: "grpc_connection", id: string, createdAt: string, updatedAt: string, workspaceId: string, requestId: string, elapsed: number, error: string | null, method: string, service: string, status: number, state: GrpcConnectionState, trailers: { [key in string]?: string }, url: string, };
 
@@ -30,20 +59,128 @@ export type GrpcEventType = "info" | "error" | "client_message" | "server_messag
 
 export type GrpcMetadataEntry = { enabled?: boolean, name: string, value: string, id?: string, };
 
-export type GrpcRequest = { model: "grpc_request", id: string, createdAt: string, updatedAt: string, workspaceId: string, folderId: string | null, authenticationType: string | null, authentication: Record<string, any>, description: string, message: string, metadata: Array<GrpcMetadataEntry>, method: string | null, name: string, service: string | null, sortPriority: number, url: string, };
+export type GrpcRequest = { model: "grpc_request", id: string, createdAt: string, updatedAt: string, workspaceId: string, folderId: string | null, authenticationType: string | null, authentication: Record<string, any>, description: string, message: string, metadata: Array<GrpcMetadataEntry>, method: string | null, name: string, service: string | null, sortPriority: number, url: string, settingRequestTimeout: number | null, settingValidateCertificates: boolean | null, };
+
+export type HttpAssertionOperator = "equals" | "not_equals" | "contains" | "matches" | "less_than" | "greater_than" | "exists";
+
+export type HttpAssertionResult = { source: HttpAssertionSource, property: string, operator: HttpAssertionOperator, expected: string, actual: string | null, passed: boolean, error: string | null, };
+
+export type HttpAssertionSource = "status" | "header" | "json_path" | "elapsed";
+
+export type HttpRequest = { model: "http_request", id: string, createdAt: string, updatedAt: string, workspaceId: string, folderId: string | null, assertions: Array<HttpRequestAssertion>, authentication: Record<string, any>, authenticationType: string | null, body: Record<string, any>, bodyType: string | null, description: string, headers: Array<HttpRequestHeader>, httpVersion: HttpVersion, method: string, name: string, retry: HttpRequestRetry, sortPriority: number, url: string, urlParameters: Array<HttpUrlParameter>, settingFollowRedirects: boolean | null, settingMaxResponseSize: number | null, settingRequestTimeout: number | null, settingValidateCertificates: boolean | null, };
 
-export type HttpRequest = { model: "http_request", id: string, createdAt: string, updatedAt: string, workspaceId: string, folderId: string | null, authentication: Record<string, any>, authenticationType: string | null, body: Record<string, any>, bodyType: string | null, description: string, headers: Array<HttpRequestHeader>, method: string, name: string, sortPriority: number, url: string, urlParameters: Array<HttpUrlParameter>, };
+export type HttpRequestAssertion = { enabled?: boolean, source: HttpAssertionSource, property: string, operator: HttpAssertionOperator, value: string, id?: string, };
 
 export type HttpRequestHeader = { enabled?: boolean, name: string, value: string, id?: string, };
 
-export type HttpResponse = { model: "http_response", id: string, createdAt: string, updatedAt: string, workspaceId: string, requestId: string, bodyPath: string | null, contentLength: number | null, elapsed: number, elapsedHeaders: number, error: string | null, headers: Array<HttpResponseHeader>, remoteAddr: string | null, status: number, statusReason: string | null, state: HttpResponseState, url: string, version: string | null, };
+/**
+ * When and how often to re-send a request that failed
+ */
+export type HttpRequestRetry = { enabled: boolean, 
+/**
+ * Total number of attempts, including the first one
+ */
+maxAttempts: number, statusCodes: Array<number>, errors: Array<HttpRetryError>, 
+/**
+ * Delay before the first retry, in milliseconds. It doubles for each retry after that.
+ */
+initialDelay: number, 
+/**
+ * Upper bound for the delay between attempts, in milliseconds
+ */
+maxDelay: number, 
+/**
+ * Wait as long as the server's Retry-After header asks, instead of backing off
+ */
+respectRetryAfter: boolean, };
+
+export type HttpResponse = { model: "http_response", id: string, createdAt: string, updatedAt: string, workspaceId: string, requestId: string, assertionResults: Array<HttpAssertionResult>, attempts: Array<HttpResponseAttempt>, bodyPath: string | null, 
+/**
+ * Bytes of the request body files that were uploaded so far
+ */
+bytesUploaded: number, contentLength: number | null, elapsed: number, elapsedHeaders: number, error: string | null, headers: Array<HttpResponseHeader>, redirects: Array<HttpResponseRedirect>, remoteAddr: string | null, sentRequest: HttpSentRequest | null, status: number, statusReason: string | null, state: HttpResponseState, timings: HttpResponseTimings, tlsInfo: HttpResponseTlsInfo | null, 
+/**
+ * Whether the body was cut off, because it was longer than the max response size
+ */
+truncated: boolean, 
+/**
+ * Total size of the request body files being uploaded, when the body is streamed from disk
+ */
+uploadSize: number | null, url: string, version: string | null, };
+
+/**
+ * One attempt at sending a request that has retries enabled
+ */
+export type HttpResponseAttempt = { 
+/**
+ * Status of the response, or 0 when the attempt failed without one
+ */
+status: number, error: string | null, elapsed: number, 
+/**
+ * How long was waited after this attempt before making the next one, in milliseconds
+ */
+delay: number, };
+
+/**
+ * A server-sent event of a `text/event-stream` response, stored as soon as it was received
+ */
+export type HttpResponseEvent = { model: "http_response_event", id: string, createdAt: string, updatedAt: string, workspaceId: string, requestId: string, responseId: string, data: string, 
+/**
+ * The `id` field of the event, not to be confused with the model ID
+ */
+eventId: string | null, eventType: string, retry: number | null, };
 
 export type HttpResponseHeader = { name: string, value: string, };
 
+/**
+ * A redirect response that was followed on the way to the final response
+ */
+export type HttpResponseRedirect = { method: string, url: string, status: number, headers: Array<HttpResponseHeader>, };
+
 export type HttpResponseState = "initialized" | "connected" | "closed";
 
+/**
+ * How long each phase of the request took, in milliseconds. The connection phases are empty when
+ * they didn't happen, like DNS for an IP address or all of them when a connection was reused.
+ */
+export type HttpResponseTimings = { dns: number | null, connect: number | null, tls: number | null, 
+/**
+ * From the connection being ready until the response headers arrived
+ */
+ttfb: number, download: number, };
+
+export type HttpResponseTlsInfo = { version: string | null, 
+/**
+ * Name of the negotiated cipher suite, like `TLS13_AES_128_GCM_SHA256`
+ */
+cipher: string | null, 
+/**
+ * PEM encoded certificates presented by the server, starting with the leaf
+ */
+certificates: Array<string>, };
+
+/**
+ * Transport failures that a request can be retried after
+ */
+export type HttpRetryError = "connect" | "timeout" | "other";
+
+/**
+ * The request as it went on the wire, after cookies and authentication were applied. Headers
+ * that the HTTP client adds itself (eg. Host and Accept-Encoding) are not included.
+ */
+export type HttpSentRequest = { method: string, url: string, headers: Array<HttpResponseHeader>, 
+/**
+ * Unknown for streamed bodies, like large file uploads
+ */
+bodySize: number | null, bodyPreview: string | null, };
+
 export type HttpUrlParameter = { enabled?: boolean, name: string, value: string, id?: string, };
 
+/**
+ * HTTP version to send a request with
+ */
+export type HttpVersion = "auto" | "http1" | "http2" | "http3";
+
 export type KeyValue = { model: "key_value", id: string, createdAt: string, updatedAt: string, key: string, namespace: string, value: string, };
 
 export type ModelChangeEvent = { "type": "upsert" } | { "type": "delete" };
@@ -54,12 +191,47 @@ export type Plugin = { model: "plugin", id: string, createdAt: string, updatedAt
 
 export type PluginKeyValue = { model: "plugin_key_value", createdAt: string, updatedAt: string, pluginName: string, key: string, value: string, };
 
-export type ProxySetting = { "type": "enabled", disabled: boolean, http: string, https: string, auth: ProxySettingAuth | null, } | { "type": "disabled" };
+export type ProxySetting = { "type": "enabled", disabled: boolean, http: string, https: string, auth: ProxySettingAuth | null, 
+/**
+ * Hosts to connect to directly, in the same comma-separated format as NO_PROXY
+ */
+bypass: string, } | { "type": "socks", disabled: boolean, 
+/**
+ * Address of the SOCKS5 proxy, like localhost:1080
+ */
+host: string, 
+/**
+ * Let the proxy resolve hostnames, instead of resolving them locally
+ */
+remoteDns: boolean, auth: ProxySettingAuth | null, bypass: string, } | { "type": "pac", disabled: boolean, 
+/**
+ * URL or file path of the proxy auto-config script
+ */
+url: string, bypass: string, } | { "type": "disabled" };
 
 export type ProxySettingAuth = { user: string, password: string, };
 
 export type Settings = { model: "settings", id: string, createdAt: string, updatedAt: string, appearance: string, editorFontSize: number, editorSoftWrap: boolean, interfaceFontSize: number, interfaceScale: number, openWorkspaceNewWindow: boolean | null, proxy: ProxySetting | null, themeDark: string, themeLight: string, updateChannel: string, editorKeymap: EditorKeymap, };
 
+export type SseConnection = { model: "sse_connection", id: string, createdAt: string, updatedAt: string, workspaceId: string, requestId: string, elapsed: number, error: string | null, headers: Array<HttpResponseHeader>, 
+/**
+ * ID of the last received event, sent as `Last-Event-ID` when reconnecting
+ */
+lastEventId: string | null, reconnects: number, state: SseConnectionState, status: number, url: string, };
+
+export type SseConnectionState = "initialized" | "connected" | "reconnecting" | "closing" | "closed";
+
+/**
+ * An event received on an SSE connection
+ */
+export type SseEvent = { model: "sse_event", id: string, createdAt: string, updatedAt: string, workspaceId: string, requestId: string, connectionId: string, data: string, 
+/**
+ * The `id` field of the event, not to be confused with the model ID
+ */
+eventId: string | null, eventType: string, retry: number | null, };
+
+export type SseRequest = { model: "sse_request", id: string, createdAt: string, updatedAt: string, workspaceId: string, folderId: string | null, authentication: Record<string, any>, authenticationType: string | null, description: string, headers: Array<HttpRequestHeader>, name: string, sortPriority: number, url: string, urlParameters: Array<HttpUrlParameter>, settingRequestTimeout: number | null, settingValidateCertificates: boolean | null, };
+
 export type SyncState = { model: "sync_state", id: string, workspaceId: string, createdAt: string, updatedAt: string, flushedAt: string, modelId: string, checksum: string, relPath: string, syncDir: string, };
 
 export type UpdateSource = { "type": "background" } | { "type": "import" } | { "type": "plugin" } | { "type": "sync" } | { "type": "window", label: string, };
@@ -74,8 +246,29 @@ export type WebsocketEventType = "binary" | "close" | "frame" | "open" | "ping"
 
 export type WebsocketMessageType = "text" | "binary";
 
-export type WebsocketRequest = { model: "websocket_request", id: string, createdAt: string, updatedAt: string, workspaceId: string, folderId: string | null, authentication: Record<string, any>, authenticationType: string | null, description: string, headers: Array<HttpRequestHeader>, message: string, name: string, sortPriority: number, url: string, urlParameters: Array<HttpUrlParameter>, };
-
-export type Workspace = { model: "workspace", id: string, createdAt: string, updatedAt: string, name: string, description: string, encryptionKeyChallenge: string | null, settingValidateCertificates: boolean, settingFollowRedirects: boolean, settingRequestTimeout: number, };
+export type WebsocketRequest = { model: "websocket_request", id: string, createdAt: string, updatedAt: string, workspaceId: string, folderId: string | null, authentication: Record<string, any>, authenticationType: string | null, description: string, headers: Array<HttpRequestHeader>, message: string, name: string, sortPriority: number, url: string, urlParameters: Array<HttpUrlParameter>, settingRequestTimeout: number | null, settingValidateCertificates: boolean | null, };
+
+export type Workspace = { model: "workspace", id: string, createdAt: string, updatedAt: string, name: string, description: string, encryptionKeyChallenge: string | null, authentication: Record<string, any>, authenticationType: string | null, headers: Array<HttpRequestHeader>, variables: Array<EnvironmentVariable>, settingValidateCertificates: boolean, settingFollowRedirects: boolean, settingMaxRedirects: number, 
+/**
+ * Switch to GET (dropping the body) when following a 301, 302 or 303, like browsers do
+ */
+settingRedirectRewriteMethod: boolean, settingRequestTimeout: number, 
+/**
+ * Largest response body to store, in bytes. Longer bodies are truncated and their download
+ * stopped. 0 is unlimited.
+ */
+settingMaxResponseSize: number, 
+/**
+ * Certificates to present to servers that ask for one (mutual TLS), matched by host
+ */
+settingClientCertificates: Array<ClientCertificate>, 
+/**
+ * Paths to PEM bundles of extra CAs to trust, on top of the system roots
+ */
+settingCaCertificates: Array<string>, 
+/**
+ * Addresses to connect to instead of the ones DNS returns, by host
+ */
+settingDnsOverrides: Array<DnsOverride>, };
 
 export type WorkspaceMeta = { model: "workspace_meta", id: string, workspaceId: string, createdAt: string, updatedAt: string, encryptionKey: EncryptedKey | null, settingSyncDir: string | null, };
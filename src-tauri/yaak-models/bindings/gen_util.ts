@@ -3,7 +3,8 @@ import type { Environment } from "./gen_models";
 import type { Folder } from "./gen_models";
 import type { GrpcRequest } from "./gen_models";
 import type { HttpRequest } from "./gen_models";
+import type { SseRequest } from "./gen_models";
 import type { WebsocketRequest } from "./gen_models";
 import type { Workspace } from "./gen_models";
 
-export type BatchUpsertResult = { workspaces: Array<Workspace>, environments: Array<Environment>, folders: Array<Folder>, httpRequests: Array<HttpRequest>, grpcRequests: Array<GrpcRequest>, websocketRequests: Array<WebsocketRequest>, };
+export type BatchUpsertResult = { workspaces: Array<Workspace>, environments: Array<Environment>, folders: Array<Folder>, httpRequests: Array<HttpRequest>, grpcRequests: Array<GrpcRequest>, websocketRequests: Array<WebsocketRequest>, sseRequests: Array<SseRequest>, };
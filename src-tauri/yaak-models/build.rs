@@ -3,11 +3,15 @@ const COMMANDS: &[&str] = &[
     "duplicate",
     "get_settings",
     "grpc_events",
+    "http_response_events",
+    "sse_events",
     "upsert",
     "websocket_events",
     "workspace_models",
 ];
 
 fn main() {
+    // The CLI embeds the migrations, so rebuild when they change
+    println!("cargo:rerun-if-changed=../migrations");
     tauri_plugin::Builder::new(COMMANDS).build();
 }
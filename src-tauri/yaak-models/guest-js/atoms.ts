@@ -7,6 +7,7 @@ import { newStoreData } from './util';
 
 export const modelStoreDataAtom = atom(newStoreData());
 
+export const collectionRunsAtom = createOrderedModelAtom('collection_run', 'createdAt', 'desc');
 export const cookieJarsAtom = createOrderedModelAtom('cookie_jar', 'name', 'asc');
 export const environmentsAtom = createOrderedModelAtom('environment', 'name', 'asc');
 export const foldersAtom = createModelAtom('folder');
@@ -15,9 +16,17 @@ export const grpcEventsAtom = createOrderedModelAtom('grpc_event', 'createdAt',
 export const grpcRequestsAtom = createModelAtom('grpc_request');
 export const httpRequestsAtom = createModelAtom('http_request');
 export const httpResponsesAtom = createOrderedModelAtom('http_response', 'createdAt', 'desc');
+export const httpResponseEventsAtom = createOrderedModelAtom(
+  'http_response_event',
+  'createdAt',
+  'asc',
+);
 export const keyValuesAtom = createModelAtom('key_value');
 export const pluginsAtom = createModelAtom('plugin');
 export const settingsAtom = createSingularModelAtom('settings');
+export const sseConnectionsAtom = createOrderedModelAtom('sse_connection', 'createdAt', 'desc');
+export const sseEventsAtom = createOrderedModelAtom('sse_event', 'createdAt', 'asc');
+export const sseRequestsAtom = createModelAtom('sse_request');
 export const websocketRequestsAtom = createModelAtom('websocket_request');
 export const websocketEventsAtom = createOrderedModelAtom('websocket_event', 'createdAt', 'asc');
 export const websocketConnectionsAtom = createOrderedModelAtom(
@@ -2,6 +2,7 @@ import { ModelStoreData } from './types';
 
 export function newStoreData(): ModelStoreData {
   return {
+    collection_run: {},
     cookie_jar: {},
     environment: {},
     folder: {},
@@ -10,9 +11,13 @@ export function newStoreData(): ModelStoreData {
     grpc_request: {},
     http_request: {},
     http_response: {},
+    http_response_event: {},
     key_value: {},
     plugin: {},
     settings: {},
+    sse_connection: {},
+    sse_event: {},
+    sse_request: {},
     sync_state: {},
     websocket_connection: {},
     websocket_event: {},
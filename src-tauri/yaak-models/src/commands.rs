@@ -1,6 +1,6 @@
 use crate::error::Error::GenericError;
 use crate::error::Result;
-use crate::models::{AnyModel, GrpcEvent, Settings, WebsocketEvent};
+use crate::models::{AnyModel, GrpcEvent, HttpResponseEvent, Settings, SseEvent, WebsocketEvent};
 use crate::query_manager::QueryManagerExt;
 use crate::util::UpdateSource;
 use tauri::{AppHandle, Runtime, WebviewWindow};
@@ -19,6 +19,7 @@ pub(crate) fn upsert<R: Runtime>(window: WebviewWindow<R>, model: AnyModel) -> R
         AnyModel::KeyValue(m) => db.upsert_key_value(&m, source)?.id,
         AnyModel::Plugin(m) => db.upsert_plugin(&m, source)?.id,
         AnyModel::Settings(m) => db.upsert_settings(&m, source)?.id,
+        AnyModel::SseRequest(m) => db.upsert_sse_request(&m, source)?.id,
         AnyModel::WebsocketRequest(m) => db.upsert_websocket_request(&m, source)?.id,
         AnyModel::Workspace(m) => db.upsert_workspace(&m, source)?.id,
         AnyModel::WorkspaceMeta(m) => db.upsert_workspace_meta(&m, source)?.id,
@@ -34,6 +35,7 @@ pub(crate) fn delete<R: Runtime>(window: WebviewWindow<R>, model: AnyModel) -> R
     window.with_tx(|tx| {
         let source = &UpdateSource::from_window(&window);
         let id = match model {
+            AnyModel::CollectionRun(m) => tx.delete_collection_run(&m, source)?.id,
             AnyModel::CookieJar(m) => tx.delete_cookie_jar(&m, source)?.id,
             AnyModel::Environment(m) => tx.delete_environment(&m, source)?.id,
             AnyModel::Folder(m) => tx.delete_folder(&m, source)?.id,
@@ -42,6 +44,8 @@ pub(crate) fn delete<R: Runtime>(window: WebviewWindow<R>, model: AnyModel) -> R
             AnyModel::HttpRequest(m) => tx.delete_http_request(&m, source)?.id,
             AnyModel::HttpResponse(m) => tx.delete_http_response(&m, source)?.id,
             AnyModel::Plugin(m) => tx.delete_plugin(&m, source)?.id,
+            AnyModel::SseConnection(m) => tx.delete_sse_connection(&m, source)?.id,
+            AnyModel::SseRequest(m) => tx.delete_sse_request(&m, source)?.id,
             AnyModel::WebsocketConnection(m) => tx.delete_websocket_connection(&m, source)?.id,
             AnyModel::WebsocketRequest(m) => tx.delete_websocket_request(&m, source)?.id,
             AnyModel::Workspace(m) => tx.delete_workspace(&m, source)?.id,
@@ -61,6 +65,7 @@ pub(crate) fn duplicate<R: Runtime>(window: WebviewWindow<R>, model: AnyModel) -
             AnyModel::Folder(m) => tx.duplicate_folder(&m, source)?.id,
             AnyModel::GrpcRequest(m) => tx.duplicate_grpc_request(&m, source)?.id,
             AnyModel::HttpRequest(m) => tx.duplicate_http_request(&m, source)?.id,
+            AnyModel::SseRequest(m) => tx.duplicate_sse_request(&m, source)?.id,
             AnyModel::WebsocketRequest(m) => tx.duplicate_websocket_request(&m, source)?.id,
             a => return Err(GenericError(format!("Cannot duplicate AnyModel {a:?})"))),
         };
@@ -85,6 +90,22 @@ pub(crate) fn grpc_events<R: Runtime>(
     Ok(app_handle.db().list_grpc_events(connection_id)?)
 }
 
+#[tauri::command]
+pub(crate) fn http_response_events<R: Runtime>(
+    app_handle: AppHandle<R>,
+    response_id: &str,
+) -> Result<Vec<HttpResponseEvent>> {
+    Ok(app_handle.db().list_http_response_events(response_id)?)
+}
+
+#[tauri::command]
+pub(crate) fn sse_events<R: Runtime>(
+    app_handle: AppHandle<R>,
+    connection_id: &str,
+) -> Result<Vec<SseEvent>> {
+    Ok(app_handle.db().list_sse_events(connection_id)?)
+}
+
 #[tauri::command]
 pub(crate) fn get_settings<R: Runtime>(app_handle: AppHandle<R>) -> Result<Settings> {
     Ok(app_handle.db().get_settings())
@@ -108,6 +129,7 @@ pub(crate) fn workspace_models<R: Runtime>(
 
     // Add the workspace children
     if let Some(wid) = workspace_id {
+        l.append(&mut db.list_collection_runs(wid)?.into_iter().map(Into::into).collect());
         l.append(&mut db.list_cookie_jars(wid)?.into_iter().map(Into::into).collect());
         l.append(&mut db.list_environments_ensure_base(wid)?.into_iter().map(Into::into).collect());
         l.append(&mut db.list_folders(wid)?.into_iter().map(Into::into).collect());
@@ -115,6 +137,8 @@ pub(crate) fn workspace_models<R: Runtime>(
         l.append(&mut db.list_grpc_requests(wid)?.into_iter().map(Into::into).collect());
         l.append(&mut db.list_http_requests(wid)?.into_iter().map(Into::into).collect());
         l.append(&mut db.list_http_responses(wid, None)?.into_iter().map(Into::into).collect());
+        l.append(&mut db.list_sse_connections(wid)?.into_iter().map(Into::into).collect());
+        l.append(&mut db.list_sse_requests(wid)?.into_iter().map(Into::into).collect());
         l.append(&mut db.list_websocket_connections(wid)?.into_iter().map(Into::into).collect());
         l.append(&mut db.list_websocket_requests(wid)?.into_iter().map(Into::into).collect());
         l.append(&mut db.list_workspace_metas(wid)?.into_iter().map(Into::into).collect());
@@ -9,6 +9,12 @@ pub enum Error {
     #[error("SQL Pool error: {0}")]
     SqlPoolError(#[from] r2d2::Error),
 
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+
+    #[error("Migration error: {0}")]
+    MigrateError(#[from] sqlx::migrate::MigrateError),
+
     #[error("JSON error: {0}")]
     JsonError(#[from] serde_json::Error),
 
@@ -1,6 +1,7 @@
 use crate::commands::*;
 use crate::query_manager::QueryManager;
-use crate::util::ModelChangeEvent;
+use crate::error::Result;
+use crate::util::{ModelChangeEvent, ModelPayload};
 use log::info;
 use r2d2::Pool;
 use r2d2_sqlite::SqliteConnectionManager;
@@ -8,7 +9,7 @@ use sqlx::migrate::Migrator;
 use sqlx::sqlite::SqliteConnectOptions;
 use sqlx::SqlitePool;
 use std::fs::create_dir_all;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::time::Duration;
 use tauri::async_runtime::Mutex;
@@ -45,6 +46,8 @@ pub fn init<R: Runtime>() -> TauriPlugin<R> {
             workspace_models,
             grpc_events,
             websocket_events,
+            http_response_events,
+            sse_events,
             get_settings,
         ])
         .setup(|app_handle, _api| {
@@ -60,12 +63,7 @@ pub fn init<R: Runtime>() -> TauriPlugin<R> {
                 });
             };
 
-            let manager = SqliteConnectionManager::file(db_file_path);
-            let pool = Pool::builder()
-                .max_size(100) // Up from 10 (just in case)
-                .connection_timeout(Duration::from_secs(10)) // Down from 30
-                .build(manager)
-                .unwrap();
+            let pool = create_pool(&db_file_path).unwrap();
 
             app_handle.manage(SqliteConnection::new(pool.clone()));
 
@@ -89,20 +87,46 @@ pub fn init<R: Runtime>() -> TauriPlugin<R> {
         .build()
 }
 
+/// Open a database outside of Tauri (eg. for the CLI). Migrations are embedded in the binary,
+/// since there are no app resources to read them from, and model change events are sent to the
+/// returned receiver, which must be drained.
+pub async fn init_standalone(
+    db_file_path: &Path,
+) -> Result<(QueryManager, mpsc::Receiver<ModelPayload>)> {
+    migrate_db(db_file_path, sqlx::migrate!("../migrations")).await?;
+    let pool = create_pool(db_file_path)?;
+    let (tx, rx) = mpsc::channel(128);
+    Ok((QueryManager::new(pool, tx), rx))
+}
+
+fn create_pool(db_file_path: &Path) -> Result<Pool<SqliteConnectionManager>> {
+    let manager = SqliteConnectionManager::file(db_file_path);
+    let pool = Pool::builder()
+        .max_size(100) // Up from 10 (just in case)
+        .connection_timeout(Duration::from_secs(10)) // Down from 30
+        .build(manager)?;
+    Ok(pool)
+}
+
 async fn must_migrate_db<R: Runtime>(app_handle: &AppHandle<R>, sqlite_file_path: &PathBuf) {
-    info!("Connecting to database at {sqlite_file_path:?}");
-    let sqlite_file_path = sqlite_file_path.to_str().unwrap().to_string();
-    let opts = SqliteConnectOptions::from_str(&sqlite_file_path).unwrap().create_if_missing(true);
-    let pool = SqlitePool::connect_with(opts).await.expect("Failed to connect to database");
     let p = app_handle
         .path()
         .resolve("migrations", BaseDirectory::Resource)
         .expect("failed to resolve resource");
-
     info!("Running database migrations from: {}", p.to_string_lossy());
-    let mut m = Migrator::new(p).await.expect("Failed to load migrations");
+    let m = Migrator::new(p).await.expect("Failed to load database migrations");
+    migrate_db(sqlite_file_path, m).await.expect("Failed to migrate database");
+}
+
+async fn migrate_db(sqlite_file_path: &Path, mut m: Migrator) -> Result<()> {
+    info!("Connecting to database at {sqlite_file_path:?}");
+    let sqlite_file_path = sqlite_file_path.to_str().unwrap().to_string();
+    let opts = SqliteConnectOptions::from_str(&sqlite_file_path)?.create_if_missing(true);
+    let pool = SqlitePool::connect_with(opts).await?;
+
     m.set_ignore_missing(true); // So we can roll back versions and not crash
-    m.run(&pool).await.expect("Failed to run migrations");
+    m.run(&pool).await?;
 
     info!("Database migrations complete");
+    Ok(())
 }
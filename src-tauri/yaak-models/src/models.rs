@@ -1,7 +1,9 @@
 use crate::error::Result;
 use crate::models::HttpRequestIden::{
-    Authentication, AuthenticationType, Body, BodyType, CreatedAt, Description, FolderId, Headers,
-    Method, Name, SortPriority, UpdatedAt, Url, UrlParameters, WorkspaceId,
+    Assertions, Authentication, AuthenticationType, Body, BodyType, CreatedAt, Description,
+    FolderId, Headers, Method, Name, Retry, SettingFollowRedirects, SettingMaxResponseSize,
+    SettingRequestTimeout, SettingValidateCertificates, SortPriority, UpdatedAt, Url,
+    UrlParameters, WorkspaceId,
 };
 use crate::util::{UpdateSource, generate_prefixed_id};
 use chrono::{NaiveDateTime, Utc};
@@ -26,7 +28,7 @@ macro_rules! impl_model {
     };
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, TS)]
 #[serde(rename_all = "camelCase", tag = "type")]
 #[ts(export, export_to = "gen_models.ts")]
 pub enum ProxySetting {
@@ -37,11 +39,35 @@ pub enum ProxySetting {
         http: String,
         https: String,
         auth: Option<ProxySettingAuth>,
+        /// Hosts to connect to directly, in the same comma-separated format as NO_PROXY
+        #[serde(default)]
+        bypass: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    Socks {
+        #[serde(default)]
+        disabled: bool,
+        /// Address of the SOCKS5 proxy, like localhost:1080
+        host: String,
+        /// Let the proxy resolve hostnames, instead of resolving them locally
+        #[serde(default)]
+        remote_dns: bool,
+        auth: Option<ProxySettingAuth>,
+        #[serde(default)]
+        bypass: String,
+    },
+    Pac {
+        #[serde(default)]
+        disabled: bool,
+        /// URL or file path of the proxy auto-config script
+        url: String,
+        #[serde(default)]
+        bypass: String,
     },
     Disabled,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, TS)]
 #[serde(rename_all = "camelCase")]
 #[ts(export, export_to = "gen_models.ts")]
 pub struct ProxySettingAuth {
@@ -219,12 +245,34 @@ pub struct Workspace {
     pub description: String,
     pub encryption_key_challenge: Option<String>,
 
+    // Inherited by requests in the workspace
+    #[ts(type = "Record<string, any>")]
+    pub authentication: BTreeMap<String, Value>,
+    pub authentication_type: Option<String>,
+    pub headers: Vec<HttpRequestHeader>,
+    pub variables: Vec<EnvironmentVariable>,
+
     // Settings
     #[serde(default = "default_true")]
     pub setting_validate_certificates: bool,
     #[serde(default = "default_true")]
     pub setting_follow_redirects: bool,
+    #[serde(default = "default_max_redirects")]
+    pub setting_max_redirects: i32,
+    /// Switch to GET (dropping the body) when following a 301, 302 or 303, like browsers do
+    #[serde(default = "default_true")]
+    pub setting_redirect_rewrite_method: bool,
     pub setting_request_timeout: i32,
+    /// Largest response body to store, in bytes. Longer bodies are truncated and their download
+    /// stopped. 0 is unlimited.
+    #[ts(type = "number")]
+    pub setting_max_response_size: i64,
+    /// Certificates to present to servers that ask for one (mutual TLS), matched by host
+    pub setting_client_certificates: Vec<ClientCertificate>,
+    /// Paths to PEM bundles of extra CAs to trust, on top of the system roots
+    pub setting_ca_certificates: Vec<String>,
+    /// Addresses to connect to instead of the ones DNS returns, by host
+    pub setting_dns_overrides: Vec<DnsOverride>,
 }
 
 impl UpsertModelInfo for Workspace {
@@ -259,9 +307,22 @@ impl UpsertModelInfo for Workspace {
             (Name, self.name.trim().into()),
             (Description, self.description.into()),
             (EncryptionKeyChallenge, self.encryption_key_challenge.into()),
+            (Authentication, serde_json::to_string(&self.authentication)?.into()),
+            (AuthenticationType, self.authentication_type.into()),
+            (Headers, serde_json::to_string(&self.headers)?.into()),
+            (Variables, serde_json::to_string(&self.variables)?.into()),
             (SettingFollowRedirects, self.setting_follow_redirects.into()),
+            (SettingMaxRedirects, self.setting_max_redirects.into()),
+            (SettingMaxResponseSize, self.setting_max_response_size.into()),
+            (SettingRedirectRewriteMethod, self.setting_redirect_rewrite_method.into()),
             (SettingRequestTimeout, self.setting_request_timeout.into()),
             (SettingValidateCertificates, self.setting_validate_certificates.into()),
+            (
+                SettingClientCertificates,
+                serde_json::to_string(&self.setting_client_certificates)?.into(),
+            ),
+            (SettingCaCertificates, serde_json::to_string(&self.setting_ca_certificates)?.into()),
+            (SettingDnsOverrides, serde_json::to_string(&self.setting_dns_overrides)?.into()),
         ])
     }
 
@@ -271,10 +332,20 @@ impl UpsertModelInfo for Workspace {
             WorkspaceIden::Name,
             WorkspaceIden::Description,
             WorkspaceIden::EncryptionKeyChallenge,
+            WorkspaceIden::Authentication,
+            WorkspaceIden::AuthenticationType,
+            WorkspaceIden::Headers,
+            WorkspaceIden::Variables,
             WorkspaceIden::SettingRequestTimeout,
             WorkspaceIden::SettingFollowRedirects,
+            WorkspaceIden::SettingMaxRedirects,
+            WorkspaceIden::SettingMaxResponseSize,
+            WorkspaceIden::SettingRedirectRewriteMethod,
             WorkspaceIden::SettingRequestTimeout,
             WorkspaceIden::SettingValidateCertificates,
+            WorkspaceIden::SettingClientCertificates,
+            WorkspaceIden::SettingCaCertificates,
+            WorkspaceIden::SettingDnsOverrides,
         ]
     }
 
@@ -282,6 +353,12 @@ impl UpsertModelInfo for Workspace {
     where
         Self: Sized,
     {
+        let authentication: String = row.get("authentication")?;
+        let headers: String = row.get("headers")?;
+        let variables: String = row.get("variables")?;
+        let client_certificates: String = row.get("setting_client_certificates")?;
+        let ca_certificates: String = row.get("setting_ca_certificates")?;
+        let dns_overrides: String = row.get("setting_dns_overrides")?;
         Ok(Self {
             id: row.get("id")?,
             model: row.get("model")?,
@@ -290,13 +367,57 @@ impl UpsertModelInfo for Workspace {
             name: row.get("name")?,
             description: row.get("description")?,
             encryption_key_challenge: row.get("encryption_key_challenge")?,
+            authentication: serde_json::from_str(&authentication).unwrap_or_default(),
+            authentication_type: row.get("authentication_type")?,
+            headers: serde_json::from_str(&headers).unwrap_or_default(),
+            variables: serde_json::from_str(&variables).unwrap_or_default(),
             setting_follow_redirects: row.get("setting_follow_redirects")?,
+            setting_max_redirects: row.get("setting_max_redirects")?,
+            setting_max_response_size: row.get("setting_max_response_size")?,
+            setting_redirect_rewrite_method: row.get("setting_redirect_rewrite_method")?,
             setting_request_timeout: row.get("setting_request_timeout")?,
             setting_validate_certificates: row.get("setting_validate_certificates")?,
+            setting_client_certificates: serde_json::from_str(&client_certificates)
+                .unwrap_or_default(),
+            setting_ca_certificates: serde_json::from_str(&ca_certificates).unwrap_or_default(),
+            setting_dns_overrides: serde_json::from_str(&dns_overrides).unwrap_or_default(),
         })
     }
 }
 
+/// A client certificate, either as PEM certificate and key files or as a single PKCS#12 file
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Default, TS)]
+#[serde(default, rename_all = "camelCase")]
+#[ts(export, export_to = "gen_models.ts")]
+pub struct ClientCertificate {
+    #[serde(default = "default_true")]
+    #[ts(optional, as = "Option<bool>")]
+    pub enabled: bool,
+    /// Host to use the certificate for, like `api.example.com`, `*.example.com` or `localhost:8443`
+    pub host: String,
+    pub crt_file: Option<String>,
+    pub key_file: Option<String>,
+    pub pfx_file: Option<String>,
+    pub passphrase: Option<String>,
+}
+
+/// An address to connect to for a host, instead of the one from DNS, like curl's `--resolve`.
+/// Both fields may contain templates.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Default, TS)]
+#[serde(default, rename_all = "camelCase")]
+#[ts(export, export_to = "gen_models.ts")]
+pub struct DnsOverride {
+    #[serde(default = "default_true")]
+    #[ts(optional, as = "Option<bool>")]
+    pub enabled: bool,
+    /// Hostname to override, like `api.example.com`
+    pub host: String,
+    /// IPv4 or IPv6 address to connect to instead
+    pub address: String,
+    #[ts(optional, as = "Option<String>")]
+    pub id: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default, TS)]
 #[serde(default, rename_all = "camelCase")]
 #[ts(export, export_to = "gen_models.ts")]
@@ -590,9 +711,21 @@ pub struct Folder {
     pub workspace_id: String,
     pub folder_id: Option<String>,
 
-    pub name: String,
+    #[ts(type = "Record<string, any>")]
+    pub authentication: BTreeMap<String, Value>,
+    pub authentication_type: Option<String>,
     pub description: String,
+    pub headers: Vec<HttpRequestHeader>,
+    pub name: String,
     pub sort_priority: f32,
+    pub variables: Vec<EnvironmentVariable>,
+
+    // Settings, overriding the parent folder or workspace when set
+    pub setting_follow_redirects: Option<bool>,
+    #[ts(type = "number | null")]
+    pub setting_max_response_size: Option<i64>,
+    pub setting_request_timeout: Option<i32>,
+    pub setting_validate_certificates: Option<bool>,
 }
 
 impl UpsertModelInfo for Folder {
@@ -629,6 +762,14 @@ impl UpsertModelInfo for Folder {
             (Name, self.name.trim().into()),
             (Description, self.description.into()),
             (SortPriority, self.sort_priority.into()),
+            (Authentication, serde_json::to_string(&self.authentication)?.into()),
+            (AuthenticationType, self.authentication_type.into()),
+            (Headers, serde_json::to_string(&self.headers)?.into()),
+            (Variables, serde_json::to_string(&self.variables)?.into()),
+            (SettingFollowRedirects, self.setting_follow_redirects.into()),
+            (SettingMaxResponseSize, self.setting_max_response_size.into()),
+            (SettingRequestTimeout, self.setting_request_timeout.into()),
+            (SettingValidateCertificates, self.setting_validate_certificates.into()),
         ])
     }
 
@@ -639,6 +780,14 @@ impl UpsertModelInfo for Folder {
             FolderIden::Description,
             FolderIden::FolderId,
             FolderIden::SortPriority,
+            FolderIden::Authentication,
+            FolderIden::AuthenticationType,
+            FolderIden::Headers,
+            FolderIden::Variables,
+            FolderIden::SettingFollowRedirects,
+            FolderIden::SettingMaxResponseSize,
+            FolderIden::SettingRequestTimeout,
+            FolderIden::SettingValidateCertificates,
         ]
     }
 
@@ -646,6 +795,9 @@ impl UpsertModelInfo for Folder {
     where
         Self: Sized,
     {
+        let authentication: String = row.get("authentication")?;
+        let headers: String = row.get("headers")?;
+        let variables: String = row.get("variables")?;
         Ok(Self {
             id: row.get("id")?,
             model: row.get("model")?,
@@ -656,6 +808,14 @@ impl UpsertModelInfo for Folder {
             folder_id: row.get("folder_id")?,
             name: row.get("name")?,
             description: row.get("description")?,
+            authentication: serde_json::from_str(&authentication).unwrap_or_default(),
+            authentication_type: row.get("authentication_type")?,
+            headers: serde_json::from_str(&headers).unwrap_or_default(),
+            variables: serde_json::from_str(&variables).unwrap_or_default(),
+            setting_follow_redirects: row.get("setting_follow_redirects")?,
+            setting_max_response_size: row.get("setting_max_response_size")?,
+            setting_request_timeout: row.get("setting_request_timeout")?,
+            setting_validate_certificates: row.get("setting_validate_certificates")?,
         })
     }
 }
@@ -686,6 +846,130 @@ pub struct HttpUrlParameter {
     pub id: Option<String>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "gen_models.ts")]
+pub enum HttpAssertionSource {
+    Status,
+    Header,
+    JsonPath,
+    Elapsed,
+}
+
+impl Default for HttpAssertionSource {
+    fn default() -> Self {
+        Self::Status
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "gen_models.ts")]
+pub enum HttpAssertionOperator {
+    Equals,
+    NotEquals,
+    Contains,
+    Matches,
+    LessThan,
+    GreaterThan,
+    Exists,
+}
+
+impl Default for HttpAssertionOperator {
+    fn default() -> Self {
+        Self::Equals
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default, TS)]
+#[serde(default, rename_all = "camelCase")]
+#[ts(export, export_to = "gen_models.ts")]
+pub struct HttpRequestAssertion {
+    #[serde(default = "default_true")]
+    #[ts(optional, as = "Option<bool>")]
+    pub enabled: bool,
+    pub source: HttpAssertionSource,
+    pub property: String,
+    pub operator: HttpAssertionOperator,
+    pub value: String,
+    #[ts(optional, as = "Option<String>")]
+    pub id: Option<String>,
+}
+
+/// Transport failures that a request can be retried after
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "gen_models.ts")]
+pub enum HttpRetryError {
+    /// The connection couldn't be established
+    Connect,
+    /// The request timed out
+    Timeout,
+    /// Any other failure, like the connection being reset
+    Other,
+}
+
+/// When and how often to re-send a request that failed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(default, rename_all = "camelCase")]
+#[ts(export, export_to = "gen_models.ts")]
+pub struct HttpRequestRetry {
+    pub enabled: bool,
+    /// Total number of attempts, including the first one
+    pub max_attempts: i32,
+    pub status_codes: Vec<i32>,
+    pub errors: Vec<HttpRetryError>,
+    /// Delay before the first retry, in milliseconds. It doubles for each retry after that.
+    pub initial_delay: i32,
+    /// Upper bound for the delay between attempts, in milliseconds
+    pub max_delay: i32,
+    /// Wait as long as the server's Retry-After header asks, instead of backing off
+    pub respect_retry_after: bool,
+}
+
+impl Default for HttpRequestRetry {
+    fn default() -> Self {
+        HttpRequestRetry {
+            enabled: false,
+            max_attempts: 3,
+            status_codes: vec![408, 429, 500, 502, 503, 504],
+            errors: vec![HttpRetryError::Connect, HttpRetryError::Timeout],
+            initial_delay: 500,
+            max_delay: 30_000,
+            respect_retry_after: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default, TS)]
+#[serde(default, rename_all = "camelCase")]
+#[ts(export, export_to = "gen_models.ts")]
+pub struct HttpAssertionResult {
+    pub source: HttpAssertionSource,
+    pub property: String,
+    pub operator: HttpAssertionOperator,
+    pub expected: String,
+    pub actual: Option<String>,
+    pub passed: bool,
+    pub error: Option<String>,
+}
+
+/// HTTP version to send a request with
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "gen_models.ts")]
+pub enum HttpVersion {
+    /// Let the server pick during the TLS handshake, falling back to HTTP/1.1
+    #[default]
+    Auto,
+    /// Only use HTTP/1.1, even when the server supports HTTP/2
+    Http1,
+    /// Start talking HTTP/2 right away, which also works without TLS (h2c)
+    Http2,
+    /// Use HTTP/3 over QUIC, which only works with TLS
+    Http3,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default, TS)]
 #[serde(default, rename_all = "camelCase")]
 #[ts(export, export_to = "gen_models.ts")]
@@ -699,6 +983,7 @@ pub struct HttpRequest {
     pub workspace_id: String,
     pub folder_id: Option<String>,
 
+    pub assertions: Vec<HttpRequestAssertion>,
     #[ts(type = "Record<string, any>")]
     pub authentication: BTreeMap<String, Value>,
     pub authentication_type: Option<String>,
@@ -707,12 +992,21 @@ pub struct HttpRequest {
     pub body_type: Option<String>,
     pub description: String,
     pub headers: Vec<HttpRequestHeader>,
+    pub http_version: HttpVersion,
     #[serde(default = "default_http_method")]
     pub method: String,
     pub name: String,
+    pub retry: HttpRequestRetry,
     pub sort_priority: f64,
     pub url: String,
     pub url_parameters: Vec<HttpUrlParameter>,
+
+    // Settings, overriding the parent folder or workspace when set
+    pub setting_follow_redirects: Option<bool>,
+    #[ts(type = "number | null")]
+    pub setting_max_response_size: Option<i64>,
+    pub setting_request_timeout: Option<i32>,
+    pub setting_validate_certificates: Option<bool>,
 }
 
 impl UpsertModelInfo for HttpRequest {
@@ -749,13 +1043,23 @@ impl UpsertModelInfo for HttpRequest {
             (Description, self.description.into()),
             (Url, self.url.into()),
             (UrlParameters, serde_json::to_string(&self.url_parameters)?.into()),
+            (Assertions, serde_json::to_string(&self.assertions)?.into()),
             (Method, self.method.into()),
             (Body, serde_json::to_string(&self.body)?.into()),
             (BodyType, self.body_type.into()),
             (Authentication, serde_json::to_string(&self.authentication)?.into()),
             (AuthenticationType, self.authentication_type.into()),
             (Headers, serde_json::to_string(&self.headers)?.into()),
+            (
+                HttpRequestIden::HttpVersion,
+                serde_json::to_value(&self.http_version)?.as_str().into(),
+            ),
             (SortPriority, self.sort_priority.into()),
+            (Retry, serde_json::to_string(&self.retry)?.into()),
+            (SettingFollowRedirects, self.setting_follow_redirects.into()),
+            (SettingMaxResponseSize, self.setting_max_response_size.into()),
+            (SettingRequestTimeout, self.setting_request_timeout.into()),
+            (SettingValidateCertificates, self.setting_validate_certificates.into()),
         ])
     }
 
@@ -768,6 +1072,7 @@ impl UpsertModelInfo for HttpRequest {
             FolderId,
             Method,
             Headers,
+            HttpRequestIden::HttpVersion,
             Body,
             BodyType,
             Authentication,
@@ -775,20 +1080,30 @@ impl UpsertModelInfo for HttpRequest {
             Url,
             UrlParameters,
             SortPriority,
+            Assertions,
+            Retry,
+            SettingFollowRedirects,
+            SettingMaxResponseSize,
+            SettingRequestTimeout,
+            SettingValidateCertificates,
         ]
     }
 
     fn from_row(r: &Row) -> rusqlite::Result<Self> {
         let url_parameters: String = r.get("url_parameters")?;
+        let assertions: String = r.get("assertions")?;
         let body: String = r.get("body")?;
         let authentication: String = r.get("authentication")?;
         let headers: String = r.get("headers")?;
+        let retry: String = r.get("retry")?;
+        let http_version: String = r.get("http_version")?;
         Ok(Self {
             id: r.get("id")?,
             model: r.get("model")?,
             workspace_id: r.get("workspace_id")?,
             created_at: r.get("created_at")?,
             updated_at: r.get("updated_at")?,
+            assertions: serde_json::from_str(assertions.as_str()).unwrap_or_default(),
             authentication: serde_json::from_str(authentication.as_str()).unwrap_or_default(),
             authentication_type: r.get("authentication_type")?,
             body: serde_json::from_str(body.as_str()).unwrap_or_default(),
@@ -796,11 +1111,18 @@ impl UpsertModelInfo for HttpRequest {
             description: r.get("description")?,
             folder_id: r.get("folder_id")?,
             headers: serde_json::from_str(headers.as_str()).unwrap_or_default(),
+            http_version: serde_json::from_str(format!(r#""{http_version}""#).as_str())
+                .unwrap_or_default(),
             method: r.get("method")?,
             name: r.get("name")?,
+            retry: serde_json::from_str(retry.as_str()).unwrap_or_default(),
             sort_priority: r.get("sort_priority")?,
             url: r.get("url")?,
             url_parameters: serde_json::from_str(url_parameters.as_str()).unwrap_or_default(),
+            setting_follow_redirects: r.get("setting_follow_redirects")?,
+            setting_max_response_size: r.get("setting_max_response_size")?,
+            setting_request_timeout: r.get("setting_request_timeout")?,
+            setting_validate_certificates: r.get("setting_validate_certificates")?,
         })
     }
 }
@@ -954,6 +1276,10 @@ pub struct WebsocketRequest {
     pub sort_priority: f32,
     pub url: String,
     pub url_parameters: Vec<HttpUrlParameter>,
+
+    // Settings, overriding the parent folder or workspace when set
+    pub setting_request_timeout: Option<i32>,
+    pub setting_validate_certificates: Option<bool>,
 }
 
 impl UpsertModelInfo for WebsocketRequest {
@@ -996,6 +1322,8 @@ impl UpsertModelInfo for WebsocketRequest {
             (SortPriority, self.sort_priority.into()),
             (Url, self.url.into()),
             (UrlParameters, serde_json::to_string(&self.url_parameters)?.into()),
+            (SettingRequestTimeout, self.setting_request_timeout.into()),
+            (SettingValidateCertificates, self.setting_validate_certificates.into()),
         ])
     }
 
@@ -1013,6 +1341,8 @@ impl UpsertModelInfo for WebsocketRequest {
             WebsocketRequestIden::SortPriority,
             WebsocketRequestIden::Url,
             WebsocketRequestIden::UrlParameters,
+            WebsocketRequestIden::SettingRequestTimeout,
+            WebsocketRequestIden::SettingValidateCertificates,
         ]
     }
 
@@ -1039,6 +1369,8 @@ impl UpsertModelInfo for WebsocketRequest {
             headers: serde_json::from_str(headers.as_str()).unwrap_or_default(),
             folder_id: row.get("folder_id")?,
             name: row.get("name")?,
+            setting_request_timeout: row.get("setting_request_timeout")?,
+            setting_validate_certificates: row.get("setting_validate_certificates")?,
         })
     }
 }
@@ -1148,24 +1480,137 @@ impl UpsertModelInfo for WebsocketEvent {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default, TS)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default, TS)]
 #[serde(default, rename_all = "camelCase")]
 #[ts(export, export_to = "gen_models.ts")]
-pub struct HttpResponseHeader {
+#[enum_def(table_name = "sse_requests")]
+pub struct SseRequest {
+    #[ts(type = "\"sse_request\"")]
+    pub model: String,
+    pub id: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub workspace_id: String,
+    pub folder_id: Option<String>,
+
+    #[ts(type = "Record<string, any>")]
+    pub authentication: BTreeMap<String, Value>,
+    pub authentication_type: Option<String>,
+    pub description: String,
+    pub headers: Vec<HttpRequestHeader>,
     pub name: String,
-    pub value: String,
+    pub sort_priority: f32,
+    pub url: String,
+    pub url_parameters: Vec<HttpUrlParameter>,
+
+    // Settings, overriding the parent folder or workspace when set
+    pub setting_request_timeout: Option<i32>,
+    pub setting_validate_certificates: Option<bool>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+impl UpsertModelInfo for SseRequest {
+    fn table_name() -> impl IntoTableRef {
+        SseRequestIden::Table
+    }
+
+    fn id_column() -> impl IntoIden + Eq + Clone {
+        SseRequestIden::Id
+    }
+
+    fn generate_id() -> String {
+        generate_prefixed_id("sr")
+    }
+
+    fn order_by() -> (impl IntoColumnRef, Order) {
+        (SseRequestIden::CreatedAt, Desc)
+    }
+
+    fn get_id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn insert_values(
+        self,
+        source: &UpdateSource,
+    ) -> Result<Vec<(impl IntoIden + Eq, impl Into<SimpleExpr>)>> {
+        use SseRequestIden::*;
+        Ok(vec![
+            (CreatedAt, upsert_date(source, self.created_at)),
+            (UpdatedAt, upsert_date(source, self.updated_at)),
+            (WorkspaceId, self.workspace_id.into()),
+            (FolderId, self.folder_id.into()),
+            (Authentication, serde_json::to_string(&self.authentication)?.into()),
+            (AuthenticationType, self.authentication_type.into()),
+            (Description, self.description.into()),
+            (Headers, serde_json::to_string(&self.headers)?.into()),
+            (Name, self.name.trim().into()),
+            (SortPriority, self.sort_priority.into()),
+            (Url, self.url.into()),
+            (UrlParameters, serde_json::to_string(&self.url_parameters)?.into()),
+            (SettingRequestTimeout, self.setting_request_timeout.into()),
+            (SettingValidateCertificates, self.setting_validate_certificates.into()),
+        ])
+    }
+
+    fn update_columns() -> Vec<impl IntoIden> {
+        vec![
+            SseRequestIden::UpdatedAt,
+            SseRequestIden::WorkspaceId,
+            SseRequestIden::FolderId,
+            SseRequestIden::Authentication,
+            SseRequestIden::AuthenticationType,
+            SseRequestIden::Description,
+            SseRequestIden::Headers,
+            SseRequestIden::Name,
+            SseRequestIden::SortPriority,
+            SseRequestIden::Url,
+            SseRequestIden::UrlParameters,
+            SseRequestIden::SettingRequestTimeout,
+            SseRequestIden::SettingValidateCertificates,
+        ]
+    }
+
+    fn from_row(row: &Row) -> rusqlite::Result<Self>
+    where
+        Self: Sized,
+    {
+        let url_parameters: String = row.get("url_parameters")?;
+        let authentication: String = row.get("authentication")?;
+        let headers: String = row.get("headers")?;
+        Ok(Self {
+            id: row.get("id")?,
+            model: row.get("model")?,
+            sort_priority: row.get("sort_priority")?,
+            workspace_id: row.get("workspace_id")?,
+            created_at: row.get("created_at")?,
+            updated_at: row.get("updated_at")?,
+            url: row.get("url")?,
+            url_parameters: serde_json::from_str(url_parameters.as_str()).unwrap_or_default(),
+            description: row.get("description")?,
+            authentication: serde_json::from_str(authentication.as_str()).unwrap_or_default(),
+            authentication_type: row.get("authentication_type")?,
+            headers: serde_json::from_str(headers.as_str()).unwrap_or_default(),
+            folder_id: row.get("folder_id")?,
+            name: row.get("name")?,
+            setting_request_timeout: row.get("setting_request_timeout")?,
+            setting_validate_certificates: row.get("setting_validate_certificates")?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
 #[serde(rename_all = "snake_case")]
 #[ts(export, export_to = "gen_models.ts")]
-pub enum HttpResponseState {
+pub enum SseConnectionState {
     Initialized,
     Connected,
+    /// The stream was interrupted and the connection waits to be re-established
+    Reconnecting,
+    Closing,
     Closed,
 }
 
-impl Default for HttpResponseState {
+impl Default for SseConnectionState {
     fn default() -> Self {
         Self::Initialized
     }
@@ -1174,9 +1619,9 @@ impl Default for HttpResponseState {
 #[derive(Debug, Clone, Serialize, Deserialize, Default, TS)]
 #[serde(default, rename_all = "camelCase")]
 #[ts(export, export_to = "gen_models.ts")]
-#[enum_def(table_name = "http_responses")]
-pub struct HttpResponse {
-    #[ts(type = "\"http_response\"")]
+#[enum_def(table_name = "sse_connections")]
+pub struct SseConnection {
+    #[ts(type = "\"sse_connection\"")]
     pub model: String,
     pub id: String,
     pub created_at: NaiveDateTime,
@@ -1184,35 +1629,32 @@ pub struct HttpResponse {
     pub workspace_id: String,
     pub request_id: String,
 
-    pub body_path: Option<String>,
-    pub content_length: Option<i32>,
     pub elapsed: i32,
-    pub elapsed_headers: i32,
     pub error: Option<String>,
     pub headers: Vec<HttpResponseHeader>,
-    pub remote_addr: Option<String>,
+    /// ID of the last received event, sent as `Last-Event-ID` when reconnecting
+    pub last_event_id: Option<String>,
+    pub reconnects: i32,
+    pub state: SseConnectionState,
     pub status: i32,
-    pub status_reason: Option<String>,
-    pub state: HttpResponseState,
     pub url: String,
-    pub version: Option<String>,
 }
 
-impl UpsertModelInfo for HttpResponse {
+impl UpsertModelInfo for SseConnection {
     fn table_name() -> impl IntoTableRef {
-        HttpResponseIden::Table
+        SseConnectionIden::Table
     }
 
     fn id_column() -> impl IntoIden + Eq + Clone {
-        HttpResponseIden::Id
+        SseConnectionIden::Id
     }
 
     fn generate_id() -> String {
-        generate_prefixed_id("rs")
+        generate_prefixed_id("sc")
     }
 
     fn order_by() -> (impl IntoColumnRef, Order) {
-        (HttpResponseIden::CreatedAt, Desc)
+        (SseConnectionIden::CreatedAt, Desc)
     }
 
     fn get_id(&self) -> String {
@@ -1223,40 +1665,358 @@ impl UpsertModelInfo for HttpResponse {
         self,
         source: &UpdateSource,
     ) -> Result<Vec<(impl IntoIden + Eq, impl Into<SimpleExpr>)>> {
-        use HttpResponseIden::*;
+        use SseConnectionIden::*;
         Ok(vec![
             (CreatedAt, upsert_date(source, self.created_at)),
             (UpdatedAt, upsert_date(source, self.updated_at)),
-            (RequestId, self.request_id.into()),
             (WorkspaceId, self.workspace_id.into()),
-            (BodyPath, self.body_path.into()),
-            (ContentLength, self.content_length.into()),
+            (RequestId, self.request_id.into()),
             (Elapsed, self.elapsed.into()),
-            (ElapsedHeaders, self.elapsed_headers.into()),
             (Error, self.error.into()),
             (Headers, serde_json::to_string(&self.headers)?.into()),
-            (RemoteAddr, self.remote_addr.into()),
-            (State, serde_json::to_value(self.state)?.as_str().into()),
+            (LastEventId, self.last_event_id.into()),
+            (Reconnects, self.reconnects.into()),
+            (State, serde_json::to_value(&self.state)?.as_str().into()),
             (Status, self.status.into()),
-            (StatusReason, self.status_reason.into()),
             (Url, self.url.into()),
-            (Version, self.version.into()),
         ])
     }
 
     fn update_columns() -> Vec<impl IntoIden> {
         vec![
-            HttpResponseIden::UpdatedAt,
-            HttpResponseIden::BodyPath,
-            HttpResponseIden::ContentLength,
-            HttpResponseIden::Elapsed,
-            HttpResponseIden::ElapsedHeaders,
-            HttpResponseIden::Error,
-            HttpResponseIden::Headers,
-            HttpResponseIden::RemoteAddr,
-            HttpResponseIden::State,
-            HttpResponseIden::Status,
-            HttpResponseIden::StatusReason,
+            SseConnectionIden::UpdatedAt,
+            SseConnectionIden::Elapsed,
+            SseConnectionIden::Error,
+            SseConnectionIden::Headers,
+            SseConnectionIden::LastEventId,
+            SseConnectionIden::Reconnects,
+            SseConnectionIden::State,
+            SseConnectionIden::Status,
+            SseConnectionIden::Url,
+        ]
+    }
+
+    fn from_row(row: &Row) -> rusqlite::Result<Self>
+    where
+        Self: Sized,
+    {
+        let headers: String = row.get("headers")?;
+        let state: String = row.get("state")?;
+        Ok(Self {
+            id: row.get("id")?,
+            model: row.get("model")?,
+            workspace_id: row.get("workspace_id")?,
+            request_id: row.get("request_id")?,
+            created_at: row.get("created_at")?,
+            updated_at: row.get("updated_at")?,
+            url: row.get("url")?,
+            headers: serde_json::from_str(headers.as_str()).unwrap_or_default(),
+            elapsed: row.get("elapsed")?,
+            error: row.get("error")?,
+            last_event_id: row.get("last_event_id")?,
+            reconnects: row.get("reconnects")?,
+            state: serde_json::from_str(format!(r#""{state}""#).as_str()).unwrap(),
+            status: row.get("status")?,
+        })
+    }
+}
+
+/// An event received on an SSE connection
+#[derive(Debug, Clone, Serialize, Deserialize, Default, TS)]
+#[serde(default, rename_all = "camelCase")]
+#[ts(export, export_to = "gen_models.ts")]
+#[enum_def(table_name = "sse_events")]
+pub struct SseEvent {
+    #[ts(type = "\"sse_event\"")]
+    pub model: String,
+    pub id: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub workspace_id: String,
+    pub request_id: String,
+    pub connection_id: String,
+
+    pub data: String,
+    /// The `id` field of the event, not to be confused with the model ID
+    pub event_id: Option<String>,
+    pub event_type: String,
+    #[ts(type = "number | null")]
+    pub retry: Option<i64>,
+}
+
+impl UpsertModelInfo for SseEvent {
+    fn table_name() -> impl IntoTableRef {
+        SseEventIden::Table
+    }
+
+    fn id_column() -> impl IntoIden + Eq + Clone {
+        SseEventIden::Id
+    }
+
+    fn generate_id() -> String {
+        generate_prefixed_id("se")
+    }
+
+    fn order_by() -> (impl IntoColumnRef, Order) {
+        (SseEventIden::CreatedAt, Desc)
+    }
+
+    fn get_id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn insert_values(
+        self,
+        source: &UpdateSource,
+    ) -> Result<Vec<(impl IntoIden + Eq, impl Into<SimpleExpr>)>> {
+        use SseEventIden::*;
+        Ok(vec![
+            (CreatedAt, upsert_date(source, self.created_at)),
+            (UpdatedAt, upsert_date(source, self.updated_at)),
+            (WorkspaceId, self.workspace_id.into()),
+            (RequestId, self.request_id.into()),
+            (ConnectionId, self.connection_id.into()),
+            (Data, self.data.into()),
+            (EventId, self.event_id.into()),
+            (EventType, self.event_type.into()),
+            (Retry, self.retry.into()),
+        ])
+    }
+
+    fn update_columns() -> Vec<impl IntoIden> {
+        vec![
+            SseEventIden::UpdatedAt,
+            SseEventIden::Data,
+            SseEventIden::EventId,
+            SseEventIden::EventType,
+            SseEventIden::Retry,
+        ]
+    }
+
+    fn from_row(row: &Row) -> rusqlite::Result<Self>
+    where
+        Self: Sized,
+    {
+        Ok(Self {
+            id: row.get("id")?,
+            model: row.get("model")?,
+            workspace_id: row.get("workspace_id")?,
+            request_id: row.get("request_id")?,
+            connection_id: row.get("connection_id")?,
+            created_at: row.get("created_at")?,
+            updated_at: row.get("updated_at")?,
+            data: row.get("data")?,
+            event_id: row.get("event_id")?,
+            event_type: row.get("event_type")?,
+            retry: row.get("retry")?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, TS)]
+#[serde(default, rename_all = "camelCase")]
+#[ts(export, export_to = "gen_models.ts")]
+pub struct HttpResponseHeader {
+    pub name: String,
+    pub value: String,
+}
+
+/// The request as it went on the wire, after cookies and authentication were applied. Headers
+/// that the HTTP client adds itself (eg. Host and Accept-Encoding) are not included.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, TS)]
+#[serde(default, rename_all = "camelCase")]
+#[ts(export, export_to = "gen_models.ts")]
+pub struct HttpSentRequest {
+    pub method: String,
+    pub url: String,
+    pub headers: Vec<HttpResponseHeader>,
+    /// Unknown for streamed bodies, like large file uploads
+    pub body_size: Option<i32>,
+    pub body_preview: Option<String>,
+}
+
+/// How long each phase of the request took, in milliseconds. The connection phases are empty when
+/// they didn't happen, like DNS for an IP address or all of them when a connection was reused.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, TS)]
+#[serde(default, rename_all = "camelCase")]
+#[ts(export, export_to = "gen_models.ts")]
+pub struct HttpResponseTimings {
+    pub dns: Option<i32>,
+    pub connect: Option<i32>,
+    pub tls: Option<i32>,
+    /// From the connection being ready until the response headers arrived
+    pub ttfb: i32,
+    pub download: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, TS)]
+#[serde(default, rename_all = "camelCase")]
+#[ts(export, export_to = "gen_models.ts")]
+pub struct HttpResponseTlsInfo {
+    pub version: Option<String>,
+    /// Name of the negotiated cipher suite, like `TLS13_AES_128_GCM_SHA256`
+    pub cipher: Option<String>,
+    /// PEM encoded certificates presented by the server, starting with the leaf
+    pub certificates: Vec<String>,
+}
+
+/// A redirect response that was followed on the way to the final response
+#[derive(Debug, Clone, Serialize, Deserialize, Default, TS)]
+#[serde(default, rename_all = "camelCase")]
+#[ts(export, export_to = "gen_models.ts")]
+pub struct HttpResponseRedirect {
+    pub method: String,
+    pub url: String,
+    pub status: i32,
+    pub headers: Vec<HttpResponseHeader>,
+}
+
+/// One attempt at sending a request that has retries enabled
+#[derive(Debug, Clone, Serialize, Deserialize, Default, TS)]
+#[serde(default, rename_all = "camelCase")]
+#[ts(export, export_to = "gen_models.ts")]
+pub struct HttpResponseAttempt {
+    /// Status of the response, or 0 when the attempt failed without one
+    pub status: i32,
+    pub error: Option<String>,
+    pub elapsed: i32,
+    /// How long was waited after this attempt before making the next one, in milliseconds
+    pub delay: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "gen_models.ts")]
+pub enum HttpResponseState {
+    Initialized,
+    Connected,
+    Closed,
+}
+
+impl Default for HttpResponseState {
+    fn default() -> Self {
+        Self::Initialized
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, TS)]
+#[serde(default, rename_all = "camelCase")]
+#[ts(export, export_to = "gen_models.ts")]
+#[enum_def(table_name = "http_responses")]
+pub struct HttpResponse {
+    #[ts(type = "\"http_response\"")]
+    pub model: String,
+    pub id: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub workspace_id: String,
+    pub request_id: String,
+
+    pub assertion_results: Vec<HttpAssertionResult>,
+    pub attempts: Vec<HttpResponseAttempt>,
+    pub body_path: Option<String>,
+    /// Bytes of the request body files that were uploaded so far
+    #[ts(type = "number")]
+    pub bytes_uploaded: i64,
+    pub content_length: Option<i32>,
+    pub elapsed: i32,
+    pub elapsed_headers: i32,
+    pub error: Option<String>,
+    pub headers: Vec<HttpResponseHeader>,
+    pub redirects: Vec<HttpResponseRedirect>,
+    pub remote_addr: Option<String>,
+    pub sent_request: Option<HttpSentRequest>,
+    pub status: i32,
+    pub status_reason: Option<String>,
+    pub state: HttpResponseState,
+    pub timings: HttpResponseTimings,
+    pub tls_info: Option<HttpResponseTlsInfo>,
+    /// Whether the body was cut off, because it was longer than the max response size
+    pub truncated: bool,
+    /// Total size of the request body files being uploaded, when the body is streamed from disk
+    #[ts(type = "number | null")]
+    pub upload_size: Option<i64>,
+    pub url: String,
+    pub version: Option<String>,
+}
+
+impl UpsertModelInfo for HttpResponse {
+    fn table_name() -> impl IntoTableRef {
+        HttpResponseIden::Table
+    }
+
+    fn id_column() -> impl IntoIden + Eq + Clone {
+        HttpResponseIden::Id
+    }
+
+    fn generate_id() -> String {
+        generate_prefixed_id("rs")
+    }
+
+    fn order_by() -> (impl IntoColumnRef, Order) {
+        (HttpResponseIden::CreatedAt, Desc)
+    }
+
+    fn get_id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn insert_values(
+        self,
+        source: &UpdateSource,
+    ) -> Result<Vec<(impl IntoIden + Eq, impl Into<SimpleExpr>)>> {
+        use HttpResponseIden::*;
+        Ok(vec![
+            (CreatedAt, upsert_date(source, self.created_at)),
+            (UpdatedAt, upsert_date(source, self.updated_at)),
+            (RequestId, self.request_id.into()),
+            (WorkspaceId, self.workspace_id.into()),
+            (AssertionResults, serde_json::to_string(&self.assertion_results)?.into()),
+            (Attempts, serde_json::to_string(&self.attempts)?.into()),
+            (BodyPath, self.body_path.into()),
+            (BytesUploaded, self.bytes_uploaded.into()),
+            (ContentLength, self.content_length.into()),
+            (Elapsed, self.elapsed.into()),
+            (ElapsedHeaders, self.elapsed_headers.into()),
+            (Error, self.error.into()),
+            (Headers, serde_json::to_string(&self.headers)?.into()),
+            (Redirects, serde_json::to_string(&self.redirects)?.into()),
+            (RemoteAddr, self.remote_addr.into()),
+            (SentRequest, serde_json::to_string(&self.sent_request)?.into()),
+            (State, serde_json::to_value(self.state)?.as_str().into()),
+            (Status, self.status.into()),
+            (StatusReason, self.status_reason.into()),
+            (Timings, serde_json::to_string(&self.timings)?.into()),
+            (TlsInfo, serde_json::to_string(&self.tls_info)?.into()),
+            (Truncated, self.truncated.into()),
+            (UploadSize, self.upload_size.into()),
+            (Url, self.url.into()),
+            (Version, self.version.into()),
+        ])
+    }
+
+    fn update_columns() -> Vec<impl IntoIden> {
+        vec![
+            HttpResponseIden::UpdatedAt,
+            HttpResponseIden::AssertionResults,
+            HttpResponseIden::Attempts,
+            HttpResponseIden::BodyPath,
+            HttpResponseIden::BytesUploaded,
+            HttpResponseIden::ContentLength,
+            HttpResponseIden::Elapsed,
+            HttpResponseIden::ElapsedHeaders,
+            HttpResponseIden::Error,
+            HttpResponseIden::Headers,
+            HttpResponseIden::Redirects,
+            HttpResponseIden::RemoteAddr,
+            HttpResponseIden::SentRequest,
+            HttpResponseIden::State,
+            HttpResponseIden::Status,
+            HttpResponseIden::StatusReason,
+            HttpResponseIden::Timings,
+            HttpResponseIden::TlsInfo,
+            HttpResponseIden::Truncated,
+            HttpResponseIden::UploadSize,
             HttpResponseIden::Url,
             HttpResponseIden::Version,
         ]
@@ -1266,7 +2026,13 @@ impl UpsertModelInfo for HttpResponse {
     where
         Self: Sized,
     {
+        let assertion_results: String = r.get("assertion_results")?;
+        let attempts: String = r.get("attempts")?;
         let headers: String = r.get("headers")?;
+        let redirects: String = r.get("redirects")?;
+        let sent_request: String = r.get("sent_request")?;
+        let timings: String = r.get("timings")?;
+        let tls_info: String = r.get("tls_info")?;
         let state: String = r.get("state")?;
         Ok(Self {
             id: r.get("id")?,
@@ -1278,6 +2044,9 @@ impl UpsertModelInfo for HttpResponse {
             error: r.get("error")?,
             url: r.get("url")?,
             content_length: r.get("content_length")?,
+            bytes_uploaded: r.get("bytes_uploaded")?,
+            truncated: r.get("truncated")?,
+            upload_size: r.get("upload_size")?,
             version: r.get("version")?,
             elapsed: r.get("elapsed")?,
             elapsed_headers: r.get("elapsed_headers")?,
@@ -1285,8 +2054,234 @@ impl UpsertModelInfo for HttpResponse {
             status: r.get("status")?,
             status_reason: r.get("status_reason")?,
             state: serde_json::from_str(format!(r#""{state}""#).as_str()).unwrap(),
+            assertion_results: serde_json::from_str(assertion_results.as_str()).unwrap_or_default(),
+            attempts: serde_json::from_str(attempts.as_str()).unwrap_or_default(),
             body_path: r.get("body_path")?,
             headers: serde_json::from_str(headers.as_str()).unwrap_or_default(),
+            redirects: serde_json::from_str(redirects.as_str()).unwrap_or_default(),
+            sent_request: serde_json::from_str(sent_request.as_str()).unwrap_or_default(),
+            timings: serde_json::from_str(timings.as_str()).unwrap_or_default(),
+            tls_info: serde_json::from_str(tls_info.as_str()).unwrap_or_default(),
+        })
+    }
+}
+
+/// A server-sent event of a `text/event-stream` response, stored as soon as it was received
+#[derive(Debug, Clone, Serialize, Deserialize, Default, TS)]
+#[serde(default, rename_all = "camelCase")]
+#[ts(export, export_to = "gen_models.ts")]
+#[enum_def(table_name = "http_response_events")]
+pub struct HttpResponseEvent {
+    #[ts(type = "\"http_response_event\"")]
+    pub model: String,
+    pub id: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub workspace_id: String,
+    pub request_id: String,
+    pub response_id: String,
+
+    pub data: String,
+    /// The `id` field of the event, not to be confused with the model ID
+    pub event_id: Option<String>,
+    pub event_type: String,
+    #[ts(type = "number | null")]
+    pub retry: Option<i64>,
+}
+
+impl UpsertModelInfo for HttpResponseEvent {
+    fn table_name() -> impl IntoTableRef {
+        HttpResponseEventIden::Table
+    }
+
+    fn id_column() -> impl IntoIden + Eq + Clone {
+        HttpResponseEventIden::Id
+    }
+
+    fn generate_id() -> String {
+        generate_prefixed_id("re")
+    }
+
+    fn order_by() -> (impl IntoColumnRef, Order) {
+        (HttpResponseEventIden::CreatedAt, Desc)
+    }
+
+    fn get_id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn insert_values(
+        self,
+        source: &UpdateSource,
+    ) -> Result<Vec<(impl IntoIden + Eq, impl Into<SimpleExpr>)>> {
+        use HttpResponseEventIden::*;
+        Ok(vec![
+            (CreatedAt, upsert_date(source, self.created_at)),
+            (UpdatedAt, upsert_date(source, self.updated_at)),
+            (WorkspaceId, self.workspace_id.into()),
+            (RequestId, self.request_id.into()),
+            (ResponseId, self.response_id.into()),
+            (Data, self.data.into()),
+            (EventId, self.event_id.into()),
+            (EventType, self.event_type.into()),
+            (Retry, self.retry.into()),
+        ])
+    }
+
+    fn update_columns() -> Vec<impl IntoIden> {
+        vec![
+            HttpResponseEventIden::UpdatedAt,
+            HttpResponseEventIden::Data,
+            HttpResponseEventIden::EventId,
+            HttpResponseEventIden::EventType,
+            HttpResponseEventIden::Retry,
+        ]
+    }
+
+    fn from_row(row: &Row) -> rusqlite::Result<Self>
+    where
+        Self: Sized,
+    {
+        Ok(Self {
+            id: row.get("id")?,
+            model: row.get("model")?,
+            workspace_id: row.get("workspace_id")?,
+            request_id: row.get("request_id")?,
+            response_id: row.get("response_id")?,
+            created_at: row.get("created_at")?,
+            updated_at: row.get("updated_at")?,
+            data: row.get("data")?,
+            event_id: row.get("event_id")?,
+            event_type: row.get("event_type")?,
+            retry: row.get("retry")?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "gen_models.ts")]
+pub enum CollectionRunState {
+    Running,
+    Passed,
+    Failed,
+    Cancelled,
+}
+
+impl Default for CollectionRunState {
+    fn default() -> Self {
+        Self::Running
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, TS)]
+#[serde(default, rename_all = "camelCase")]
+#[ts(export, export_to = "gen_models.ts")]
+pub struct CollectionRunResult {
+    pub request_id: String,
+    pub response_id: String,
+    pub iteration: i32,
+    pub passed: bool,
+    pub status: i32,
+    pub elapsed: i32,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, TS)]
+#[serde(default, rename_all = "camelCase")]
+#[ts(export, export_to = "gen_models.ts")]
+#[enum_def(table_name = "collection_runs")]
+pub struct CollectionRun {
+    #[ts(type = "\"collection_run\"")]
+    pub model: String,
+    pub id: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub workspace_id: String,
+    pub folder_id: Option<String>,
+    pub environment_id: Option<String>,
+    pub cookie_jar_id: Option<String>,
+
+    pub delay: i32,
+    pub elapsed: i32,
+    pub iterations: i32,
+    pub results: Vec<CollectionRunResult>,
+    pub state: CollectionRunState,
+    pub stop_on_failure: bool,
+}
+
+impl UpsertModelInfo for CollectionRun {
+    fn table_name() -> impl IntoTableRef {
+        CollectionRunIden::Table
+    }
+
+    fn id_column() -> impl IntoIden + Eq + Clone {
+        CollectionRunIden::Id
+    }
+
+    fn generate_id() -> String {
+        generate_prefixed_id("cr")
+    }
+
+    fn order_by() -> (impl IntoColumnRef, Order) {
+        (CollectionRunIden::CreatedAt, Desc)
+    }
+
+    fn get_id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn insert_values(
+        self,
+        source: &UpdateSource,
+    ) -> Result<Vec<(impl IntoIden + Eq, impl Into<SimpleExpr>)>> {
+        use CollectionRunIden::*;
+        Ok(vec![
+            (CreatedAt, upsert_date(source, self.created_at)),
+            (UpdatedAt, upsert_date(source, self.updated_at)),
+            (WorkspaceId, self.workspace_id.into()),
+            (FolderId, self.folder_id.into()),
+            (EnvironmentId, self.environment_id.into()),
+            (CookieJarId, self.cookie_jar_id.into()),
+            (Delay, self.delay.into()),
+            (Elapsed, self.elapsed.into()),
+            (Iterations, self.iterations.into()),
+            (Results, serde_json::to_string(&self.results)?.into()),
+            (State, serde_json::to_value(&self.state)?.as_str().into()),
+            (StopOnFailure, self.stop_on_failure.into()),
+        ])
+    }
+
+    fn update_columns() -> Vec<impl IntoIden> {
+        vec![
+            CollectionRunIden::UpdatedAt,
+            CollectionRunIden::Elapsed,
+            CollectionRunIden::Results,
+            CollectionRunIden::State,
+        ]
+    }
+
+    fn from_row(r: &Row) -> rusqlite::Result<Self>
+    where
+        Self: Sized,
+    {
+        let results: String = r.get("results")?;
+        let state: String = r.get("state")?;
+        Ok(Self {
+            id: r.get("id")?,
+            model: r.get("model")?,
+            workspace_id: r.get("workspace_id")?,
+            created_at: r.get("created_at")?,
+            updated_at: r.get("updated_at")?,
+            folder_id: r.get("folder_id")?,
+            environment_id: r.get("environment_id")?,
+            cookie_jar_id: r.get("cookie_jar_id")?,
+            delay: r.get("delay")?,
+            elapsed: r.get("elapsed")?,
+            iterations: r.get("iterations")?,
+            results: serde_json::from_str(results.as_str()).unwrap_or_default(),
+            state: serde_json::from_str(format!(r#""{state}""#).as_str()).unwrap(),
+            stop_on_failure: r.get("stop_on_failure")?,
         })
     }
 }
@@ -1328,6 +2323,10 @@ pub struct GrpcRequest {
     pub service: Option<String>,
     pub sort_priority: f32,
     pub url: String,
+
+    // Settings, overriding the parent folder or workspace when set
+    pub setting_request_timeout: Option<i32>,
+    pub setting_validate_certificates: Option<bool>,
 }
 
 impl UpsertModelInfo for GrpcRequest {
@@ -1371,6 +2370,8 @@ impl UpsertModelInfo for GrpcRequest {
             (AuthenticationType, self.authentication_type.into()),
             (Authentication, serde_json::to_string(&self.authentication)?.into()),
             (Metadata, serde_json::to_string(&self.metadata)?.into()),
+            (SettingRequestTimeout, self.setting_request_timeout.into()),
+            (SettingValidateCertificates, self.setting_validate_certificates.into()),
         ])
     }
 
@@ -1389,6 +2390,8 @@ impl UpsertModelInfo for GrpcRequest {
             GrpcRequestIden::AuthenticationType,
             GrpcRequestIden::Authentication,
             GrpcRequestIden::Metadata,
+            GrpcRequestIden::SettingRequestTimeout,
+            GrpcRequestIden::SettingValidateCertificates,
         ]
     }
 
@@ -1415,6 +2418,8 @@ impl UpsertModelInfo for GrpcRequest {
             url: row.get("url")?,
             sort_priority: row.get("sort_priority")?,
             metadata: serde_json::from_str(metadata.as_str()).unwrap_or_default(),
+            setting_request_timeout: row.get("setting_request_timeout")?,
+            setting_validate_certificates: row.get("setting_validate_certificates")?,
         })
     }
 }
@@ -1922,6 +2927,10 @@ fn default_true() -> bool {
     true
 }
 
+fn default_max_redirects() -> i32 {
+    10
+}
+
 fn default_http_method() -> String {
     "GET".to_string()
 }
@@ -1961,6 +2970,7 @@ macro_rules! define_any_model {
 }
 
 define_any_model! {
+    CollectionRun,
     CookieJar,
     Environment,
     Folder,
@@ -1969,9 +2979,13 @@ define_any_model! {
     GrpcRequest,
     HttpRequest,
     HttpResponse,
+    HttpResponseEvent,
     KeyValue,
     Plugin,
     Settings,
+    SseConnection,
+    SseEvent,
+    SseRequest,
     SyncState,
     WebsocketConnection,
     WebsocketEvent,
@@ -1990,6 +3004,7 @@ impl<'de> Deserialize<'de> for AnyModel {
         use serde_json::from_value as fv;
 
         let model = match model.get("model") {
+            Some(m) if m == "collection_run" => AnyModel::CollectionRun(fv(value).unwrap()),
             Some(m) if m == "cookie_jar" => AnyModel::CookieJar(fv(value).unwrap()),
             Some(m) if m == "environment" => AnyModel::Environment(fv(value).unwrap()),
             Some(m) if m == "folder" => AnyModel::Folder(fv(value).unwrap()),
@@ -1997,9 +3012,15 @@ impl<'de> Deserialize<'de> for AnyModel {
             Some(m) if m == "grpc_event" => AnyModel::GrpcEvent(fv(value).unwrap()),
             Some(m) if m == "grpc_request" => AnyModel::GrpcRequest(fv(value).unwrap()),
             Some(m) if m == "http_request" => AnyModel::HttpRequest(fv(value).unwrap()),
+            Some(m) if m == "http_response_event" => {
+                AnyModel::HttpResponseEvent(fv(value).unwrap())
+            }
             Some(m) if m == "key_value" => AnyModel::KeyValue(fv(value).unwrap()),
             Some(m) if m == "plugin" => AnyModel::Plugin(fv(value).unwrap()),
             Some(m) if m == "settings" => AnyModel::Settings(fv(value).unwrap()),
+            Some(m) if m == "sse_connection" => AnyModel::SseConnection(fv(value).unwrap()),
+            Some(m) if m == "sse_event" => AnyModel::SseEvent(fv(value).unwrap()),
+            Some(m) if m == "sse_request" => AnyModel::SseRequest(fv(value).unwrap()),
             Some(m) if m == "websocket_connection" => {
                 AnyModel::WebsocketConnection(fv(value).unwrap())
             }
@@ -2038,6 +3059,7 @@ impl AnyModel {
             AnyModel::Folder(v) => v.name,
             AnyModel::GrpcRequest(v) => compute_name(&v.name, &v.url, "gRPC Request"),
             AnyModel::HttpRequest(v) => compute_name(&v.name, &v.url, "HTTP Request"),
+            AnyModel::SseRequest(v) => compute_name(&v.name, &v.url, "SSE Request"),
             AnyModel::WebsocketRequest(v) => compute_name(&v.name, &v.url, "WebSocket Request"),
             AnyModel::Workspace(v) => v.name,
             _ => "No Name".to_string(),
@@ -1,6 +1,8 @@
 use crate::db_context::DbContext;
 use crate::error::Result;
-use crate::models::{Environment, Folder, GrpcRequest, HttpRequest, WebsocketRequest, Workspace};
+use crate::models::{
+    Environment, Folder, GrpcRequest, HttpRequest, SseRequest, WebsocketRequest, Workspace,
+};
 use crate::util::{BatchUpsertResult, UpdateSource};
 use log::info;
 
@@ -13,6 +15,7 @@ impl<'a> DbContext<'a> {
         http_requests: Vec<HttpRequest>,
         grpc_requests: Vec<GrpcRequest>,
         websocket_requests: Vec<WebsocketRequest>,
+        sse_requests: Vec<SseRequest>,
         source: &UpdateSource,
     ) -> Result<BatchUpsertResult> {
         let mut imported_resources = BatchUpsertResult::default();
@@ -49,6 +52,14 @@ impl<'a> DbContext<'a> {
             info!("Upserted {} websocket_requests", imported_resources.websocket_requests.len());
         }
 
+        if sse_requests.len() > 0 {
+            for v in sse_requests {
+                let x = self.upsert_sse_request(&v, source)?;
+                imported_resources.sse_requests.push(x.clone());
+            }
+            info!("Upserted {} sse_requests", imported_resources.sse_requests.len());
+        }
+
         if environments.len() > 0 {
             for x in environments {
                 let x = self.upsert_environment(&x, source)?;
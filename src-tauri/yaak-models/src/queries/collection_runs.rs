@@ -0,0 +1,54 @@
+use crate::db_context::DbContext;
+use crate::error::Result;
+use crate::models::{CollectionRun, CollectionRunIden, CollectionRunState};
+use crate::util::UpdateSource;
+use sea_query::{Expr, Query, SqliteQueryBuilder};
+use sea_query_rusqlite::RusqliteBinder;
+
+impl<'a> DbContext<'a> {
+    pub fn get_collection_run(&self, id: &str) -> Result<CollectionRun> {
+        self.find_one(CollectionRunIden::Id, id)
+    }
+
+    pub fn list_collection_runs(&self, workspace_id: &str) -> Result<Vec<CollectionRun>> {
+        self.find_many(CollectionRunIden::WorkspaceId, workspace_id, None)
+    }
+
+    pub fn delete_collection_run(
+        &self,
+        collection_run: &CollectionRun,
+        source: &UpdateSource,
+    ) -> Result<CollectionRun> {
+        self.delete(collection_run, source)
+    }
+
+    pub fn delete_collection_run_by_id(
+        &self,
+        id: &str,
+        source: &UpdateSource,
+    ) -> Result<CollectionRun> {
+        let collection_run = self.get_collection_run(id)?;
+        self.delete_collection_run(&collection_run, source)
+    }
+
+    pub fn upsert_collection_run(
+        &self,
+        collection_run: &CollectionRun,
+        source: &UpdateSource,
+    ) -> Result<CollectionRun> {
+        self.upsert(collection_run, source)
+    }
+
+    pub fn cancel_pending_collection_runs(&self) -> Result<()> {
+        let running = serde_json::to_value(&CollectionRunState::Running)?;
+        let cancelled = serde_json::to_value(&CollectionRunState::Cancelled)?;
+        let (sql, params) = Query::update()
+            .table(CollectionRunIden::Table)
+            .values([(CollectionRunIden::State, cancelled.as_str().into())])
+            .cond_where(Expr::col(CollectionRunIden::State).eq(running.as_str()))
+            .build_rusqlite(SqliteQueryBuilder);
+        let mut stmt = self.conn.prepare(sql.as_str())?;
+        stmt.execute(&*params.as_params())?;
+        Ok(())
+    }
+}
@@ -98,4 +98,36 @@ impl<'a> DbContext<'a> {
             source,
         )
     }
+
+    /// Set a variable on the environment, replacing the value of the first variable with the
+    /// same name or adding a new one if there isn't one
+    pub fn set_environment_variable(
+        &self,
+        environment: &Environment,
+        name: &str,
+        value: &str,
+        source: &UpdateSource,
+    ) -> Result<Environment> {
+        let mut variables = environment.variables.clone();
+        match variables.iter_mut().find(|v| v.name == name) {
+            Some(v) => {
+                v.value = value.to_string();
+                v.enabled = true;
+            }
+            None => variables.push(EnvironmentVariable {
+                enabled: true,
+                name: name.to_string(),
+                value: value.to_string(),
+                id: None,
+            }),
+        }
+
+        self.upsert_environment(
+            &Environment {
+                variables,
+                ..environment.clone()
+            },
+            source,
+        )
+    }
 }
@@ -2,9 +2,10 @@ use crate::connection_or_tx::ConnectionOrTx;
 use crate::db_context::DbContext;
 use crate::error::Result;
 use crate::models::{
-    Folder, FolderIden, GrpcRequest, GrpcRequestIden, HttpRequest, HttpRequestIden,
-    WebsocketRequest, WebsocketRequestIden,
+    Folder, FolderIden, GrpcRequest, GrpcRequestIden, HttpRequest, HttpRequestIden, SseRequest,
+    SseRequestIden, WebsocketRequest, WebsocketRequestIden,
 };
+use crate::render::InheritedSettings;
 use crate::util::UpdateSource;
 
 impl<'a> DbContext<'a> {
@@ -16,6 +17,33 @@ impl<'a> DbContext<'a> {
         self.find_many(FolderIden::WorkspaceId, workspace_id, None)
     }
 
+    /// Resolve the headers, authentication and variables inherited by a model that lives in
+    /// `folder_id` (or at the root of the workspace when `None`)
+    pub fn resolve_inherited_settings(
+        &self,
+        workspace_id: &str,
+        folder_id: Option<&str>,
+    ) -> Result<InheritedSettings> {
+        let workspace = self.get_workspace(workspace_id)?;
+
+        let mut folders: Vec<Folder> = Vec::new();
+        let mut next_id = folder_id.map(|id| id.to_string());
+        while let Some(id) = next_id {
+            // Guard against cycles in the folder tree
+            if folders.iter().any(|f| f.id == id) {
+                break;
+            }
+            let folder = self.get_folder(&id)?;
+            next_id = folder.folder_id.clone();
+            folders.push(folder);
+        }
+
+        // Walk from the outermost folder down to the direct parent
+        folders.reverse();
+
+        Ok(InheritedSettings::from_parents(&workspace, &folders))
+    }
+
     pub fn delete_folder(&self, folder: &Folder, source: &UpdateSource) -> Result<Folder> {
         match self.conn {
             ConnectionOrTx::Connection(_) => {}
@@ -35,6 +63,10 @@ impl<'a> DbContext<'a> {
             self.delete_websocket_request(&m, source)?;
         }
 
+        for m in self.find_many::<SseRequest>(SseRequestIden::FolderId, fid, None)? {
+            self.delete_sse_request(&m, source)?;
+        }
+
         // Recurse down into child folders
         for folder in self.find_many::<Folder>(FolderIden::FolderId, fid, None)? {
             self.delete_folder(&folder, source)?;
@@ -86,6 +118,17 @@ impl<'a> DbContext<'a> {
             )?;
         }
 
+        for m in self.find_many::<SseRequest>(SseRequestIden::FolderId, fid, None)? {
+            self.upsert_sse_request(
+                &SseRequest {
+                    id: "".into(),
+                    folder_id: Some(new_folder.id.clone()),
+                    ..m
+                },
+                source,
+            )?;
+        }
+
         for m in self.find_many::<GrpcRequest>(GrpcRequestIden::FolderId, fid, None)? {
             self.upsert_grpc_request(
                 &GrpcRequest {
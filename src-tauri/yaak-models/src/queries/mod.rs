@@ -1,4 +1,5 @@
 mod batch;
+mod collection_runs;
 mod cookie_jars;
 mod environments;
 mod folders;
@@ -6,11 +7,15 @@ mod grpc_connections;
 mod grpc_events;
 mod grpc_requests;
 mod http_requests;
+mod http_response_events;
 mod http_responses;
 mod key_values;
 mod plugin_key_values;
 mod plugins;
 mod settings;
+mod sse_connections;
+mod sse_events;
+mod sse_requests;
 mod sync_states;
 mod websocket_connections;
 mod websocket_events;
@@ -0,0 +1,90 @@
+use crate::db_context::DbContext;
+use crate::error::Result;
+use crate::models::{SseConnection, SseConnectionIden, SseConnectionState};
+use crate::queries::MAX_HISTORY_ITEMS;
+use crate::util::UpdateSource;
+use log::debug;
+use sea_query::{Expr, Query, SqliteQueryBuilder};
+use sea_query_rusqlite::RusqliteBinder;
+
+impl<'a> DbContext<'a> {
+    pub fn get_sse_connection(&self, id: &str) -> Result<SseConnection> {
+        self.find_one(SseConnectionIden::Id, id)
+    }
+
+    pub fn delete_all_sse_connections_for_request(
+        &self,
+        request_id: &str,
+        source: &UpdateSource,
+    ) -> Result<()> {
+        let responses = self.list_sse_connections_for_request(request_id)?;
+        for m in responses {
+            self.delete(&m, source)?;
+        }
+        Ok(())
+    }
+
+    pub fn delete_all_sse_connections_for_workspace(
+        &self,
+        workspace_id: &str,
+        source: &UpdateSource,
+    ) -> Result<()> {
+        let responses = self.list_sse_connections(workspace_id)?;
+        for m in responses {
+            self.delete(&m, source)?;
+        }
+        Ok(())
+    }
+
+    pub fn list_sse_connections(&self, workspace_id: &str) -> Result<Vec<SseConnection>> {
+        self.find_many(SseConnectionIden::WorkspaceId, workspace_id, None)
+    }
+
+    pub fn list_sse_connections_for_request(&self, request_id: &str) -> Result<Vec<SseConnection>> {
+        self.find_many(SseConnectionIden::RequestId, request_id, None)
+    }
+
+    pub fn delete_sse_connection(
+        &self,
+        sse_connection: &SseConnection,
+        source: &UpdateSource,
+    ) -> Result<SseConnection> {
+        self.delete(sse_connection, source)
+    }
+
+    pub fn delete_sse_connection_by_id(
+        &self,
+        id: &str,
+        source: &UpdateSource,
+    ) -> Result<SseConnection> {
+        let sse_connection = self.get_sse_connection(id)?;
+        self.delete_sse_connection(&sse_connection, source)
+    }
+
+    pub fn upsert_sse_connection(
+        &self,
+        sse_connection: &SseConnection,
+        source: &UpdateSource,
+    ) -> Result<SseConnection> {
+        let connections = self.list_sse_connections_for_request(&sse_connection.request_id)?;
+
+        for m in connections.iter().skip(MAX_HISTORY_ITEMS - 1) {
+            debug!("Deleting old SSE connection {}", m.id);
+            self.delete_sse_connection(m, source)?;
+        }
+
+        self.upsert(sse_connection, source)
+    }
+
+    pub fn cancel_pending_sse_connections(&self) -> Result<()> {
+        let closed = serde_json::to_value(&SseConnectionState::Closed)?;
+        let (sql, params) = Query::update()
+            .table(SseConnectionIden::Table)
+            .values([(SseConnectionIden::State, closed.as_str().into())])
+            .cond_where(Expr::col(SseConnectionIden::State).ne(closed.as_str()))
+            .build_rusqlite(SqliteQueryBuilder);
+        let mut stmt = self.conn.prepare(sql.as_str())?;
+        stmt.execute(&*params.as_params())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,18 @@
+use crate::db_context::DbContext;
+use crate::error::Result;
+use crate::models::{SseEvent, SseEventIden};
+use crate::util::UpdateSource;
+
+impl<'a> DbContext<'a> {
+    pub fn list_sse_events(&self, connection_id: &str) -> Result<Vec<SseEvent>> {
+        self.find_many(SseEventIden::ConnectionId, connection_id, None)
+    }
+
+    pub fn upsert_sse_event(
+        &self,
+        sse_event: &SseEvent,
+        source: &UpdateSource,
+    ) -> Result<SseEvent> {
+        self.upsert(sse_event, source)
+    }
+}
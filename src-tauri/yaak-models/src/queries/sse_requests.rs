@@ -0,0 +1,47 @@
+use crate::db_context::DbContext;
+use crate::error::Result;
+use crate::models::{SseRequest, SseRequestIden};
+use crate::util::UpdateSource;
+
+impl<'a> DbContext<'a> {
+    pub fn get_sse_request(&self, id: &str) -> Result<SseRequest> {
+        self.find_one(SseRequestIden::Id, id)
+    }
+
+    pub fn list_sse_requests(&self, workspace_id: &str) -> Result<Vec<SseRequest>> {
+        self.find_many(SseRequestIden::WorkspaceId, workspace_id, None)
+    }
+
+    pub fn delete_sse_request(
+        &self,
+        sse_request: &SseRequest,
+        source: &UpdateSource,
+    ) -> Result<SseRequest> {
+        self.delete_all_sse_connections_for_request(sse_request.id.as_str(), source)?;
+        self.delete(sse_request, source)
+    }
+
+    pub fn delete_sse_request_by_id(&self, id: &str, source: &UpdateSource) -> Result<SseRequest> {
+        let request = self.get_sse_request(id)?;
+        self.delete_sse_request(&request, source)
+    }
+
+    pub fn duplicate_sse_request(
+        &self,
+        sse_request: &SseRequest,
+        source: &UpdateSource,
+    ) -> Result<SseRequest> {
+        let mut sse_request = sse_request.clone();
+        sse_request.id = "".to_string();
+        sse_request.sort_priority = sse_request.sort_priority + 0.001;
+        self.upsert(&sse_request, source)
+    }
+
+    pub fn upsert_sse_request(
+        &self,
+        sse_request: &SseRequest,
+        source: &UpdateSource,
+    ) -> Result<SseRequest> {
+        self.upsert(sse_request, source)
+    }
+}
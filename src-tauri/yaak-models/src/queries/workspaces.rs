@@ -1,8 +1,8 @@
 use crate::db_context::DbContext;
 use crate::error::Result;
 use crate::models::{
-    EnvironmentIden, FolderIden, GrpcRequestIden, HttpRequestIden, WebsocketRequestIden, Workspace,
-    WorkspaceIden,
+    EnvironmentIden, FolderIden, GrpcRequestIden, HttpRequestIden, SseRequestIden,
+    WebsocketRequestIden, Workspace, WorkspaceIden,
 };
 use crate::util::UpdateSource;
 
@@ -19,6 +19,8 @@ impl<'a> DbContext<'a> {
                 &Workspace {
                     name: "Yaak".to_string(),
                     setting_follow_redirects: true,
+                    setting_max_redirects: 10,
+                    setting_redirect_rewrite_method: true,
                     setting_validate_certificates: true,
                     ..Default::default()
                 },
@@ -46,6 +48,10 @@ impl<'a> DbContext<'a> {
             self.delete_websocket_request(&m, source)?;
         }
 
+        for m in self.find_many(SseRequestIden::WorkspaceId, &workspace.id, None)? {
+            self.delete_sse_request(&m, source)?;
+        }
+
         for m in self.find_many(FolderIden::WorkspaceId, &workspace.id, None)? {
             self.delete_folder(&m, source)?;
         }
@@ -1,12 +1,213 @@
-use std::collections::HashMap;
-use crate::models::{Environment, EnvironmentVariable};
+use std::collections::{BTreeMap, HashMap};
+use serde_json::Value;
+use crate::models::{
+    Environment, EnvironmentVariable, Folder, GrpcRequest, HttpRequest, HttpRequestHeader,
+    SseRequest, WebsocketRequest, Workspace,
+};
+
+/// Authentication type that explicitly disables authentication, instead of inheriting it
+pub const AUTH_TYPE_NONE: &str = "none";
+
+/// Headers, authentication and variables that a request inherits from its workspace and
+/// ancestor folders. Settings closer to the request override those further up the tree.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InheritedSettings {
+    pub headers: Vec<HttpRequestHeader>,
+    pub authentication_type: Option<String>,
+    pub authentication: BTreeMap<String, Value>,
+    pub variables: Vec<EnvironmentVariable>,
+    pub setting_overrides: SettingOverrides,
+}
+
+/// Workspace settings that folders and requests can override. `None` inherits the value from
+/// further up the tree.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SettingOverrides {
+    pub follow_redirects: Option<bool>,
+    pub max_response_size: Option<i64>,
+    pub request_timeout: Option<i32>,
+    pub validate_certificates: Option<bool>,
+}
+
+impl SettingOverrides {
+    /// Layer more specific overrides on top of these ones
+    pub fn merge(&self, other: &SettingOverrides) -> SettingOverrides {
+        SettingOverrides {
+            follow_redirects: other.follow_redirects.or(self.follow_redirects),
+            max_response_size: other.max_response_size.or(self.max_response_size),
+            request_timeout: other.request_timeout.or(self.request_timeout),
+            validate_certificates: other.validate_certificates.or(self.validate_certificates),
+        }
+    }
+
+    /// A copy of the workspace with the overridden settings applied
+    pub fn apply(&self, workspace: &Workspace) -> Workspace {
+        let mut workspace = workspace.clone();
+        if let Some(v) = self.follow_redirects {
+            workspace.setting_follow_redirects = v;
+        }
+        if let Some(v) = self.max_response_size {
+            workspace.setting_max_response_size = v;
+        }
+        if let Some(v) = self.request_timeout {
+            workspace.setting_request_timeout = v;
+        }
+        if let Some(v) = self.validate_certificates {
+            workspace.setting_validate_certificates = v;
+        }
+        workspace
+    }
+}
+
+impl From<&Folder> for SettingOverrides {
+    fn from(f: &Folder) -> Self {
+        SettingOverrides {
+            follow_redirects: f.setting_follow_redirects,
+            max_response_size: f.setting_max_response_size,
+            request_timeout: f.setting_request_timeout,
+            validate_certificates: f.setting_validate_certificates,
+        }
+    }
+}
+
+impl From<&HttpRequest> for SettingOverrides {
+    fn from(r: &HttpRequest) -> Self {
+        SettingOverrides {
+            follow_redirects: r.setting_follow_redirects,
+            max_response_size: r.setting_max_response_size,
+            request_timeout: r.setting_request_timeout,
+            validate_certificates: r.setting_validate_certificates,
+        }
+    }
+}
+
+impl From<&GrpcRequest> for SettingOverrides {
+    fn from(r: &GrpcRequest) -> Self {
+        SettingOverrides {
+            follow_redirects: None,
+            max_response_size: None,
+            request_timeout: r.setting_request_timeout,
+            validate_certificates: r.setting_validate_certificates,
+        }
+    }
+}
+
+impl From<&WebsocketRequest> for SettingOverrides {
+    fn from(r: &WebsocketRequest) -> Self {
+        SettingOverrides {
+            follow_redirects: None,
+            max_response_size: None,
+            request_timeout: r.setting_request_timeout,
+            validate_certificates: r.setting_validate_certificates,
+        }
+    }
+}
+
+impl From<&SseRequest> for SettingOverrides {
+    fn from(r: &SseRequest) -> Self {
+        SettingOverrides {
+            follow_redirects: None,
+            max_response_size: None,
+            request_timeout: r.setting_request_timeout,
+            validate_certificates: r.setting_validate_certificates,
+        }
+    }
+}
+
+impl InheritedSettings {
+    /// Build settings from a workspace and its folders, ordered from the outermost folder to
+    /// the request's direct parent
+    pub fn from_parents(workspace: &Workspace, folders: &[Folder]) -> Self {
+        let mut settings = InheritedSettings::default();
+        settings.push(
+            &workspace.headers,
+            &workspace.authentication_type,
+            &workspace.authentication,
+            &workspace.variables,
+        );
+        for f in folders {
+            settings.push(&f.headers, &f.authentication_type, &f.authentication, &f.variables);
+            settings.setting_overrides = settings.setting_overrides.merge(&f.into());
+        }
+        settings
+    }
+
+    /// The workspace with folder and request setting overrides applied, which is what requests
+    /// should read their settings from
+    pub fn resolve_workspace(
+        &self,
+        workspace: &Workspace,
+        request: impl Into<SettingOverrides>,
+    ) -> Workspace {
+        self.setting_overrides.merge(&request.into()).apply(workspace)
+    }
+
+    fn push(
+        &mut self,
+        headers: &Vec<HttpRequestHeader>,
+        authentication_type: &Option<String>,
+        authentication: &BTreeMap<String, Value>,
+        variables: &Vec<EnvironmentVariable>,
+    ) {
+        self.headers = self.merge_headers(headers);
+        if authentication_type.is_some() {
+            self.authentication_type = authentication_type.clone();
+            self.authentication = authentication.clone();
+        }
+        self.variables.extend(variables.iter().filter(|v| v.enabled).cloned());
+    }
+
+    /// Merge a request's headers on top of the inherited ones. A request header replaces every
+    /// inherited header with the same (case-insensitive) name.
+    pub fn merge_headers(&self, headers: &Vec<HttpRequestHeader>) -> Vec<HttpRequestHeader> {
+        let mut merged: Vec<HttpRequestHeader> = self
+            .headers
+            .iter()
+            .filter(|h| {
+                !headers.iter().any(|c| c.enabled && c.name.eq_ignore_ascii_case(&h.name))
+            })
+            .cloned()
+            .collect();
+        merged.extend(headers.iter().cloned());
+        merged
+    }
+
+    /// Resolve a request's authentication. Requests without an authentication type inherit
+    /// their parent's, and [`AUTH_TYPE_NONE`] disables authentication altogether.
+    pub fn resolve_authentication(
+        &self,
+        authentication_type: &Option<String>,
+        authentication: &BTreeMap<String, Value>,
+    ) -> (Option<String>, BTreeMap<String, Value>) {
+        let (authentication_type, authentication) = match authentication_type {
+            None => (self.authentication_type.clone(), self.authentication.clone()),
+            Some(_) => (authentication_type.clone(), authentication.clone()),
+        };
+
+        match authentication_type.as_deref() {
+            Some(AUTH_TYPE_NONE) => (None, BTreeMap::new()),
+            _ => (authentication_type, authentication),
+        }
+    }
+}
 
 pub fn make_vars_hashmap(
     base_environment: &Environment,
     environment: Option<&Environment>,
+) -> HashMap<String, String> {
+    make_request_vars_hashmap(base_environment, &InheritedSettings::default(), environment)
+}
+
+/// Variables for rendering a request. Inherited workspace and folder variables override the
+/// base environment, and the selected environment overrides everything.
+pub fn make_request_vars_hashmap(
+    base_environment: &Environment,
+    inherited: &InheritedSettings,
+    environment: Option<&Environment>,
 ) -> HashMap<String, String> {
     let mut variables = HashMap::new();
     variables = add_variable_to_map(variables, &base_environment.variables);
+    variables = add_variable_to_map(variables, &inherited.variables);
 
     if let Some(e) = environment {
         variables = add_variable_to_map(variables, &e.variables);
@@ -32,3 +233,117 @@ fn add_variable_to_map(
     map
 }
 
+#[cfg(test)]
+mod render_tests {
+    use crate::models::{EnvironmentVariable, Folder, HttpRequest, HttpRequestHeader, Workspace};
+    use crate::render::{AUTH_TYPE_NONE, InheritedSettings};
+    use serde_json::Value;
+    use std::collections::BTreeMap;
+
+    fn header(name: &str, value: &str) -> HttpRequestHeader {
+        HttpRequestHeader { enabled: true, name: name.into(), value: value.into(), id: None }
+    }
+
+    fn variable(name: &str, value: &str) -> EnvironmentVariable {
+        EnvironmentVariable { enabled: true, name: name.into(), value: value.into(), id: None }
+    }
+
+    fn auth(token: &str) -> BTreeMap<String, Value> {
+        BTreeMap::from([("token".to_string(), Value::String(token.into()))])
+    }
+
+    fn settings() -> InheritedSettings {
+        let workspace = Workspace {
+            headers: vec![header("Authorization", "ws"), header("X-Workspace", "1")],
+            authentication_type: Some("bearer".into()),
+            authentication: auth("ws"),
+            variables: vec![variable("base_url", "https://ws.example.com")],
+            ..Default::default()
+        };
+        let folders = vec![
+            Folder {
+                headers: vec![header("authorization", "outer")],
+                variables: vec![variable("base_url", "https://outer.example.com")],
+                ..Default::default()
+            },
+            Folder {
+                authentication_type: Some("basic".into()),
+                authentication: auth("inner"),
+                ..Default::default()
+            },
+        ];
+        InheritedSettings::from_parents(&workspace, &folders)
+    }
+
+    #[test]
+    fn child_headers_override_parent() {
+        let s = settings();
+        assert_eq!(s.headers, vec![header("X-Workspace", "1"), header("authorization", "outer")]);
+
+        let merged = s.merge_headers(&vec![header("X-WORKSPACE", "req")]);
+        assert_eq!(merged, vec![header("authorization", "outer"), header("X-WORKSPACE", "req")]);
+    }
+
+    #[test]
+    fn authentication_inherited_from_nearest_parent() {
+        let s = settings();
+        assert_eq!(
+            s.resolve_authentication(&None, &BTreeMap::new()),
+            (Some("basic".into()), auth("inner"))
+        );
+        assert_eq!(
+            s.resolve_authentication(&Some("bearer".into()), &auth("req")),
+            (Some("bearer".into()), auth("req"))
+        );
+        assert_eq!(
+            s.resolve_authentication(&Some(AUTH_TYPE_NONE.into()), &BTreeMap::new()),
+            (None, BTreeMap::new())
+        );
+    }
+
+    #[test]
+    fn settings_overridden_by_nearest_folder_then_request() {
+        let workspace = Workspace {
+            setting_follow_redirects: true,
+            setting_request_timeout: 0,
+            setting_validate_certificates: true,
+            ..Default::default()
+        };
+        let folders = vec![
+            Folder {
+                setting_request_timeout: Some(1000),
+                setting_validate_certificates: Some(false),
+                ..Default::default()
+            },
+            Folder {
+                setting_request_timeout: Some(5000),
+                ..Default::default()
+            },
+        ];
+        let s = InheritedSettings::from_parents(&workspace, &folders);
+
+        let resolved = s.resolve_workspace(&workspace, &HttpRequest::default());
+        assert_eq!(resolved.setting_request_timeout, 5000);
+        assert!(!resolved.setting_validate_certificates);
+        assert!(resolved.setting_follow_redirects);
+
+        let request = HttpRequest {
+            setting_follow_redirects: Some(false),
+            setting_request_timeout: Some(0),
+            ..Default::default()
+        };
+        let resolved = s.resolve_workspace(&workspace, &request);
+        assert_eq!(resolved.setting_request_timeout, 0);
+        assert!(!resolved.setting_follow_redirects);
+    }
+
+    #[test]
+    fn folder_variables_override_workspace() {
+        let vars = crate::render::make_request_vars_hashmap(
+            &Default::default(),
+            &settings(),
+            None,
+        );
+        assert_eq!(vars.get("base_url").unwrap(), "https://outer.example.com");
+    }
+}
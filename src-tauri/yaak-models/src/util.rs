@@ -1,7 +1,7 @@
 use crate::error::Result;
 use crate::models::{
-    AnyModel, Environment, Folder, GrpcRequest, HttpRequest, UpsertModelInfo, WebsocketRequest,
-    Workspace, WorkspaceIden,
+    AnyModel, Environment, Folder, GrpcRequest, HttpRequest, SseRequest, UpsertModelInfo,
+    WebsocketRequest, Workspace, WorkspaceIden,
 };
 use crate::query_manager::QueryManagerExt;
 use chrono::{NaiveDateTime, Utc};
@@ -118,6 +118,7 @@ pub struct BatchUpsertResult {
     pub http_requests: Vec<HttpRequest>,
     pub grpc_requests: Vec<GrpcRequest>,
     pub websocket_requests: Vec<WebsocketRequest>,
+    pub sse_requests: Vec<SseRequest>,
 }
 
 pub fn get_workspace_export_resources<R: Runtime>(
@@ -136,6 +137,7 @@ pub fn get_workspace_export_resources<R: Runtime>(
             http_requests: Vec::new(),
             grpc_requests: Vec::new(),
             websocket_requests: Vec::new(),
+            sse_requests: Vec::new(),
         },
     };
 
@@ -153,6 +155,7 @@ pub fn get_workspace_export_resources<R: Runtime>(
         data.resources.http_requests.append(&mut db.list_http_requests(workspace_id)?);
         data.resources.grpc_requests.append(&mut db.list_grpc_requests(workspace_id)?);
         data.resources.websocket_requests.append(&mut db.list_websocket_requests(workspace_id)?);
+        data.resources.sse_requests.append(&mut db.list_sse_requests(workspace_id)?);
     }
 
     Ok(data)
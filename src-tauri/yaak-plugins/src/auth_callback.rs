@@ -0,0 +1,66 @@
+use crate::events::{AuthenticationRequestType, CallHttpAuthenticationRequest, HttpHeader};
+use crate::manager::PluginManager;
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use tauri::{Manager, Runtime, WebviewWindow};
+use yaak_http::auth;
+use yaak_http::auth::{AuthenticationCallback, AuthenticationRequest, AuthenticationResult};
+use yaak_http::error::Error::AuthenticationError;
+use yaak_http::error::Result;
+
+#[derive(Clone)]
+pub struct PluginAuthenticationCallback<R: Runtime> {
+    window: WebviewWindow<R>,
+}
+
+impl<R: Runtime> PluginAuthenticationCallback<R> {
+    pub fn new(window: &WebviewWindow<R>) -> PluginAuthenticationCallback<R> {
+        PluginAuthenticationCallback {
+            window: window.to_owned(),
+        }
+    }
+}
+
+impl<R: Runtime> AuthenticationCallback for PluginAuthenticationCallback<R> {
+    async fn authenticate(
+        &self,
+        auth_name: &str,
+        req: AuthenticationRequest,
+    ) -> Result<AuthenticationResult> {
+        let plugin_manager = &*self.window.state::<PluginManager>();
+        let req = CallHttpAuthenticationRequest {
+            context_id: req.context_id,
+            request_type: match req.request_type {
+                auth::AuthenticationRequestType::Http => AuthenticationRequestType::Http,
+                auth::AuthenticationRequestType::Grpc => AuthenticationRequestType::Grpc,
+                auth::AuthenticationRequestType::Websocket => AuthenticationRequestType::Websocket,
+                auth::AuthenticationRequestType::Sse => AuthenticationRequestType::Sse,
+            },
+            values: serde_json::from_value(serde_json::to_value(&req.values)?)?,
+            url: req.url,
+            method: req.method,
+            headers: req
+                .headers
+                .into_iter()
+                .map(|(name, value)| HttpHeader { name, value })
+                .collect(),
+            body_base64: req.body.map(|b| BASE64_STANDARD.encode(b)),
+        };
+
+        let resp = plugin_manager
+            .call_http_authentication(&self.window, auth_name, req)
+            .await
+            .map_err(|e| AuthenticationError(e.to_string()))?;
+
+        Ok(AuthenticationResult {
+            set_headers: resp.set_headers.into_iter().map(|h| (h.name, h.value)).collect(),
+            set_query_parameters: resp
+                .set_query_parameters
+                .unwrap_or_default()
+                .into_iter()
+                .map(|p| (p.name, p.value))
+                .collect(),
+            set_url: resp.set_url,
+        })
+    }
+}
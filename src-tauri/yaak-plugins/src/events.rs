@@ -4,7 +4,8 @@ use tauri::{Runtime, WebviewWindow};
 use ts_rs::TS;
 use yaak_common::window::WorkspaceWindowTrait;
 use yaak_models::models::{
-    Environment, Folder, GrpcRequest, HttpRequest, HttpResponse, WebsocketRequest, Workspace,
+    Environment, Folder, GrpcRequest, HttpRequest, HttpResponse, SseRequest, WebsocketRequest,
+    Workspace,
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
@@ -84,6 +85,12 @@ pub enum InternalEventPayload {
     SendHttpRequestRequest(SendHttpRequestRequest),
     SendHttpRequestResponse(SendHttpRequestResponse),
 
+    // Http Request Hooks
+    PreRequestHookRequest(PreRequestHookRequest),
+    PreRequestHookResponse(PreRequestHookResponse),
+    PostResponseHookRequest(PostResponseHookRequest),
+    PostResponseHookResponse(EmptyPayload),
+
     // Request Actions
     GetHttpRequestActionsRequest(EmptyPayload),
     GetHttpRequestActionsResponse(GetHttpRequestActionsResponse),
@@ -118,6 +125,9 @@ pub enum InternalEventPayload {
     DeleteKeyValueRequest(DeleteKeyValueRequest),
     DeleteKeyValueResponse(DeleteKeyValueResponse),
 
+    SetEnvironmentVariableRequest(SetEnvironmentVariableRequest),
+    SetEnvironmentVariableResponse(EmptyPayload),
+
     OpenWindowRequest(OpenWindowRequest),
     WindowNavigateEvent(WindowNavigateEvent),
     WindowCloseEvent,
@@ -231,6 +241,30 @@ pub struct SendHttpRequestResponse {
     pub http_response: HttpResponse,
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[serde(default, rename_all = "camelCase")]
+#[ts(export, export_to = "gen_events.ts")]
+pub struct PreRequestHookRequest {
+    /// The rendered request, just before it is sent
+    pub http_request: HttpRequest,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[serde(default, rename_all = "camelCase")]
+#[ts(export, export_to = "gen_events.ts")]
+pub struct PreRequestHookResponse {
+    /// The request to send in place of the original
+    pub http_request: HttpRequest,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[serde(default, rename_all = "camelCase")]
+#[ts(export, export_to = "gen_events.ts")]
+pub struct PostResponseHookRequest {
+    pub http_request: HttpRequest,
+    pub http_response: HttpResponse,
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
 #[serde(default, rename_all = "camelCase")]
 #[ts(export, export_to = "gen_events.ts")]
@@ -412,11 +446,25 @@ pub struct HttpAuthenticationAction {
     pub icon: Option<Icon>,
 }
 
+/// The kind of request that authentication is being applied to. Plugins can use this to
+/// adjust their behavior, since gRPC and WebSocket requests only support setting headers.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export, export_to = "gen_events.ts")]
+pub enum AuthenticationRequestType {
+    #[default]
+    Http,
+    Grpc,
+    Websocket,
+    Sse,
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
 #[serde(default, rename_all = "camelCase")]
 #[ts(export, export_to = "gen_events.ts")]
 pub struct GetHttpAuthenticationConfigRequest {
     pub context_id: String,
+    pub request_type: AuthenticationRequestType,
     pub values: HashMap<String, JsonPrimitive>,
 }
 
@@ -439,15 +487,27 @@ pub struct HttpHeader {
     pub value: String,
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[serde(default, rename_all = "camelCase")]
+#[ts(export, export_to = "gen_events.ts")]
+pub struct HttpQueryParameter {
+    pub name: String,
+    pub value: String,
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
 #[serde(default, rename_all = "camelCase")]
 #[ts(export, export_to = "gen_events.ts")]
 pub struct CallHttpAuthenticationRequest {
     pub context_id: String,
+    pub request_type: AuthenticationRequestType,
     pub values: HashMap<String, JsonPrimitive>,
     pub method: String,
     pub url: String,
     pub headers: Vec<HttpHeader>,
+    /// Base64 encoded request body, for signing it. This is null when there's no body, or when
+    /// it's streamed (eg. large file uploads).
+    pub body_base64: Option<String>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
@@ -464,6 +524,7 @@ pub struct CallHttpAuthenticationActionRequest {
 #[ts(export, export_to = "gen_events.ts")]
 pub struct CallHttpAuthenticationActionArgs {
     pub context_id: String,
+    pub request_type: AuthenticationRequestType,
     pub values: HashMap<String, JsonPrimitive>,
 }
 
@@ -484,6 +545,15 @@ pub struct CallHttpAuthenticationResponse {
     /// HTTP headers to add to the request. Existing headers will be replaced, while
     /// new headers will be added.
     pub set_headers: Vec<HttpHeader>,
+
+    /// Query parameters to add to the URL. Existing parameters with the same name will be
+    /// replaced. Use `setUrl` instead when the exact encoding of the URL matters.
+    #[ts(optional)]
+    pub set_query_parameters: Option<Vec<HttpQueryParameter>>,
+
+    /// Replacement for the request URL, applied before `setQueryParameters`
+    #[ts(optional)]
+    pub set_url: Option<String>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
@@ -900,6 +970,18 @@ pub struct ImportResources {
     pub http_requests: Vec<HttpRequest>,
     pub grpc_requests: Vec<GrpcRequest>,
     pub websocket_requests: Vec<WebsocketRequest>,
+    pub sse_requests: Vec<SseRequest>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[serde(default, rename_all = "camelCase")]
+#[ts(export, export_to = "gen_events.ts")]
+pub struct SetEnvironmentVariableRequest {
+    pub name: String,
+    pub value: String,
+    /// Set the variable in the base environment instead of the active one
+    #[ts(optional)]
+    pub base: Option<bool>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
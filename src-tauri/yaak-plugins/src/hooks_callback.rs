@@ -0,0 +1,38 @@
+use crate::events::PluginWindowContext;
+use crate::manager::PluginManager;
+use tauri::{Manager, Runtime, WebviewWindow};
+use yaak_http::error::Error::HookError;
+use yaak_http::error::Result;
+use yaak_http::hooks::HttpHooksCallback;
+use yaak_models::models::{HttpRequest, HttpResponse};
+
+#[derive(Clone)]
+pub struct PluginHooksCallback<R: Runtime> {
+    window: WebviewWindow<R>,
+}
+
+impl<R: Runtime> PluginHooksCallback<R> {
+    pub fn new(window: &WebviewWindow<R>) -> PluginHooksCallback<R> {
+        PluginHooksCallback {
+            window: window.to_owned(),
+        }
+    }
+}
+
+impl<R: Runtime> HttpHooksCallback for PluginHooksCallback<R> {
+    async fn pre_request(&self, req: HttpRequest) -> Result<HttpRequest> {
+        let plugin_manager = &*self.window.state::<PluginManager>();
+        plugin_manager
+            .call_pre_request_hooks(&PluginWindowContext::new(&self.window), req)
+            .await
+            .map_err(|e| HookError(e.to_string()))
+    }
+
+    async fn post_response(&self, req: &HttpRequest, resp: &HttpResponse) -> Result<()> {
+        let plugin_manager = &*self.window.state::<PluginManager>();
+        plugin_manager
+            .call_post_response_hooks(&PluginWindowContext::new(&self.window), req, resp)
+            .await
+            .map_err(|e| HookError(e.to_string()))
+    }
+}
@@ -4,8 +4,10 @@ use std::process::exit;
 use tauri::plugin::{Builder, TauriPlugin};
 use tauri::{Manager, RunEvent, Runtime, State};
 
+pub mod auth_callback;
 pub mod error;
 pub mod events;
+pub mod hooks_callback;
 pub mod manager;
 pub mod native_template_functions;
 mod nodejs;
@@ -3,13 +3,15 @@ use crate::error::Error::{
 };
 use crate::error::Result;
 use crate::events::{
-    BootRequest, CallHttpAuthenticationActionArgs, CallHttpAuthenticationActionRequest,
-    CallHttpAuthenticationRequest, CallHttpAuthenticationResponse, CallHttpRequestActionRequest,
-    CallTemplateFunctionArgs, CallTemplateFunctionRequest, CallTemplateFunctionResponse,
-    EmptyPayload, FilterRequest, FilterResponse, GetHttpAuthenticationConfigRequest,
-    GetHttpAuthenticationConfigResponse, GetHttpAuthenticationSummaryResponse,
-    GetHttpRequestActionsResponse, GetTemplateFunctionsResponse, ImportRequest, ImportResponse,
-    InternalEvent, InternalEventPayload, JsonPrimitive, PluginWindowContext, RenderPurpose,
+    AuthenticationRequestType, BootRequest, CallHttpAuthenticationActionArgs,
+    CallHttpAuthenticationActionRequest, CallHttpAuthenticationRequest,
+    CallHttpAuthenticationResponse, CallHttpRequestActionRequest, CallTemplateFunctionArgs,
+    CallTemplateFunctionRequest, CallTemplateFunctionResponse, EmptyPayload, FilterRequest,
+    FilterResponse, GetHttpAuthenticationConfigRequest, GetHttpAuthenticationConfigResponse,
+    GetHttpAuthenticationSummaryResponse, GetHttpRequestActionsResponse,
+    GetTemplateFunctionsResponse, ImportRequest, ImportResponse, InternalEvent,
+    InternalEventPayload, JsonPrimitive, PluginWindowContext, PostResponseHookRequest,
+    PreRequestHookRequest, RenderPurpose,
 };
 use crate::nodejs::start_nodejs_plugin_runtime;
 use crate::plugin_handle::PluginHandle;
@@ -26,11 +28,14 @@ use tokio::fs::read_dir;
 use tokio::net::TcpListener;
 use tokio::sync::{mpsc, Mutex};
 use tokio::time::{timeout, Instant};
+use yaak_models::models::{HttpRequest, HttpResponse};
 use yaak_models::query_manager::QueryManagerExt;
 use yaak_models::util::generate_id;
 use yaak_templates::error::Error::RenderError;
 use yaak_templates::error::Result as TemplateResult;
-use crate::native_template_functions::template_function_secure;
+use crate::native_template_functions::{
+    template_function_response_body_path, template_function_secure,
+};
 
 #[derive(Clone)]
 pub struct PluginManager {
@@ -446,10 +451,15 @@ impl PluginManager {
             }
         }
 
-        // Add Rust-based functions
+        // Add Rust-based functions, which replace plugin functions of the same name
+        let native_functions =
+            vec![template_function_secure(), template_function_response_body_path()];
+        for resp in result.iter_mut() {
+            resp.functions.retain(|f| !native_functions.iter().any(|n| n.name == f.name));
+        }
         result.push(GetTemplateFunctionsResponse {
             plugin_ref_id: "__NATIVE__".to_string(), // Meh
-            functions: vec![template_function_secure()],
+            functions: native_functions,
         });
 
         Ok(result)
@@ -505,6 +515,7 @@ impl PluginManager {
         auth_name: &str,
         values: HashMap<String, JsonPrimitive>,
         request_id: &str,
+        request_type: AuthenticationRequestType,
     ) -> Result<GetHttpAuthenticationConfigResponse> {
         let results = self.get_http_authentication_summaries(window).await?;
         let plugin = results
@@ -518,7 +529,11 @@ impl PluginManager {
                 &PluginWindowContext::new(window),
                 &plugin,
                 &InternalEventPayload::GetHttpAuthenticationConfigRequest(
-                    GetHttpAuthenticationConfigRequest { values, context_id },
+                    GetHttpAuthenticationConfigRequest {
+                        values,
+                        context_id,
+                        request_type,
+                    },
                 ),
             )
             .await?;
@@ -538,6 +553,7 @@ impl PluginManager {
         action_index: i32,
         values: HashMap<String, JsonPrimitive>,
         request_id: &str,
+        request_type: AuthenticationRequestType,
     ) -> Result<()> {
         let results = self.get_http_authentication_summaries(window).await?;
         let plugin = results
@@ -553,7 +569,11 @@ impl PluginManager {
                 CallHttpAuthenticationActionRequest {
                     index: action_index,
                     plugin_ref_id: plugin.clone().ref_id,
-                    args: CallHttpAuthenticationActionArgs { context_id, values },
+                    args: CallHttpAuthenticationActionArgs {
+                        context_id,
+                        request_type,
+                        values,
+                    },
                 },
             ),
         )
@@ -575,9 +595,7 @@ impl PluginManager {
         // Auth is disabled, so don't do anything
         if disabled {
             info!("Not applying disabled auth {:?}", auth_name);
-            return Ok(CallHttpAuthenticationResponse {
-                set_headers: Vec::new(),
-            });
+            return Ok(CallHttpAuthenticationResponse::default());
         }
 
         let handlers = self.get_http_authentication_summaries(window).await?;
@@ -602,6 +620,69 @@ impl PluginManager {
         }
     }
 
+    /// Pass the rendered request through each plugin's pre-request hook, in order, so that each
+    /// hook sees the changes made by the ones before it.
+    pub async fn call_pre_request_hooks(
+        &self,
+        window_context: &PluginWindowContext,
+        http_request: HttpRequest,
+    ) -> Result<HttpRequest> {
+        let plugins = { self.plugins.lock().await.clone() };
+        let mut http_request = http_request;
+        for plugin in plugins {
+            let event = self
+                .send_to_plugin_and_wait(
+                    window_context,
+                    &plugin,
+                    &InternalEventPayload::PreRequestHookRequest(PreRequestHookRequest {
+                        http_request: http_request.clone(),
+                    }),
+                )
+                .await?;
+            match event.payload {
+                InternalEventPayload::PreRequestHookResponse(resp) => {
+                    http_request = resp.http_request
+                }
+                InternalEventPayload::ErrorResponse(resp) => {
+                    let name = plugin.name().await;
+                    return Err(PluginErr(format!(
+                        "Pre-request hook {name} failed: {}",
+                        resp.error
+                    )));
+                }
+                _ => {}
+            }
+        }
+        Ok(http_request)
+    }
+
+    pub async fn call_post_response_hooks(
+        &self,
+        window_context: &PluginWindowContext,
+        http_request: &HttpRequest,
+        http_response: &HttpResponse,
+    ) -> Result<()> {
+        let req = PostResponseHookRequest {
+            http_request: http_request.clone(),
+            http_response: http_response.clone(),
+        };
+        let events = self
+            .send_and_wait(window_context, &InternalEventPayload::PostResponseHookRequest(req))
+            .await?;
+
+        let errors = events
+            .into_iter()
+            .filter_map(|e| match e.payload {
+                InternalEventPayload::ErrorResponse(resp) => Some(resp.error),
+                _ => None,
+            })
+            .collect::<Vec<String>>();
+        if !errors.is_empty() {
+            return Err(PluginErr(format!("Post-response hook failed: {}", errors.join(", "))));
+        }
+        Ok(())
+    }
+
     pub async fn call_template_function(
         &self,
         window_context: &PluginWindowContext,
@@ -1,13 +1,29 @@
+use crate::auth_callback::PluginAuthenticationCallback;
 use crate::events::{
-    FormInput, FormInputBase, FormInputText, PluginWindowContext, RenderPurpose, TemplateFunction,
-    TemplateFunctionArg,
+    FormInput, FormInputBase, FormInputHttpRequest, FormInputSelect, FormInputSelectOption,
+    FormInputText, PluginWindowContext, RenderPurpose, TemplateFunction, TemplateFunctionArg,
 };
+use crate::hooks_callback::PluginHooksCallback;
 use crate::template_callback::PluginTemplateCallback;
 use base64::Engine;
 use base64::prelude::BASE64_STANDARD;
+use chrono::{Duration, Utc};
+use jsonpath_rust::JsonPath;
+use serde_json::Value;
 use std::collections::HashMap;
-use tauri::{AppHandle, Runtime};
+use std::future::Future;
+use std::pin::Pin;
+use std::str::FromStr;
+use tauri::{AppHandle, Manager, Runtime};
+use tokio::sync::watch;
+use tokio::sync::watch::Receiver;
+use yaak_common::window::WorkspaceWindowTrait;
 use yaak_crypto::manager::EncryptionManagerExt;
+use yaak_http::client_pool::HttpClientPool;
+use yaak_http::sender::HttpSender;
+use yaak_models::models::{HttpResponse, HttpResponseState};
+use yaak_models::query_manager::QueryManagerExt;
+use yaak_models::util::UpdateSource;
 use yaak_templates::error::Error::RenderError;
 use yaak_templates::error::Result;
 use yaak_templates::{FnArg, Parser, Token, Tokens, Val, transform_args};
@@ -101,6 +117,236 @@ pub fn template_function_secure_transform_arg<R: Runtime>(
     }
 }
 
+pub(crate) fn template_function_response_body_path() -> TemplateFunction {
+    TemplateFunction {
+        name: "response.body.path".to_string(),
+        description: Some(
+            "Access a field of the response body using JsonPath or XPath".to_string(),
+        ),
+        aliases: Some(vec!["response".to_string()]),
+        args: vec![
+            TemplateFunctionArg::FormInput(FormInput::HttpRequest(FormInputHttpRequest {
+                base: FormInputBase {
+                    name: "request".to_string(),
+                    label: Some("Request".to_string()),
+                    ..Default::default()
+                },
+            })),
+            TemplateFunctionArg::FormInput(FormInput::Text(FormInputText {
+                placeholder: Some("$.books[0].id or /books[0]/id".to_string()),
+                base: FormInputBase {
+                    name: "path".to_string(),
+                    label: Some("JSONPath or XPath".to_string()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })),
+            TemplateFunctionArg::FormInput(FormInput::Select(FormInputSelect {
+                options: vec![
+                    FormInputSelectOption {
+                        label: "When no responses or expired".to_string(),
+                        value: "smart".to_string(),
+                    },
+                    FormInputSelectOption {
+                        label: "Always".to_string(),
+                        value: "always".to_string(),
+                    },
+                    FormInputSelectOption {
+                        label: "Never".to_string(),
+                        value: "never".to_string(),
+                    },
+                ],
+                base: FormInputBase {
+                    name: "behavior".to_string(),
+                    label: Some("Sending Behavior".to_string()),
+                    default_value: Some("smart".to_string()),
+                    ..Default::default()
+                },
+            })),
+            TemplateFunctionArg::FormInput(FormInput::Text(FormInputText {
+                placeholder: Some("Never expires".to_string()),
+                base: FormInputBase {
+                    name: "ttl".to_string(),
+                    label: Some("Max Response Age (seconds)".to_string()),
+                    optional: Some(true),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })),
+        ],
+    }
+}
+
+/// Render a value from another request's latest response, sending that request first if needed.
+///
+/// This returns a boxed future because sending the request renders it, which can call back into
+/// this function.
+pub fn template_function_response_body_path_run<'a, R: Runtime>(
+    app_handle: &'a AppHandle<R>,
+    window_context: &'a PluginWindowContext,
+    render_purpose: &'a RenderPurpose,
+    request_chain: &'a [String],
+    cancelled_rx: Option<&'a Receiver<bool>>,
+    args: HashMap<String, String>,
+) -> Pin<Box<dyn Future<Output = Result<String>> + Send + 'a>> {
+    Box::pin(async move {
+        let request_id = args.get("request").cloned().unwrap_or_default();
+        let path = args.get("path").cloned().unwrap_or_default();
+        if request_id.is_empty() || path.is_empty() {
+            return Ok("".to_string());
+        }
+
+        check_request_chain(request_chain, &request_id)?;
+
+        let ttl = args.get("ttl").and_then(|t| t.trim().parse::<i64>().ok()).unwrap_or(0);
+        let behavior = args.get("behavior").map(|b| b.as_str()).unwrap_or("smart");
+
+        let (request, latest_response) = {
+            let db = app_handle.db();
+            let request = db
+                .get_http_request(&request_id)
+                .map_err(|_| RenderError(format!("Request {request_id} not found")))?;
+            let latest_response = db
+                .list_http_responses_for_request(&request_id, None)
+                .map_err(|e| RenderError(e.to_string()))?
+                .into_iter()
+                .find(|r| matches!(r.state, HttpResponseState::Closed));
+            (request, latest_response)
+        };
+
+        let age = latest_response.as_ref().map(|r| Utc::now().naive_utc() - r.updated_at);
+        let response = match (should_send(render_purpose, behavior, age, ttl), latest_response) {
+            (true, _) => {
+                let window = match window_context {
+                    PluginWindowContext::Label { label, .. } => {
+                        app_handle.get_webview_window(label)
+                    }
+                    PluginWindowContext::None => None,
+                }
+                .ok_or(RenderError("Chained requests need a window to send from".to_string()))?;
+
+                let mut request_chain = request_chain.to_vec();
+                request_chain.push(request.id.clone());
+
+                // Cancelling the request being rendered also cancels the ones it's waiting on
+                let (_never_cancelled, mut cancelled_rx) = cancel_receiver(cancelled_rx);
+
+                let (environment, cookie_jar) = {
+                    let db = app_handle.db();
+                    let environment =
+                        window.environment_id().and_then(|id| db.get_environment(&id).ok());
+                    let cookie_jar =
+                        window.cookie_jar_id().and_then(|id| db.get_cookie_jar(&id).ok());
+                    (environment, cookie_jar)
+                };
+
+                let sender = HttpSender {
+                    query_manager: app_handle.db_manager().inner().clone(),
+                    response_dir: app_handle
+                        .path()
+                        .app_data_dir()
+                        .map_err(|e| RenderError(e.to_string()))?
+                        .join("responses"),
+                    update_source: UpdateSource::from_window(&window),
+                    client_pool: app_handle.state::<HttpClientPool>().inner().clone(),
+                    fresh_connection: false,
+                    template_callback: PluginTemplateCallback::new(
+                        app_handle,
+                        window_context,
+                        render_purpose.clone(),
+                    )
+                    .with_request_chain(request_chain)
+                    .with_cancel(cancelled_rx.clone()),
+                    auth_callback: PluginAuthenticationCallback::new(&window),
+                    hooks_callback: PluginHooksCallback::new(&window),
+                };
+
+                let response = app_handle
+                    .db()
+                    .upsert_http_response(
+                        &HttpResponse {
+                            request_id: request.id.clone(),
+                            workspace_id: request.workspace_id.clone(),
+                            ..Default::default()
+                        },
+                        &sender.update_source,
+                    )
+                    .map_err(|e| RenderError(e.to_string()))?;
+
+                sender
+                    .send(&request, &response, environment.as_ref(), cookie_jar, &mut cancelled_rx)
+                    .await
+                    .map_err(|e| RenderError(e.to_string()))?
+            }
+            (false, Some(r)) => r,
+            (false, None) => return Ok("".to_string()),
+        };
+
+        if let Some(e) = response.error {
+            return Err(RenderError(format!("Request {} failed: {e}", request.name)));
+        }
+
+        let body = match response.body_path {
+            Some(p) => std::fs::read_to_string(p).map_err(|e| RenderError(e.to_string()))?,
+            None => return Ok("".to_string()),
+        };
+
+        filter_json_path(&body, &path)
+    })
+}
+
+/// Whether to send the request instead of using its latest response, which is `age` old
+fn should_send(purpose: &RenderPurpose, behavior: &str, age: Option<Duration>, ttl: i64) -> bool {
+    match (purpose, behavior) {
+        // Previews render on every keystroke, so they only ever use what's already there
+        (RenderPurpose::Preview, _) | (_, "never") => false,
+        (_, "always") => true,
+        _ => age.is_none_or(|age| ttl > 0 && age > Duration::seconds(ttl)),
+    }
+}
+
+/// Follow `cancelled_rx`, or never cancel without one. The sender is returned so it can be kept
+/// alive for the length of the send, since a closed channel counts as changed.
+fn cancel_receiver(
+    cancelled_rx: Option<&Receiver<bool>>,
+) -> (Option<watch::Sender<bool>>, Receiver<bool>) {
+    match cancelled_rx {
+        Some(rx) => (None, rx.clone()),
+        None => {
+            let (tx, rx) = watch::channel(false);
+            (Some(tx), rx)
+        }
+    }
+}
+
+/// Fail if sending `request_id` would send a request that's already waiting on it
+fn check_request_chain(request_chain: &[String], request_id: &str) -> Result<()> {
+    if request_chain.iter().any(|id| id == request_id) {
+        let mut chain = request_chain.to_vec();
+        chain.push(request_id.to_string());
+        return Err(RenderError(format!("Request chain has a cycle: {}", chain.join(" → "))));
+    }
+    Ok(())
+}
+
+/// Select the first value matching `path`, formatting objects and arrays as JSON
+fn filter_json_path(body: &str, path: &str) -> Result<String> {
+    let json: Value = serde_json::from_str(body)
+        .map_err(|e| RenderError(format!("Response body is not valid JSON: {e}")))?;
+    let path =
+        JsonPath::from_str(path).map_err(|e| RenderError(format!("Invalid JSONPath: {e}")))?;
+
+    let value = match path.find_slice_ptr(&json).first() {
+        None => return Ok("".to_string()),
+        Some(v) => (**v).clone(),
+    };
+
+    Ok(match value {
+        Value::String(s) => s,
+        v => v.to_string(),
+    })
+}
+
 pub fn decrypt_secure_template_function<R: Runtime>(
     app_handle: &AppHandle<R>,
     window_context: &PluginWindowContext,
@@ -162,3 +408,92 @@ pub fn encrypt_secure_template_function<R: Runtime>(
     )?
     .to_string())
 }
+
+#[cfg(test)]
+mod native_template_functions_tests {
+    use crate::events::RenderPurpose;
+    use crate::native_template_functions::{
+        cancel_receiver, check_request_chain, filter_json_path, should_send,
+    };
+    use chrono::Duration;
+    use tokio::sync::watch;
+    use yaak_templates::error::Error::RenderError;
+
+    const BODY: &str = r#"{"user": {"age": 36, "name": "Ada", "tags": ["a", "b"]}}"#;
+
+    #[test]
+    fn filters_strings_without_quotes() {
+        assert_eq!(filter_json_path(BODY, "$.user.name").unwrap(), "Ada");
+    }
+
+    #[test]
+    fn filters_other_values_as_json() {
+        assert_eq!(filter_json_path(BODY, "$.user.age").unwrap(), "36");
+        assert_eq!(filter_json_path(BODY, "$.user.tags").unwrap(), r#"["a","b"]"#);
+        assert_eq!(
+            filter_json_path(BODY, "$.user").unwrap(),
+            r#"{"age":36,"name":"Ada","tags":["a","b"]}"#
+        );
+    }
+
+    #[test]
+    fn filters_first_match() {
+        assert_eq!(filter_json_path(BODY, "$.user.tags[*]").unwrap(), "a");
+    }
+
+    #[test]
+    fn filters_nothing_when_no_match() {
+        assert_eq!(filter_json_path(BODY, "$.user.email").unwrap(), "");
+    }
+
+    #[test]
+    fn fails_for_invalid_path_or_body() {
+        let err = filter_json_path(BODY, "$.user[").unwrap_err().to_string();
+        assert!(err.contains("Invalid JSONPath"), "{err}");
+        let err = filter_json_path("not json", "$.user").unwrap_err().to_string();
+        assert!(err.contains("Response body is not valid JSON"), "{err}");
+    }
+
+    #[test]
+    fn detects_request_chain_cycles() {
+        let chain = vec!["rq_a".to_string(), "rq_b".to_string()];
+        assert!(check_request_chain(&chain, "rq_c").is_ok());
+        let err = check_request_chain(&chain, "rq_a").unwrap_err();
+        assert!(
+            matches!(&err, RenderError(m) if m == "Request chain has a cycle: rq_a → rq_b → rq_a"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn never_cancels_without_a_receiver() {
+        let (_never_cancelled, rx) = cancel_receiver(None);
+        assert_eq!(rx.has_changed().ok(), Some(false));
+
+        let (tx, cancelled_rx) = watch::channel(false);
+        let (_, rx) = cancel_receiver(Some(&cancelled_rx));
+        tx.send(true).unwrap();
+        assert_eq!(rx.has_changed().ok(), Some(true));
+    }
+
+    #[test]
+    fn sends_only_when_the_response_is_missing_or_expired() {
+        let send = RenderPurpose::Send;
+        let fresh = Some(Duration::seconds(5));
+        let stale = Some(Duration::seconds(60));
+        assert!(should_send(&send, "smart", None, 0));
+        assert!(!should_send(&send, "smart", stale, 0));
+        assert!(!should_send(&send, "smart", fresh, 30));
+        assert!(should_send(&send, "smart", stale, 30));
+        assert!(should_send(&send, "always", fresh, 0));
+        assert!(!should_send(&send, "never", None, 0));
+    }
+
+    #[test]
+    fn never_sends_while_previewing() {
+        let preview = RenderPurpose::Preview;
+        assert!(!should_send(&preview, "smart", None, 0));
+        assert!(!should_send(&preview, "smart", Some(Duration::seconds(60)), 30));
+        assert!(!should_send(&preview, "always", Some(Duration::seconds(5)), 0));
+    }
+}
@@ -1,10 +1,12 @@
 use crate::events::{PluginWindowContext, RenderPurpose};
 use crate::manager::PluginManager;
 use crate::native_template_functions::{
-    template_function_secure_run, template_function_secure_transform_arg,
+    template_function_response_body_path_run, template_function_secure_run,
+    template_function_secure_transform_arg,
 };
 use std::collections::HashMap;
 use tauri::{AppHandle, Manager, Runtime};
+use tokio::sync::watch::Receiver;
 use yaak_templates::error::Result;
 use yaak_templates::TemplateCallback;
 
@@ -13,6 +15,10 @@ pub struct PluginTemplateCallback<R: Runtime> {
     app_handle: AppHandle<R>,
     render_purpose: RenderPurpose,
     window_context: PluginWindowContext,
+    /// IDs of requests being sent to resolve the template, used to detect cycles
+    request_chain: Vec<String>,
+    /// Cancels the request being rendered, which also cancels requests sent to render it
+    cancelled_rx: Option<Receiver<bool>>,
 }
 
 impl<R: Runtime> PluginTemplateCallback<R> {
@@ -25,8 +31,22 @@ impl<R: Runtime> PluginTemplateCallback<R> {
             render_purpose,
             app_handle: app_handle.to_owned(),
             window_context: window_context.to_owned(),
+            request_chain: Vec::new(),
+            cancelled_rx: None,
         }
     }
+
+    /// Set the requests that are already being sent to resolve the template being rendered
+    pub fn with_request_chain(mut self, request_chain: Vec<String>) -> Self {
+        self.request_chain = request_chain;
+        self
+    }
+
+    /// Set the channel that cancels the request being rendered
+    pub fn with_cancel(mut self, cancelled_rx: Receiver<bool>) -> Self {
+        self.cancelled_rx = Some(cancelled_rx);
+        self
+    }
 }
 
 impl<R: Runtime> TemplateCallback for PluginTemplateCallback<R> {
@@ -39,6 +59,20 @@ impl<R: Runtime> TemplateCallback for PluginTemplateCallback<R> {
             return template_function_secure_run(&self.app_handle, args, &self.window_context);
         }
 
+        // JSONPath is handled natively, but XPath is still left to the plugin
+        let is_json_path = args.get("path").is_some_and(|p| p.trim_start().starts_with('$'));
+        if (fn_name == "response.body.path" || fn_name == "response") && is_json_path {
+            return template_function_response_body_path_run(
+                &self.app_handle,
+                &self.window_context,
+                &self.render_purpose,
+                &self.request_chain,
+                self.cancelled_rx.as_ref(),
+                args,
+            )
+            .await;
+        }
+
         let plugin_manager = &*self.app_handle.state::<PluginManager>();
         let resp = plugin_manager
             .call_template_function(
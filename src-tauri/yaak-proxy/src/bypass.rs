@@ -0,0 +1,119 @@
+use ipnet::IpNet;
+use std::net::IpAddr;
+
+/// Hosts that skip the proxy, parsed from a comma-separated list in the format of the NO_PROXY
+/// environment variable
+#[derive(Debug, Clone, Default)]
+pub struct Bypass {
+    entries: Vec<Entry>,
+}
+
+#[derive(Debug, Clone)]
+enum Entry {
+    /// `*` matches every host
+    All,
+    /// `<local>` matches plain hostnames, like `localhost` or `intranet`
+    Local,
+    /// An IP address or CIDR range, like `10.0.0.0/8`
+    Net(IpNet),
+    /// A domain and its subdomains, optionally limited to a single port
+    Domain(String, Option<u16>),
+}
+
+impl Bypass {
+    pub fn parse(list: &str) -> Bypass {
+        let entries = list
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .map(|e| e.trim())
+            .filter(|e| !e.is_empty())
+            .map(parse_entry)
+            .collect();
+        Bypass { entries }
+    }
+
+    pub fn matches(&self, host: &str, port: Option<u16>) -> bool {
+        let host = host.trim_start_matches('[').trim_end_matches(']').to_lowercase();
+        let ip = host.parse::<IpAddr>().ok();
+        self.entries.iter().any(|entry| match entry {
+            Entry::All => true,
+            Entry::Local => ip.is_none() && !host.contains('.'),
+            Entry::Net(net) => ip.is_some_and(|ip| net.contains(&ip)),
+            Entry::Domain(domain, entry_port) => {
+                let port_matches = entry_port.is_none() || *entry_port == port;
+                let host_matches = host == *domain || host.ends_with(&format!(".{domain}"));
+                port_matches && host_matches
+            }
+        })
+    }
+}
+
+fn parse_entry(entry: &str) -> Entry {
+    if entry == "*" {
+        return Entry::All;
+    }
+
+    if entry.eq_ignore_ascii_case("<local>") {
+        return Entry::Local;
+    }
+
+    let bare = entry.trim_start_matches('[').trim_end_matches(']');
+    if let Ok(net) = bare.parse::<IpNet>() {
+        return Entry::Net(net);
+    }
+    if let Ok(ip) = bare.parse::<IpAddr>() {
+        return Entry::Net(IpNet::from(ip));
+    }
+
+    // Unbracketed IPv6 addresses were handled above, so a colon can only start a port
+    let (domain, port) = match entry.rsplit_once(':') {
+        Some((d, p)) => match p.parse::<u16>() {
+            Ok(p) => (d, Some(p)),
+            Err(_) => (entry, None),
+        },
+        None => (entry, None),
+    };
+
+    // IPs with a port, like 127.0.0.1:8080 or [::1]:8080, bypass the proxy on every port
+    let bare = domain.trim_start_matches('[').trim_end_matches(']');
+    if let Ok(ip) = bare.parse::<IpAddr>() {
+        return Entry::Net(IpNet::from(ip));
+    }
+
+    let domain = domain.trim_start_matches("*.").trim_start_matches('.').to_lowercase();
+    Entry::Domain(domain, port)
+}
+
+#[cfg(test)]
+mod bypass_tests {
+    use crate::bypass::Bypass;
+
+    #[test]
+    fn matches_domains_and_subdomains() {
+        let bypass = Bypass::parse("example.com, .internal.net,*.corp.io");
+        assert!(bypass.matches("example.com", Some(443)));
+        assert!(bypass.matches("api.example.com", Some(443)));
+        assert!(bypass.matches("a.b.internal.net", None));
+        assert!(bypass.matches("internal.net", None));
+        assert!(bypass.matches("git.corp.io", None));
+        assert!(!bypass.matches("notexample.com", None));
+        assert!(!bypass.matches("example.org", None));
+    }
+
+    #[test]
+    fn matches_ips_ports_and_local() {
+        let bypass = Bypass::parse("10.0.0.0/8 192.168.1.5 ::1 localhost:8080 <local>");
+        assert!(bypass.matches("10.1.2.3", Some(80)));
+        assert!(bypass.matches("192.168.1.5", None));
+        assert!(!bypass.matches("192.168.1.6", None));
+        assert!(bypass.matches("[::1]", Some(443)));
+        assert!(bypass.matches("localhost", Some(8080)));
+        assert!(bypass.matches("intranet", None));
+        assert!(!bypass.matches("example.com", Some(8080)));
+    }
+
+    #[test]
+    fn matches_everything_with_wildcard() {
+        assert!(Bypass::parse("*").matches("example.com", None));
+        assert!(!Bypass::parse("").matches("example.com", None));
+    }
+}
@@ -0,0 +1,163 @@
+use crate::config::ProxyRoute;
+use crate::dns::{DnsOverrides, OverrideResolver};
+use crate::error::Error::{ConnectError, ConnectTimeout, InvalidProxyUrl, UnsupportedProxy};
+use crate::error::Result;
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use http::{HeaderValue, Uri};
+use hyper_util::client::legacy::connect::HttpConnector;
+use hyper_util::client::legacy::connect::proxy::{SocksV4, SocksV5, Tunnel};
+use hyper_util::rt::TokioIo;
+use percent_encoding::percent_decode_str;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tower_service::Service;
+use url::Url;
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Opens TCP connections, going through the route's proxy if it has one. This is for
+/// transports that can't talk to proxies themselves, like gRPC and WebSockets.
+#[derive(Debug, Clone)]
+pub struct ProxyConnector {
+    http: HttpConnector<OverrideResolver>,
+    route: ProxyRoute,
+    timeout: Option<Duration>,
+}
+
+impl ProxyConnector {
+    /// The timeout covers reaching the proxy as well as the proxy reaching the destination.
+    /// Overridden hosts are connected to at their addresses, including the proxy's.
+    pub fn new(route: ProxyRoute, dns_overrides: DnsOverrides, timeout: Option<Duration>) -> Self {
+        let mut http = HttpConnector::new_with_resolver(OverrideResolver::new(dns_overrides));
+        http.enforce_http(false);
+        http.set_nodelay(true);
+        ProxyConnector {
+            http,
+            route,
+            timeout,
+        }
+    }
+}
+
+impl Service<Uri> for ProxyConnector {
+    type Response = TokioIo<TcpStream>;
+    type Error = BoxError;
+    type Future =
+        Pin<Box<dyn Future<Output = std::result::Result<Self::Response, BoxError>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<std::result::Result<(), BoxError>> {
+        self.http.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, dst: Uri) -> Self::Future {
+        let http = self.http.clone();
+        let route = self.route.clone();
+        let timeout = self.timeout;
+        Box::pin(async move {
+            match timeout {
+                None => connect_via(http, &route, dst).await,
+                Some(t) => tokio::time::timeout(t, connect_via(http, &route, dst))
+                    .await
+                    .map_err(|_| ConnectTimeout(t.as_millis()))?,
+            }
+        })
+    }
+}
+
+/// Open a TCP connection to a host, through the route's proxy if it has one
+pub async fn connect(
+    route: &ProxyRoute,
+    dns_overrides: &DnsOverrides,
+    host: &str,
+    port: u16,
+    timeout: Option<Duration>,
+) -> Result<TcpStream> {
+    let dst =
+        format!("http://{host}:{port}").parse::<Uri>().map_err(|e| ConnectError(e.to_string()))?;
+    let stream = ProxyConnector::new(route.clone(), dns_overrides.clone(), timeout)
+        .call(dst)
+        .await
+        .map_err(|e| match e.downcast::<crate::error::Error>() {
+            Ok(e) => *e,
+            Err(e) => ConnectError(e.to_string()),
+        })?;
+    Ok(stream.into_inner())
+}
+
+async fn connect_via(
+    mut http: HttpConnector<OverrideResolver>,
+    route: &ProxyRoute,
+    dst: Uri,
+) -> std::result::Result<TokioIo<TcpStream>, BoxError> {
+    let proxy = match route {
+        ProxyRoute::Direct => return Ok(http.call(dst).await?),
+        ProxyRoute::Proxy(proxy) => proxy,
+    };
+
+    // The proxy connectors assume port 443 when there isn't one
+    let dst = with_port(dst)?;
+
+    let proxy_dst = proxy_address(proxy)?;
+    let user = percent_decode(proxy.username());
+    let password = percent_decode(proxy.password().unwrap_or_default());
+
+    let stream = match proxy.scheme() {
+        "http" => {
+            let mut tunnel = Tunnel::new(proxy_dst, http);
+            if !user.is_empty() {
+                let credentials = BASE64_STANDARD.encode(format!("{user}:{password}"));
+                tunnel = tunnel.with_auth(HeaderValue::from_str(&format!("Basic {credentials}"))?);
+            }
+            tunnel.call(dst).await?
+        }
+        scheme @ ("socks5" | "socks5h") => {
+            let mut socks = SocksV5::new(proxy_dst, http).local_dns(scheme == "socks5");
+            if !user.is_empty() {
+                socks = socks.with_auth(user, password);
+            }
+            socks.call(dst).await?
+        }
+        scheme @ ("socks4" | "socks4a") => {
+            SocksV4::new(proxy_dst, http).local_dns(scheme == "socks4").call(dst).await?
+        }
+        scheme => {
+            return Err(UnsupportedProxy(format!(
+                "scheme {scheme}, only HTTP requests support it"
+            ))
+            .into());
+        }
+    };
+
+    Ok(stream)
+}
+
+/// Address the proxy itself is reached on, without credentials
+fn proxy_address(proxy: &Url) -> Result<Uri> {
+    let host = proxy.host_str().unwrap_or_default();
+    // url only knows the default ports of http and https, so use the usual one for SOCKS
+    let port = proxy.port_or_known_default().unwrap_or(1080);
+    format!("http://{host}:{port}")
+        .parse::<Uri>()
+        .map_err(|e| InvalidProxyUrl(host.to_string(), e.to_string()))
+}
+
+fn with_port(dst: Uri) -> std::result::Result<Uri, BoxError> {
+    if dst.port().is_some() {
+        return Ok(dst);
+    }
+    let port = match dst.scheme_str() {
+        Some("http") | Some("ws") => 80,
+        _ => 443,
+    };
+    let host = dst.host().unwrap_or_default();
+    let scheme = dst.scheme_str().unwrap_or("https");
+    Ok(format!("{scheme}://{host}:{port}").parse::<Uri>()?)
+}
+
+fn percent_decode(value: &str) -> String {
+    percent_decode_str(value).decode_utf8_lossy().to_string()
+}
@@ -0,0 +1,143 @@
+use crate::error::Error::InvalidDnsOverride;
+use crate::error::Result;
+use hyper_util::client::legacy::connect::dns::{GaiResolver, Name};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::future::Future;
+use std::io;
+use std::net::{IpAddr, SocketAddr};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tower_service::Service;
+use yaak_models::models::DnsOverride;
+
+/// Addresses to connect to instead of asking DNS, by lowercase host. Hosts with several entries
+/// resolve to all of their addresses, in order.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct DnsOverrides {
+    hosts: BTreeMap<String, Vec<IpAddr>>,
+}
+
+impl DnsOverrides {
+    /// Collect the enabled overrides, which should already have their templates rendered
+    pub fn new(overrides: &[DnsOverride]) -> Result<DnsOverrides> {
+        let mut hosts: BTreeMap<String, Vec<IpAddr>> = BTreeMap::new();
+        for o in overrides {
+            let host = o.host.trim().to_lowercase();
+            if !o.enabled || host.is_empty() {
+                continue;
+            }
+
+            let address = o.address.trim();
+            let ip = address
+                .trim_start_matches('[')
+                .trim_end_matches(']')
+                .parse::<IpAddr>()
+                .map_err(|e| InvalidDnsOverride(address.to_string(), e.to_string()))?;
+            hosts.entry(host).or_default().push(ip);
+        }
+        Ok(DnsOverrides { hosts })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.hosts.is_empty()
+    }
+
+    /// Addresses to use for a host, if it's overridden. The ports are left at 0 so connectors
+    /// fill in the one from the URL.
+    pub fn lookup(&self, host: &str) -> Option<Vec<SocketAddr>> {
+        let host = host.trim_start_matches('[').trim_end_matches(']').to_lowercase();
+        self.hosts.get(&host).map(|ips| ips.iter().map(|ip| SocketAddr::new(*ip, 0)).collect())
+    }
+
+    /// Each overridden host along with its addresses
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Vec<SocketAddr>)> {
+        self.hosts.iter().map(|(host, ips)| {
+            (host.as_str(), ips.iter().map(|ip| SocketAddr::new(*ip, 0)).collect())
+        })
+    }
+}
+
+/// Resolves overridden hosts to their addresses and everything else with the system resolver
+#[derive(Clone)]
+pub struct OverrideResolver {
+    overrides: DnsOverrides,
+    system: GaiResolver,
+}
+
+impl OverrideResolver {
+    pub fn new(overrides: DnsOverrides) -> Self {
+        OverrideResolver {
+            overrides,
+            system: GaiResolver::new(),
+        }
+    }
+}
+
+impl fmt::Debug for OverrideResolver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OverrideResolver").field("overrides", &self.overrides).finish()
+    }
+}
+
+impl Service<Name> for OverrideResolver {
+    type Response = std::vec::IntoIter<SocketAddr>;
+    type Error = io::Error;
+    type Future =
+        Pin<Box<dyn Future<Output = std::result::Result<Self::Response, io::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<std::result::Result<(), io::Error>> {
+        self.system.poll_ready(cx)
+    }
+
+    fn call(&mut self, name: Name) -> Self::Future {
+        if let Some(addrs) = self.overrides.lookup(name.as_str()) {
+            return Box::pin(async move { Ok(addrs.into_iter()) });
+        }
+
+        let lookup = self.system.call(name);
+        Box::pin(async move { Ok(lookup.await?.collect::<Vec<_>>().into_iter()) })
+    }
+}
+
+#[cfg(test)]
+mod dns_tests {
+    use crate::dns::DnsOverrides;
+    use std::net::SocketAddr;
+    use yaak_models::models::DnsOverride;
+
+    fn entry(host: &str, address: &str) -> DnsOverride {
+        DnsOverride {
+            enabled: true,
+            host: host.to_string(),
+            address: address.to_string(),
+            id: None,
+        }
+    }
+
+    #[test]
+    fn looks_up_enabled_hosts() {
+        let overrides = DnsOverrides::new(&[
+            entry("API.example.com", "10.0.0.1"),
+            entry("api.example.com", "[::1]"),
+            DnsOverride {
+                enabled: false,
+                ..entry("disabled.example.com", "10.0.0.2")
+            },
+            entry("", "10.0.0.3"),
+        ])
+        .unwrap();
+
+        let expected: Vec<SocketAddr> =
+            vec!["10.0.0.1:0".parse().unwrap(), "[::1]:0".parse().unwrap()];
+        assert_eq!(overrides.lookup("api.example.com"), Some(expected));
+        assert_eq!(overrides.lookup("disabled.example.com"), None);
+        assert_eq!(overrides.lookup("example.com"), None);
+    }
+
+    #[test]
+    fn rejects_invalid_addresses() {
+        assert!(DnsOverrides::new(&[entry("example.com", "not-an-ip")]).is_err());
+        assert!(DnsOverrides::new(&[entry("example.com", "")]).is_err());
+    }
+}
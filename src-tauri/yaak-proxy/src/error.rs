@@ -0,0 +1,40 @@
+use serde::{Serialize, Serializer};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Invalid URL {0}: {1}")]
+    InvalidUrl(String, String),
+
+    #[error("Invalid DNS override address {0}: {1}")]
+    InvalidDnsOverride(String, String),
+
+    #[error("Invalid proxy URL {0}: {1}")]
+    InvalidProxyUrl(String, String),
+
+    #[error("Failed to load PAC file {0}: {1}")]
+    PacLoadError(String, String),
+
+    #[error("Failed to run PAC file: {0}")]
+    PacEvalError(String),
+
+    #[error("Unsupported proxy {0}")]
+    UnsupportedProxy(String),
+
+    #[error("Failed to connect through proxy: {0}")]
+    ConnectError(String),
+
+    #[error("Timed out connecting after {0}ms")]
+    ConnectTimeout(u128),
+}
+
+impl Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.to_string().as_ref())
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
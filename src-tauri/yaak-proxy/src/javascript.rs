@@ -0,0 +1,79 @@
+use crate::error::Error::PacEvalError;
+use crate::error::Result;
+use boa_engine::{Context, JsResult, JsString, JsValue, NativeFunction, Source};
+use std::net::{ToSocketAddrs, UdpSocket};
+
+const PAC_UTILS: &str = include_str!("pac_utils.js");
+
+/// Loops in a script are stopped after this many iterations, so one that never returns doesn't
+/// keep a blocking thread busy forever
+const LOOP_ITERATION_LIMIT: u64 = 10_000_000;
+
+/// Run a PAC script's FindProxyForURL function. A new JS context is used for every call, so
+/// scripts can't keep state between them. This blocks on DNS lookups, so don't call it from
+/// async code.
+pub(crate) fn find_proxy_for_url(source: &str, url: &str, host: &str) -> Result<String> {
+    let mut context = Context::default();
+    context.runtime_limits_mut().set_loop_iteration_limit(LOOP_ITERATION_LIMIT);
+    let err = |e: boa_engine::JsError| PacEvalError(e.to_string());
+
+    context
+        .register_global_callable(
+            JsString::from("dnsResolve"),
+            1,
+            NativeFunction::from_fn_ptr(dns_resolve),
+        )
+        .map_err(err)?;
+    context
+        .register_global_callable(
+            JsString::from("myIpAddress"),
+            0,
+            NativeFunction::from_fn_ptr(my_ip_address),
+        )
+        .map_err(err)?;
+
+    context.eval(Source::from_bytes(PAC_UTILS)).map_err(err)?;
+    context.eval(Source::from_bytes(source)).map_err(err)?;
+
+    // JSON strings are valid JS strings, so this takes care of escaping
+    let call = format!(
+        "FindProxyForURL({}, {})",
+        serde_json::to_string(url).unwrap_or_default(),
+        serde_json::to_string(host).unwrap_or_default(),
+    );
+    let result = context.eval(Source::from_bytes(&call)).map_err(err)?;
+    let result = result.to_string(&mut context).map_err(err)?;
+    Ok(result.to_std_string_escaped())
+}
+
+fn dns_resolve(_this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let host = match args.first() {
+        Some(h) => h.to_string(context)?.to_std_string_escaped(),
+        None => return Ok(JsValue::null()),
+    };
+
+    let ip = (host.as_str(), 0)
+        .to_socket_addrs()
+        .ok()
+        .and_then(|mut addrs| addrs.find(|a| a.is_ipv4()))
+        .map(|a| a.ip().to_string());
+
+    Ok(match ip {
+        Some(ip) => JsValue::from(JsString::from(ip.as_str())),
+        None => JsValue::null(),
+    })
+}
+
+fn my_ip_address(_this: &JsValue, _args: &[JsValue], _context: &mut Context) -> JsResult<JsValue> {
+    // Connecting a UDP socket doesn't send anything, but does pick the interface that would
+    // be used to reach the internet
+    let ip = UdpSocket::bind("0.0.0.0:0")
+        .and_then(|s| {
+            s.connect("8.8.8.8:80")?;
+            s.local_addr()
+        })
+        .map(|a| a.ip().to_string())
+        .unwrap_or("127.0.0.1".to_string());
+
+    Ok(JsValue::from(JsString::from(ip.as_str())))
+}
@@ -0,0 +1,7 @@
+pub mod bypass;
+pub mod config;
+pub mod connect;
+pub mod dns;
+pub mod error;
+mod javascript;
+pub mod pac;
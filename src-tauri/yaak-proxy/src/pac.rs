@@ -0,0 +1,230 @@
+use crate::config::ProxyRoute;
+use crate::error::Error::{InvalidProxyUrl, PacEvalError, PacLoadError, UnsupportedProxy};
+use crate::error::Result;
+use crate::javascript::find_proxy_for_url;
+use log::debug;
+use std::collections::HashMap;
+use std::sync::mpsc;
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, Instant};
+use tokio::runtime::{Handle, RuntimeFlavor};
+use url::Url;
+use yaak_tls::config::{TlsSettings, get_config};
+
+/// PAC scripts are fetched again once they're older than this
+const PAC_MAX_AGE: Duration = Duration::from_secs(5 * 60);
+
+/// Loaded scripts by URL, along with when they were loaded
+type PacCache = HashMap<String, (Arc<PacScript>, Instant)>;
+
+static PAC_CACHE: LazyLock<Mutex<PacCache>> = LazyLock::new(Default::default);
+
+/// How long FindProxyForURL may take, since scripts can block on DNS lookups or never return
+const PAC_EVAL_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// What FindProxyForURL returned, by script URL and the URL it was asked about, so the script
+/// only runs once per host (or per URL for plain HTTP) until the script is loaded again
+type PacResultCache = HashMap<(String, String), String>;
+
+static PAC_RESULT_CACHE: LazyLock<Mutex<PacResultCache>> = LazyLock::new(Default::default);
+
+/// A proxy auto-config script, which picks the proxy for each URL with its FindProxyForURL
+/// function
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct PacScript {
+    pub url: String,
+    source: String,
+}
+
+impl PacScript {
+    /// Load the script from an http(s) URL, a file:// URL or a file path
+    pub async fn load(url: &str) -> Result<Arc<PacScript>> {
+        if let Some(script) = cached(url) {
+            return Ok(script);
+        }
+
+        let source = match url.starts_with("http://") || url.starts_with("https://") {
+            true => fetch(url).await,
+            false => {
+                let path = url.strip_prefix("file://").unwrap_or(url);
+                tokio::fs::read_to_string(path).await.map_err(|e| e.to_string())
+            }
+        }
+        .map_err(|e| PacLoadError(url.to_string(), e))?;
+
+        debug!("Loaded PAC file {url}");
+        let script = Arc::new(PacScript {
+            url: url.to_string(),
+            source,
+        });
+
+        if let Ok(mut cache) = PAC_CACHE.lock() {
+            cache.insert(url.to_string(), (script.clone(), Instant::now()));
+        }
+        if let Ok(mut cache) = PAC_RESULT_CACHE.lock() {
+            cache.retain(|(script_url, _), _| script_url != url);
+        }
+
+        Ok(script)
+    }
+
+    pub fn route(&self, url: &Url) -> Result<ProxyRoute> {
+        let host = url.host_str().unwrap_or_default();
+        let host = host.trim_start_matches('[').trim_end_matches(']');
+
+        // Like browsers do, hide the path and query of secure URLs from the script
+        let url = match url.scheme() {
+            "http" | "ws" => url.to_string(),
+            _ => format!("{}/", url.origin().ascii_serialization()),
+        };
+
+        let key = (self.url.clone(), url);
+        if let Some(result) = PAC_RESULT_CACHE.lock().ok().and_then(|c| c.get(&key).cloned()) {
+            return parse_pac_result(&result);
+        }
+
+        let result = self.eval(&key.1, host)?;
+        if let Ok(mut cache) = PAC_RESULT_CACHE.lock() {
+            cache.insert(key, result.clone());
+        }
+        parse_pac_result(&result)
+    }
+
+    /// Run FindProxyForURL on the blocking pool, giving up after PAC_EVAL_TIMEOUT. A script that
+    /// takes longer keeps running there until it finishes, but nothing waits for it.
+    fn eval(&self, url: &str, host: &str) -> Result<String> {
+        let (tx, rx) = mpsc::channel();
+        let (source, url, host) = (self.source.clone(), url.to_string(), host.to_string());
+        tokio::task::spawn_blocking(move || {
+            let _ = tx.send(find_proxy_for_url(&source, &url, &host));
+        });
+
+        // Routes are picked from sync code, like reqwest's proxy callback, so let the runtime
+        // move other tasks off of this worker while it waits
+        let wait = || rx.recv_timeout(PAC_EVAL_TIMEOUT);
+        let received = match Handle::current().runtime_flavor() {
+            RuntimeFlavor::MultiThread => tokio::task::block_in_place(wait),
+            _ => wait(),
+        };
+
+        received.unwrap_or_else(|_| {
+            let secs = PAC_EVAL_TIMEOUT.as_secs();
+            Err(PacEvalError(format!("FindProxyForURL didn't return within {secs}s")))
+        })
+    }
+}
+
+fn cached(url: &str) -> Option<Arc<PacScript>> {
+    let cache = PAC_CACHE.lock().ok()?;
+    match cache.get(url) {
+        Some((script, loaded_at)) if loaded_at.elapsed() < PAC_MAX_AGE => Some(script.clone()),
+        _ => None,
+    }
+}
+
+async fn fetch(url: &str) -> std::result::Result<String, String> {
+    let parsed = Url::parse(url).map_err(|e| e.to_string())?;
+    let tls_config = get_config(
+        &TlsSettings::default(),
+        parsed.host_str().unwrap_or_default(),
+        parsed.port_or_known_default(),
+    )
+    .map_err(|e| e.to_string())?;
+
+    // The PAC file is what says which proxy to use, so it can't be fetched through one
+    let client = reqwest::Client::builder()
+        .no_proxy()
+        .use_preconfigured_tls(tls_config)
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    let response = client.get(url).send().await.map_err(|e| e.to_string())?;
+    let response = response.error_for_status().map_err(|e| e.to_string())?;
+    response.text().await.map_err(|e| e.to_string())
+}
+
+/// Parse what FindProxyForURL returned, like `PROXY proxy:8080; DIRECT`. Only the first entry
+/// is used, since nothing falls back to the next one when a proxy can't be reached.
+pub fn parse_pac_result(result: &str) -> Result<ProxyRoute> {
+    let first = result.split(';').map(str::trim).find(|s| !s.is_empty()).unwrap_or("DIRECT");
+    let mut parts = first.split_whitespace();
+    let kind = parts.next().unwrap_or_default().to_uppercase();
+    if kind == "DIRECT" {
+        return Ok(ProxyRoute::Direct);
+    }
+
+    let address = match parts.next() {
+        Some(a) => a,
+        None => return Err(PacEvalError(format!("Missing proxy address in \"{first}\""))),
+    };
+
+    let scheme = match kind.as_str() {
+        "PROXY" | "HTTP" => "http",
+        "HTTPS" => "https",
+        "SOCKS" | "SOCKS4" => "socks4",
+        // Browsers let SOCKS5 proxies resolve hostnames
+        "SOCKS5" => "socks5h",
+        _ => return Err(UnsupportedProxy(format!("type {kind} in PAC result"))),
+    };
+
+    Url::parse(&format!("{scheme}://{address}"))
+        .map(ProxyRoute::Proxy)
+        .map_err(|e| InvalidProxyUrl(address.to_string(), e.to_string()))
+}
+
+#[cfg(test)]
+mod pac_tests {
+    use crate::config::ProxyRoute;
+    use crate::pac::{PacScript, parse_pac_result};
+    use url::Url;
+
+    fn route(source: &str, url: &str) -> crate::error::Result<ProxyRoute> {
+        let script = PacScript {
+            url: format!("test://{source}"),
+            source: source.to_string(),
+        };
+        let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+        let route = runtime.block_on(async { script.route(&Url::parse(url).unwrap()) });
+        // Don't wait for a script that's still running
+        runtime.shutdown_background();
+        route
+    }
+
+    #[test]
+    fn routes_with_script() {
+        let source = r#"
+            function FindProxyForURL(url, host) {
+                return dnsDomainIs(host, ".corp") ? "PROXY proxy.corp:8080" : "DIRECT";
+            }
+        "#;
+        assert_eq!(
+            route(source, "https://api.corp/a").unwrap(),
+            ProxyRoute::Proxy(Url::parse("http://proxy.corp:8080").unwrap())
+        );
+        assert_eq!(route(source, "https://example.com").unwrap(), ProxyRoute::Direct);
+    }
+
+    #[test]
+    fn stops_scripts_that_never_return() {
+        let source = "function FindProxyForURL(url, host) { while (true) {} }";
+        assert!(route(source, "https://example.com").is_err());
+    }
+
+    #[test]
+    fn parses_first_entry() {
+        let proxy = |u: &str| ProxyRoute::Proxy(Url::parse(u).unwrap());
+        assert_eq!(parse_pac_result("DIRECT").unwrap(), ProxyRoute::Direct);
+        assert_eq!(parse_pac_result("").unwrap(), ProxyRoute::Direct);
+        assert_eq!(
+            parse_pac_result("PROXY proxy.corp:8080; DIRECT").unwrap(),
+            proxy("http://proxy.corp:8080")
+        );
+        assert_eq!(
+            parse_pac_result(" SOCKS5 10.0.0.1:1080 ;PROXY p:80").unwrap(),
+            proxy("socks5h://10.0.0.1:1080")
+        );
+        assert_eq!(parse_pac_result("SOCKS s:1080").unwrap(), proxy("socks4://s:1080"));
+        assert!(parse_pac_result("PROXY").is_err());
+        assert!(parse_pac_result("QUIC q:443").is_err());
+    }
+}
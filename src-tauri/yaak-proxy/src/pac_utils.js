@@ -0,0 +1,49 @@
+// Helper functions that PAC scripts expect to be defined. dnsResolve and myIpAddress are
+// provided natively. The date and time functions (weekdayRange, dateRange, timeRange) are not
+// supported.
+
+function isPlainHostName(host) {
+  return host.indexOf('.') === -1;
+}
+
+function dnsDomainIs(host, domain) {
+  return host.length >= domain.length && host.substring(host.length - domain.length) === domain;
+}
+
+function localHostOrDomainIs(host, hostdom) {
+  return host === hostdom || hostdom.lastIndexOf(host + '.', 0) === 0;
+}
+
+function isResolvable(host) {
+  return dnsResolve(host) !== null;
+}
+
+function isInNet(host, pattern, mask) {
+  var ip = /^\d+\.\d+\.\d+\.\d+$/.test(host) ? host : dnsResolve(host);
+  if (ip === null) {
+    return false;
+  }
+  return (convert_addr(ip) & convert_addr(mask)) === (convert_addr(pattern) & convert_addr(mask));
+}
+
+function dnsDomainLevels(host) {
+  return host.split('.').length - 1;
+}
+
+function convert_addr(ip) {
+  var bytes = ip.split('.');
+  return (
+    ((bytes[0] & 0xff) << 24) |
+    ((bytes[1] & 0xff) << 16) |
+    ((bytes[2] & 0xff) << 8) |
+    (bytes[3] & 0xff)
+  );
+}
+
+function shExpMatch(str, shexp) {
+  var pattern = shexp
+    .replace(/[.+^${}()|[\]\\]/g, '\\$&')
+    .replace(/\*/g, '.*')
+    .replace(/\?/g, '.');
+  return new RegExp('^' + pattern + '$').test(str);
+}
@@ -0,0 +1,180 @@
+use crate::sse::ServerSentEvent;
+
+/// Parses a `text/event-stream` body incrementally, as its chunks arrive. Chunks may end anywhere,
+/// including in the middle of a line or a multi-byte character.
+#[derive(Debug, Default)]
+pub struct EventStreamParser {
+    /// Bytes of the line that hasn't been terminated yet
+    line: Vec<u8>,
+    /// Whether the previous byte was a CR, in which case a LF that follows doesn't end a line
+    after_cr: bool,
+    event_type: String,
+    data: String,
+    id: Option<String>,
+    retry: Option<u64>,
+    last_event_id: Option<String>,
+    reconnection_time: Option<u64>,
+}
+
+impl EventStreamParser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed the next chunk of the body, returning the events that were completed by it
+    pub fn feed(&mut self, bytes: &[u8]) -> Vec<ServerSentEvent> {
+        let mut events = Vec::new();
+        for &b in bytes {
+            match b {
+                b'\n' if self.after_cr => self.after_cr = false,
+                b'\n' | b'\r' => {
+                    self.after_cr = b == b'\r';
+                    let line = std::mem::take(&mut self.line);
+                    if let Some(e) = self.process_line(&String::from_utf8_lossy(&line)) {
+                        events.push(e);
+                    }
+                }
+                b => {
+                    self.after_cr = false;
+                    self.line.push(b);
+                }
+            }
+        }
+        events
+    }
+
+    /// ID of the most recently dispatched event that had one, to resume the stream from when
+    /// reconnecting. Unlike the `id` of each event, this carries over to events without an ID.
+    pub fn last_event_id(&self) -> Option<&str> {
+        self.last_event_id.as_deref()
+    }
+
+    /// Reconnection delay in milliseconds, as last requested by the server with `retry`
+    pub fn reconnection_time(&self) -> Option<u64> {
+        self.reconnection_time
+    }
+
+    fn process_line(&mut self, line: &str) -> Option<ServerSentEvent> {
+        if line.is_empty() {
+            return self.dispatch();
+        }
+
+        // Lines starting with a colon are comments, often sent to keep the connection alive
+        if line.starts_with(':') {
+            return None;
+        }
+
+        let (field, value) = match line.split_once(':') {
+            Some((f, v)) => (f, v.strip_prefix(' ').unwrap_or(v)),
+            None => (line, ""),
+        };
+        match field {
+            "event" => self.event_type = value.to_string(),
+            "data" => {
+                self.data.push_str(value);
+                self.data.push('\n');
+            }
+            "id" if !value.contains('\0') => self.id = Some(value.to_string()),
+            "retry" => {
+                if let Ok(retry) = value.parse() {
+                    self.retry = Some(retry);
+                    self.reconnection_time = Some(retry);
+                }
+            }
+            _ => {}
+        }
+        None
+    }
+
+    /// Finish the pending event. Events without any data are discarded, like browsers do.
+    fn dispatch(&mut self) -> Option<ServerSentEvent> {
+        let event_type = std::mem::take(&mut self.event_type);
+        let id = self.id.take();
+        let retry = self.retry.take();
+        if let Some(id) = &id {
+            self.last_event_id = Some(id.clone());
+        }
+        let mut data = std::mem::take(&mut self.data);
+        if data.is_empty() {
+            return None;
+        }
+
+        data.pop();
+        Some(ServerSentEvent {
+            event_type: if event_type.is_empty() { "message".to_string() } else { event_type },
+            data,
+            id,
+            retry,
+        })
+    }
+}
+
+#[cfg(test)]
+mod parser_tests {
+    use crate::parser::EventStreamParser;
+
+    #[test]
+    fn parses_events_split_across_chunks() {
+        let mut parser = EventStreamParser::new();
+        assert!(parser.feed(b": keep-alive\r\nevent: up").is_empty());
+        assert!(parser.feed(b"date\r").is_empty());
+        let events = parser.feed(b"\nid: 7\ndata: {\"a\":\ndata:1}\nretry: 300\n\ndata: second");
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, "update");
+        assert_eq!(events[0].data, "{\"a\":\n1}");
+        assert_eq!(events[0].id, Some("7".to_string()));
+        assert_eq!(events[0].retry, Some(300));
+
+        let events = parser.feed(b"\r\r");
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, "message");
+        assert_eq!(events[0].data, "second");
+        assert_eq!(events[0].id, None);
+    }
+
+    #[test]
+    fn skips_events_without_data() {
+        let mut parser = EventStreamParser::new();
+        assert!(parser.feed(b"event: ping\n\nid: 1\n\n").is_empty());
+        let events = parser.feed(b"data\n\n");
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].data, "");
+        assert_eq!(events[0].event_type, "message");
+    }
+
+    #[test]
+    fn tracks_last_event_id_and_reconnection_time() {
+        let mut parser = EventStreamParser::new();
+        assert_eq!(parser.last_event_id(), None);
+        parser.feed(
+            b"id: 1
+data: a
+
+data: b
+
+",
+        );
+        assert_eq!(parser.last_event_id(), Some("1"));
+
+        // IDs and delays count even when the event itself is discarded for lacking data
+        parser.feed(
+            b"id: 2
+retry: 500
+
+retry: nope
+
+",
+        );
+        assert_eq!(parser.last_event_id(), Some("2"));
+        assert_eq!(parser.reconnection_time(), Some(500));
+
+        // An empty ID resets it, and an undispatched one doesn't count yet
+        parser.feed(
+            b"id
+
+id: 3
+",
+        );
+        assert_eq!(parser.last_event_id(), Some(""));
+    }
+}
@@ -1,25 +1,108 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
+/**
+ * A client certificate, either as PEM certificate and key files or as a single PKCS#12 file
+ */
+export type ClientCertificate = { enabled?: boolean, 
+/**
+ * Host to use the certificate for, like `api.example.com`, `*.example.com` or `localhost:8443`
+ */
+host: string, crtFile: string | null, keyFile: string | null, pfxFile: string | null, passphrase: string | null, };
+
+/**
+ * An address to connect to for a host, instead of the one from DNS, like curl's `--resolve`.
+ * Both fields may contain templates.
+ */
+export type DnsOverride = { enabled?: boolean, 
+/**
+ * Hostname to override, like `api.example.com`
+ */
+host: string, 
+/**
+ * IPv4 or IPv6 address to connect to instead
+ */
+address: string, id?: string, };
+
 export type Environment = { model: "environment", id: string, workspaceId: string, createdAt: string, updatedAt: string, name: string, public: boolean, base: boolean, variables: Array<EnvironmentVariable>, };
 
 export type EnvironmentVariable = { enabled?: boolean, name: string, value: string, id?: string, };
 
-export type Folder = { model: "folder", id: string, createdAt: string, updatedAt: string, workspaceId: string, folderId: string | null, name: string, description: string, sortPriority: number, };
+export type Folder = { model: "folder", id: string, createdAt: string, updatedAt: string, workspaceId: string, folderId: string | null, authentication: Record<string, any>, authenticationType: string | null, description: string, headers: Array<HttpRequestHeader>, name: string, sortPriority: number, variables: Array<EnvironmentVariable>, settingFollowRedirects: boolean | null, settingMaxResponseSize: number | null, settingRequestTimeout: number | null, settingValidateCertificates: boolean | null, };
 
 export type GrpcMetadataEntry = { enabled?: boolean, name: string, value: string, id?: string, };
 
-export type GrpcRequest = { model: "grpc_request", id: string, createdAt: string, updatedAt: string, workspaceId: string, folderId: string | null, authenticationType: string | null, authentication: Record<string, any>, description: string, message: string, metadata: Array<GrpcMetadataEntry>, method: string | null, name: string, service: string | null, sortPriority: number, url: string, };
+export type GrpcRequest = { model: "grpc_request", id: string, createdAt: string, updatedAt: string, workspaceId: string, folderId: string | null, authenticationType: string | null, authentication: Record<string, any>, description: string, message: string, metadata: Array<GrpcMetadataEntry>, method: string | null, name: string, service: string | null, sortPriority: number, url: string, settingRequestTimeout: number | null, settingValidateCertificates: boolean | null, };
+
+export type HttpAssertionOperator = "equals" | "not_equals" | "contains" | "matches" | "less_than" | "greater_than" | "exists";
+
+export type HttpAssertionSource = "status" | "header" | "json_path" | "elapsed";
+
+export type HttpRequest = { model: "http_request", id: string, createdAt: string, updatedAt: string, workspaceId: string, folderId: string | null, assertions: Array<HttpRequestAssertion>, authentication: Record<string, any>, authenticationType: string | null, body: Record<string, any>, bodyType: string | null, description: string, headers: Array<HttpRequestHeader>, httpVersion: HttpVersion, method: string, name: string, retry: HttpRequestRetry, sortPriority: number, url: string, urlParameters: Array<HttpUrlParameter>, settingFollowRedirects: boolean | null, settingMaxResponseSize: number | null, settingRequestTimeout: number | null, settingValidateCertificates: boolean | null, };
 
-export type HttpRequest = { model: "http_request", id: string, createdAt: string, updatedAt: string, workspaceId: string, folderId: string | null, authentication: Record<string, any>, authenticationType: string | null, body: Record<string, any>, bodyType: string | null, description: string, headers: Array<HttpRequestHeader>, method: string, name: string, sortPriority: number, url: string, urlParameters: Array<HttpUrlParameter>, };
+export type HttpRequestAssertion = { enabled?: boolean, source: HttpAssertionSource, property: string, operator: HttpAssertionOperator, value: string, id?: string, };
 
 export type HttpRequestHeader = { enabled?: boolean, name: string, value: string, id?: string, };
 
+/**
+ * When and how often to re-send a request that failed
+ */
+export type HttpRequestRetry = { enabled: boolean, 
+/**
+ * Total number of attempts, including the first one
+ */
+maxAttempts: number, statusCodes: Array<number>, errors: Array<HttpRetryError>, 
+/**
+ * Delay before the first retry, in milliseconds. It doubles for each retry after that.
+ */
+initialDelay: number, 
+/**
+ * Upper bound for the delay between attempts, in milliseconds
+ */
+maxDelay: number, 
+/**
+ * Wait as long as the server's Retry-After header asks, instead of backing off
+ */
+respectRetryAfter: boolean, };
+
+/**
+ * Transport failures that a request can be retried after
+ */
+export type HttpRetryError = "connect" | "timeout" | "other";
+
 export type HttpUrlParameter = { enabled?: boolean, name: string, value: string, id?: string, };
 
-export type SyncModel = { "type": "workspace" } & Workspace | { "type": "environment" } & Environment | { "type": "folder" } & Folder | { "type": "http_request" } & HttpRequest | { "type": "grpc_request" } & GrpcRequest | { "type": "websocket_request" } & WebsocketRequest;
+/**
+ * HTTP version to send a request with
+ */
+export type HttpVersion = "auto" | "http1" | "http2" | "http3";
+
+export type SseRequest = { model: "sse_request", id: string, createdAt: string, updatedAt: string, workspaceId: string, folderId: string | null, authentication: Record<string, any>, authenticationType: string | null, description: string, headers: Array<HttpRequestHeader>, name: string, sortPriority: number, url: string, urlParameters: Array<HttpUrlParameter>, settingRequestTimeout: number | null, settingValidateCertificates: boolean | null, };
+
+export type SyncModel = { "type": "workspace" } & Workspace | { "type": "environment" } & Environment | { "type": "folder" } & Folder | { "type": "http_request" } & HttpRequest | { "type": "grpc_request" } & GrpcRequest | { "type": "websocket_request" } & WebsocketRequest | { "type": "sse_request" } & SseRequest;
 
 export type SyncState = { model: "sync_state", id: string, workspaceId: string, createdAt: string, updatedAt: string, flushedAt: string, modelId: string, checksum: string, relPath: string, syncDir: string, };
 
-export type WebsocketRequest = { model: "websocket_request", id: string, createdAt: string, updatedAt: string, workspaceId: string, folderId: string | null, authentication: Record<string, any>, authenticationType: string | null, description: string, headers: Array<HttpRequestHeader>, message: string, name: string, sortPriority: number, url: string, urlParameters: Array<HttpUrlParameter>, };
+export type WebsocketRequest = { model: "websocket_request", id: string, createdAt: string, updatedAt: string, workspaceId: string, folderId: string | null, authentication: Record<string, any>, authenticationType: string | null, description: string, headers: Array<HttpRequestHeader>, message: string, name: string, sortPriority: number, url: string, urlParameters: Array<HttpUrlParameter>, settingRequestTimeout: number | null, settingValidateCertificates: boolean | null, };
 
-export type Workspace = { model: "workspace", id: string, createdAt: string, updatedAt: string, name: string, description: string, encryptionKeyChallenge: string | null, settingValidateCertificates: boolean, settingFollowRedirects: boolean, settingRequestTimeout: number, };
+export type Workspace = { model: "workspace", id: string, createdAt: string, updatedAt: string, name: string, description: string, encryptionKeyChallenge: string | null, authentication: Record<string, any>, authenticationType: string | null, headers: Array<HttpRequestHeader>, variables: Array<EnvironmentVariable>, settingValidateCertificates: boolean, settingFollowRedirects: boolean, settingMaxRedirects: number, 
+/**
+ * Switch to GET (dropping the body) when following a 301, 302 or 303, like browsers do
+ */
+settingRedirectRewriteMethod: boolean, settingRequestTimeout: number, 
+/**
+ * Largest response body to store, in bytes. Longer bodies are truncated and their download
+ * stopped. 0 is unlimited.
+ */
+settingMaxResponseSize: number, 
+/**
+ * Certificates to present to servers that ask for one (mutual TLS), matched by host
+ */
+settingClientCertificates: Array<ClientCertificate>, 
+/**
+ * Paths to PEM bundles of extra CAs to trust, on top of the system roots
+ */
+settingCaCertificates: Array<string>, 
+/**
+ * Addresses to connect to instead of the ones DNS returns, by host
+ */
+settingDnsOverrides: Array<DnsOverride>, };
@@ -7,7 +7,8 @@ use std::fs;
 use std::path::Path;
 use ts_rs::TS;
 use yaak_models::models::{
-    AnyModel, Environment, Folder, GrpcRequest, HttpRequest, WebsocketRequest, Workspace,
+    AnyModel, Environment, Folder, GrpcRequest, HttpRequest, SseRequest, WebsocketRequest,
+    Workspace,
 };
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
@@ -20,6 +21,7 @@ pub enum SyncModel {
     HttpRequest(HttpRequest),
     GrpcRequest(GrpcRequest),
     WebsocketRequest(WebsocketRequest),
+    SseRequest(SseRequest),
 }
 
 impl SyncModel {
@@ -77,6 +79,7 @@ impl SyncModel {
             SyncModel::HttpRequest(m) => m.id,
             SyncModel::GrpcRequest(m) => m.id,
             SyncModel::WebsocketRequest(m) => m.id,
+            SyncModel::SseRequest(m) => m.id,
         }
     }
 
@@ -88,6 +91,7 @@ impl SyncModel {
             SyncModel::HttpRequest(m) => m.workspace_id,
             SyncModel::GrpcRequest(m) => m.workspace_id,
             SyncModel::WebsocketRequest(m) => m.workspace_id,
+            SyncModel::SseRequest(m) => m.workspace_id,
         }
     }
 
@@ -99,6 +103,7 @@ impl SyncModel {
             SyncModel::HttpRequest(m) => m.updated_at,
             SyncModel::GrpcRequest(m) => m.updated_at,
             SyncModel::WebsocketRequest(m) => m.updated_at,
+            SyncModel::SseRequest(m) => m.updated_at,
         }
     }
 }
@@ -112,17 +117,22 @@ impl TryFrom<AnyModel> for SyncModel {
             AnyModel::Folder(m) => SyncModel::Folder(m),
             AnyModel::GrpcRequest(m) => SyncModel::GrpcRequest(m),
             AnyModel::HttpRequest(m) => SyncModel::HttpRequest(m),
+            AnyModel::SseRequest(m) => SyncModel::SseRequest(m),
             AnyModel::WebsocketRequest(m) => SyncModel::WebsocketRequest(m),
             AnyModel::Workspace(m) => SyncModel::Workspace(m),
 
             // Non-sync models
+            AnyModel::CollectionRun(m) => return Err(UnknownModel(m.model)),
             AnyModel::CookieJar(m) => return Err(UnknownModel(m.model)),
             AnyModel::GrpcConnection(m) => return Err(UnknownModel(m.model)),
             AnyModel::GrpcEvent(m) => return Err(UnknownModel(m.model)),
             AnyModel::HttpResponse(m) => return Err(UnknownModel(m.model)),
+            AnyModel::HttpResponseEvent(m) => return Err(UnknownModel(m.model)),
             AnyModel::KeyValue(m) => return Err(UnknownModel(m.model)),
             AnyModel::Plugin(m) => return Err(UnknownModel(m.model)),
             AnyModel::Settings(m) => return Err(UnknownModel(m.model)),
+            AnyModel::SseConnection(m) => return Err(UnknownModel(m.model)),
+            AnyModel::SseEvent(m) => return Err(UnknownModel(m.model)),
             AnyModel::WebsocketConnection(m) => return Err(UnknownModel(m.model)),
             AnyModel::WebsocketEvent(m) => return Err(UnknownModel(m.model)),
             AnyModel::WorkspaceMeta(m) => return Err(UnknownModel(m.model)),
@@ -349,6 +349,9 @@ fn workspace_models<R: Runtime>(
     for m in resources.websocket_requests {
         sync_models.push(SyncModel::WebsocketRequest(m));
     }
+    for m in resources.sse_requests {
+        sync_models.push(SyncModel::SseRequest(m));
+    }
 
     Ok(sync_models)
 }
@@ -375,6 +378,7 @@ pub(crate) fn apply_sync_ops<R: Runtime>(
     let mut http_requests_to_upsert = Vec::new();
     let mut grpc_requests_to_upsert = Vec::new();
     let mut websocket_requests_to_upsert = Vec::new();
+    let mut sse_requests_to_upsert = Vec::new();
 
     for op in sync_ops {
         // Only apply things if workspace ID matches
@@ -436,6 +440,7 @@ pub(crate) fn apply_sync_ops<R: Runtime>(
                     SyncModel::GrpcRequest(m) => grpc_requests_to_upsert.push(m),
                     SyncModel::HttpRequest(m) => http_requests_to_upsert.push(m),
                     SyncModel::WebsocketRequest(m) => websocket_requests_to_upsert.push(m),
+                    SyncModel::SseRequest(m) => sse_requests_to_upsert.push(m),
                     SyncModel::Workspace(m) => workspaces_to_upsert.push(m),
                 };
                 SyncStateOp::Create {
@@ -453,6 +458,7 @@ pub(crate) fn apply_sync_ops<R: Runtime>(
                     SyncModel::GrpcRequest(m) => grpc_requests_to_upsert.push(m),
                     SyncModel::HttpRequest(m) => http_requests_to_upsert.push(m),
                     SyncModel::WebsocketRequest(m) => websocket_requests_to_upsert.push(m),
+                    SyncModel::SseRequest(m) => sse_requests_to_upsert.push(m),
                     SyncModel::Workspace(m) => workspaces_to_upsert.push(m),
                 }
                 SyncStateOp::Update {
@@ -479,6 +485,7 @@ pub(crate) fn apply_sync_ops<R: Runtime>(
             http_requests_to_upsert,
             grpc_requests_to_upsert,
             websocket_requests_to_upsert,
+            sse_requests_to_upsert,
             &UpdateSource::Sync,
         )?;
 
@@ -608,6 +615,9 @@ fn delete_model<R: Runtime>(app_handle: &AppHandle<R>, model: &SyncModel) -> Res
         SyncModel::WebsocketRequest(m) => {
             db.delete_websocket_request(&m, &UpdateSource::Sync)?;
         }
+        SyncModel::SseRequest(m) => {
+            db.delete_sse_request(&m, &UpdateSource::Sync)?;
+        }
     };
     Ok(())
 }
@@ -0,0 +1,189 @@
+use crate::error::Error::{InvalidCertificateError, Pkcs12Error, ReadFileError};
+use crate::error::Result;
+use p12_keystore::KeyStore;
+use rustls::crypto::CryptoProvider;
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, PrivatePkcs8KeyDer};
+use rustls::sign::CertifiedKey;
+use std::fs;
+use std::sync::Arc;
+use yaak_models::models::ClientCertificate;
+
+/// A certificate chain and the private key that goes with it
+pub struct ClientIdentity {
+    pub chain: Vec<CertificateDer<'static>>,
+    pub key: PrivateKeyDer<'static>,
+}
+
+/// Find the first enabled client certificate configured for the given host and port
+pub fn find_client_certificate<'a>(
+    certificates: &'a [ClientCertificate],
+    host: &str,
+    port: Option<u16>,
+) -> Option<&'a ClientCertificate> {
+    certificates.iter().find(|c| c.enabled && host_matches(&c.host, host, port))
+}
+
+/// Every enabled client certificate, loaded up front so that one can be picked for each connection
+/// by the host it's to, rather than once for a whole client.
+#[derive(Debug, Default)]
+pub struct ClientIdentities {
+    identities: Vec<(String, Arc<CertifiedKey>)>,
+}
+
+impl ClientIdentities {
+    pub fn load(certificates: &[ClientCertificate], provider: &CryptoProvider) -> Result<Self> {
+        let mut identities = Vec::new();
+        for certificate in certificates.iter().filter(|c| c.enabled) {
+            let identity = load_client_certificate(certificate)?;
+            let key = provider.key_provider.load_private_key(identity.key)?;
+            identities
+                .push((certificate.host.clone(), Arc::new(CertifiedKey::new(identity.chain, key))));
+        }
+        Ok(ClientIdentities { identities })
+    }
+
+    /// The identity of the first certificate configured for the given host and port
+    pub fn find(&self, host: &str, port: Option<u16>) -> Option<Arc<CertifiedKey>> {
+        self.identities.iter().find(|(h, _)| host_matches(h, host, port)).map(|(_, k)| k.clone())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.identities.is_empty()
+    }
+}
+
+/// Load the certificate chain and key, either from a PKCS#12 file or from PEM files. The
+/// passphrase only applies to PKCS#12, since encrypted PEM keys aren't supported.
+pub fn load_client_certificate(certificate: &ClientCertificate) -> Result<ClientIdentity> {
+    if let Some(pfx_file) = non_empty(&certificate.pfx_file) {
+        let passphrase = certificate.passphrase.clone().unwrap_or_default();
+        let store = KeyStore::from_pkcs12(&read_file(pfx_file)?, &passphrase)
+            .map_err(|e| Pkcs12Error(pfx_file.to_string(), e.to_string()))?;
+        let (_, chain) = store
+            .private_key_chain()
+            .ok_or_else(|| Pkcs12Error(pfx_file.to_string(), "no private key found".to_string()))?;
+        return Ok(ClientIdentity {
+            chain: chain
+                .chain()
+                .iter()
+                .map(|c| CertificateDer::from(c.as_der().to_vec()))
+                .collect(),
+            key: PrivatePkcs8KeyDer::from(chain.key().to_vec()).into(),
+        });
+    }
+
+    let (crt_file, key_file) =
+        match (non_empty(&certificate.crt_file), non_empty(&certificate.key_file)) {
+            (Some(crt), Some(key)) => (crt, key),
+            _ => {
+                return Err(InvalidCertificateError(
+                    certificate.host.clone(),
+                    "a certificate and key file, or a PKCS#12 file, is required".to_string(),
+                ));
+            }
+        };
+
+    let chain = read_certificates(crt_file)?;
+    let key = PrivateKeyDer::from_pem_slice(&read_file(key_file)?)
+        .map_err(|e| InvalidCertificateError(key_file.to_string(), e.to_string()))?;
+    Ok(ClientIdentity { chain, key })
+}
+
+/// Load all certificates from the given CA bundles
+pub fn load_ca_certificates(paths: &[String]) -> Result<Vec<CertificateDer<'static>>> {
+    let mut certificates = Vec::new();
+    for path in paths.iter().filter(|p| !p.is_empty()) {
+        certificates.extend(read_certificates(path)?);
+    }
+    Ok(certificates)
+}
+
+/// Read every certificate in a PEM file, falling back to treating it as a single DER certificate
+fn read_certificates(path: &str) -> Result<Vec<CertificateDer<'static>>> {
+    let contents = read_file(path)?;
+    let certificates = CertificateDer::pem_slice_iter(&contents)
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(|e| InvalidCertificateError(path.to_string(), e.to_string()))?;
+    match certificates.is_empty() {
+        true => Ok(vec![CertificateDer::from(contents)]),
+        false => Ok(certificates),
+    }
+}
+
+fn read_file(path: &str) -> Result<Vec<u8>> {
+    fs::read(path).map_err(|e| ReadFileError(path.to_string(), e))
+}
+
+fn non_empty(value: &Option<String>) -> Option<&str> {
+    value.as_deref().filter(|v| !v.is_empty())
+}
+
+/// Match a host like `api.example.com`, `*.example.com` or `localhost:8443` (only that port)
+fn host_matches(pattern: &str, host: &str, port: Option<u16>) -> bool {
+    let pattern = pattern.trim().to_lowercase();
+    let pattern = pattern.split_once("://").map(|(_, p)| p).unwrap_or(&pattern);
+    let pattern = pattern.trim_end_matches('/');
+
+    // IPv6 addresses contain colons too, so only split off a port after the closing bracket
+    let (pattern_host, pattern_port) = match pattern.rsplit_once(':') {
+        Some((h, p)) if !h.contains(':') || h.ends_with(']') => (h, p.parse::<u16>().ok()),
+        _ => (pattern, None),
+    };
+
+    if pattern_port.is_some() && pattern_port != port {
+        return false;
+    }
+
+    let host = host.to_lowercase();
+    match pattern_host.strip_prefix("*.") {
+        Some(domain) => host.ends_with(&format!(".{domain}")),
+        None => pattern_host == host,
+    }
+}
+
+#[cfg(test)]
+mod certificates_tests {
+    use crate::certificates::{find_client_certificate, host_matches};
+    use yaak_models::models::ClientCertificate;
+
+    #[test]
+    fn matches_hosts() {
+        assert!(host_matches("api.example.com", "api.example.com", Some(443)));
+        assert!(host_matches("API.example.com", "api.example.com", Some(443)));
+        assert!(!host_matches("api.example.com", "example.com", Some(443)));
+        assert!(host_matches("*.example.com", "api.example.com", Some(443)));
+        assert!(!host_matches("*.example.com", "example.com", Some(443)));
+        assert!(host_matches("https://api.example.com/", "api.example.com", Some(443)));
+    }
+
+    #[test]
+    fn matches_ports() {
+        assert!(host_matches("localhost:8443", "localhost", Some(8443)));
+        assert!(!host_matches("localhost:8443", "localhost", Some(443)));
+        assert!(host_matches("[::1]:8443", "[::1]", Some(8443)));
+        assert!(host_matches("[::1]", "[::1]", Some(8443)));
+    }
+
+    #[test]
+    fn skips_disabled_certificates() {
+        let certificates = vec![
+            ClientCertificate {
+                enabled: false,
+                host: "example.com".to_string(),
+                crt_file: Some("disabled.crt".to_string()),
+                ..Default::default()
+            },
+            ClientCertificate {
+                enabled: true,
+                host: "example.com".to_string(),
+                crt_file: Some("enabled.crt".to_string()),
+                ..Default::default()
+            },
+        ];
+
+        let found = find_client_certificate(&certificates, "example.com", Some(443));
+        assert_eq!(found.unwrap().crt_file, Some("enabled.crt".to_string()));
+        assert!(find_client_certificate(&certificates, "other.com", Some(443)).is_none());
+    }
+}
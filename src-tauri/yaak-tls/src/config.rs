@@ -0,0 +1,123 @@
+use crate::certificates::{find_client_certificate, load_ca_certificates, load_client_certificate};
+use crate::error::Result;
+use rustls::client::WantsClientCert;
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::crypto::{CryptoProvider, ring};
+use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use rustls::{ClientConfig, ConfigBuilder, DigitallySignedStruct, SignatureScheme};
+use rustls_platform_verifier::Verifier;
+use std::sync::Arc;
+use yaak_models::models::{ClientCertificate, Workspace};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TlsSettings {
+    pub validate_certificates: bool,
+    pub client_certificates: Vec<ClientCertificate>,
+    pub ca_certificates: Vec<String>,
+}
+
+impl Default for TlsSettings {
+    fn default() -> Self {
+        TlsSettings {
+            validate_certificates: true,
+            client_certificates: Vec::new(),
+            ca_certificates: Vec::new(),
+        }
+    }
+}
+
+impl TlsSettings {
+    pub fn from_workspace(workspace: &Workspace) -> Self {
+        TlsSettings {
+            validate_certificates: workspace.setting_validate_certificates,
+            client_certificates: workspace.setting_client_certificates.clone(),
+            ca_certificates: workspace.setting_ca_certificates.clone(),
+        }
+    }
+}
+
+/// The platform-native verifier, additionally trusting any custom CAs
+pub fn platform_verifier(
+    settings: &TlsSettings,
+    provider: Arc<CryptoProvider>,
+) -> Result<Arc<dyn ServerCertVerifier>> {
+    let roots = load_ca_certificates(&settings.ca_certificates)?;
+    let verifier = match roots.is_empty() {
+        true => Verifier::new(),
+        false => Verifier::new_with_extra_roots(roots)?,
+    };
+    Ok(Arc::new(verifier.with_provider(provider)))
+}
+
+/// Finish building the config, presenting the client certificate configured for the host (if any)
+pub fn with_client_auth(
+    builder: ConfigBuilder<ClientConfig, WantsClientCert>,
+    settings: &TlsSettings,
+    host: &str,
+    port: Option<u16>,
+) -> Result<ClientConfig> {
+    match find_client_certificate(&settings.client_certificates, host, port) {
+        None => Ok(builder.with_no_client_auth()),
+        Some(certificate) => {
+            let identity = load_client_certificate(certificate)?;
+            Ok(builder.with_client_auth_cert(identity.chain, identity.key)?)
+        }
+    }
+}
+
+/// Build the TLS config for connecting to the given host
+pub fn get_config(settings: &TlsSettings, host: &str, port: Option<u16>) -> Result<ClientConfig> {
+    let provider = Arc::new(ring::default_provider());
+    let verifier: Arc<dyn ServerCertVerifier> = match settings.validate_certificates {
+        true => platform_verifier(settings, provider.clone())?,
+        false => Arc::new(NoVerifier {
+            provider: provider.clone(),
+        }),
+    };
+    let builder = ClientConfig::builder_with_provider(provider)
+        .with_safe_default_protocol_versions()?
+        .dangerous()
+        .with_custom_certificate_verifier(verifier);
+    with_client_auth(builder, settings, host, port)
+}
+
+/// Accepts any certificate, for when certificate validation is disabled
+#[derive(Debug)]
+struct NoVerifier {
+    provider: Arc<CryptoProvider>,
+}
+
+impl ServerCertVerifier for NoVerifier {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> std::result::Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        _message: &[u8],
+        _cert: &CertificateDer<'_>,
+        _dss: &DigitallySignedStruct,
+    ) -> std::result::Result<HandshakeSignatureValid, rustls::Error> {
+        Ok(HandshakeSignatureValid::assertion())
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        _message: &[u8],
+        _cert: &CertificateDer<'_>,
+        _dss: &DigitallySignedStruct,
+    ) -> std::result::Result<HandshakeSignatureValid, rustls::Error> {
+        Ok(HandshakeSignatureValid::assertion())
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.provider.signature_verification_algorithms.supported_schemes()
+    }
+}
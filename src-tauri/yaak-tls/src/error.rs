@@ -0,0 +1,29 @@
+use serde::{Serialize, Serializer};
+use std::io;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("TLS error: {0}")]
+    RustlsError(#[from] rustls::Error),
+
+    #[error("Failed to read certificate file {0}: {1}")]
+    ReadFileError(String, io::Error),
+
+    #[error("Invalid certificate file {0}: {1}")]
+    InvalidCertificateError(String, String),
+
+    #[error("Failed to open PKCS#12 file {0}: {1}")]
+    Pkcs12Error(String, String),
+}
+
+impl Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.to_string().as_ref())
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
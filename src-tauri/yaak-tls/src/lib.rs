@@ -0,0 +1,3 @@
+pub mod certificates;
+pub mod config;
+pub mod error;
@@ -2,24 +2,25 @@ use crate::error::Result;
 use crate::manager::WebsocketManager;
 use crate::render::render_request;
 use log::{info, warn};
-use std::str::FromStr;
-use tauri::http::{HeaderMap, HeaderName};
+use std::time::Duration;
+use tauri::http::HeaderMap;
 use tauri::{AppHandle, Runtime, State, Url, WebviewWindow};
 use tokio::sync::{Mutex, mpsc};
 use tokio_tungstenite::tungstenite::Message;
-use tokio_tungstenite::tungstenite::http::HeaderValue;
 use yaak_http::apply_path_placeholders;
+use yaak_http::auth::{AuthenticationCallback, AuthenticationRequest, AuthenticationRequestType};
+use yaak_http::render::render_dns_overrides;
 use yaak_models::models::{
     HttpResponseHeader, WebsocketConnection, WebsocketConnectionState, WebsocketEvent,
     WebsocketEventType, WebsocketRequest,
 };
 use yaak_models::query_manager::QueryManagerExt;
 use yaak_models::util::UpdateSource;
-use yaak_plugins::events::{
-    CallHttpAuthenticationRequest, HttpHeader, PluginWindowContext, RenderPurpose,
-};
-use yaak_plugins::manager::PluginManager;
+use yaak_plugins::auth_callback::PluginAuthenticationCallback;
+use yaak_plugins::events::{PluginWindowContext, RenderPurpose};
 use yaak_plugins::template_callback::PluginTemplateCallback;
+use yaak_proxy::config::ProxyConfig;
+use yaak_tls::config::TlsSettings;
 
 #[tauri::command]
 pub(crate) async fn upsert_request<R: Runtime>(
@@ -119,10 +120,15 @@ pub(crate) async fn send<R: Runtime>(
     };
     let base_environment =
         app_handle.db().get_base_environment(&unrendered_request.workspace_id)?;
+    let inherited = app_handle.db().resolve_inherited_settings(
+        &unrendered_request.workspace_id,
+        unrendered_request.folder_id.as_deref(),
+    )?;
     let request = render_request(
         &unrendered_request,
         &base_environment,
         environment.as_ref(),
+        &inherited,
         &PluginTemplateCallback::new(
             &app_handle,
             &PluginWindowContext::new(&window),
@@ -184,7 +190,6 @@ pub(crate) async fn connect<R: Runtime>(
     cookie_jar_id: Option<&str>,
     app_handle: AppHandle<R>,
     window: WebviewWindow<R>,
-    plugin_manager: State<'_, PluginManager>,
     ws_manager: State<'_, Mutex<WebsocketManager>>,
 ) -> Result<WebsocketConnection> {
     let unrendered_request = app_handle.db().get_websocket_request(request_id)?;
@@ -194,10 +199,15 @@ pub(crate) async fn connect<R: Runtime>(
     };
     let base_environment =
         app_handle.db().get_base_environment(&unrendered_request.workspace_id)?;
+    let inherited = app_handle.db().resolve_inherited_settings(
+        &unrendered_request.workspace_id,
+        unrendered_request.folder_id.as_deref(),
+    )?;
     let request = render_request(
         &unrendered_request,
         &base_environment,
         environment.as_ref(),
+        &inherited,
         &PluginTemplateCallback::new(
             &app_handle,
             &PluginWindowContext::new(&window),
@@ -206,32 +216,40 @@ pub(crate) async fn connect<R: Runtime>(
     )
     .await?;
 
+    let (url, url_parameters) = apply_path_placeholders(&request.url, request.url_parameters);
+
+    // Add URL parameters to URL
+    let mut url = Url::parse(&url).unwrap();
+    {
+        let valid_query_pairs = url_parameters
+            .into_iter()
+            .filter(|p| p.enabled && !p.name.is_empty())
+            .collect::<Vec<_>>();
+        // NOTE: Only mutate query pairs if there are any, or it will append an empty `?` to the URL
+        if !valid_query_pairs.is_empty() {
+            let mut query_pairs = url.query_pairs_mut();
+            for p in valid_query_pairs {
+                query_pairs.append_pair(p.name.as_str(), p.value.as_str());
+            }
+        }
+    }
+
+    // Authenticate after building the URL, since auth can sign or modify it
     let mut headers = HeaderMap::new();
     if let Some(auth_name) = request.authentication_type.clone() {
-        let auth = request.authentication.clone();
-        let plugin_req = CallHttpAuthenticationRequest {
+        let auth_req = AuthenticationRequest {
             context_id: format!("{:x}", md5::compute(request_id.to_string())),
-            values: serde_json::from_value(serde_json::to_value(&auth).unwrap()).unwrap(),
-            method: "POST".to_string(),
-            url: request.url.clone(),
-            headers: request
-                .headers
-                .clone()
-                .into_iter()
-                .map(|h| HttpHeader {
-                    name: h.name,
-                    value: h.value,
-                })
-                .collect(),
+            request_type: AuthenticationRequestType::Websocket,
+            values: request.authentication.clone(),
+            method: "GET".to_string(),
+            url: url.to_string(),
+            headers: request.headers.iter().map(|h| (h.name.clone(), h.value.clone())).collect(),
+            body: None,
         };
-        let plugin_result =
-            plugin_manager.call_http_authentication(&window, &auth_name, plugin_req).await?;
-        for header in plugin_result.set_headers {
-            headers.insert(
-                HeaderName::from_str(&header.name).unwrap(),
-                HeaderValue::from_str(&header.value).unwrap(),
-            );
-        }
+        let result =
+            PluginAuthenticationCallback::new(&window).authenticate(&auth_name, auth_req).await?;
+        result.apply_to_url(&mut url)?;
+        result.apply_to_headers(&mut headers)?;
     }
 
     // TODO: Handle cookies
@@ -252,25 +270,42 @@ pub(crate) async fn connect<R: Runtime>(
     let (receive_tx, mut receive_rx) = mpsc::channel::<Message>(128);
     let mut ws_manager = ws_manager.lock().await;
 
-    let (url, url_parameters) = apply_path_placeholders(&request.url, request.url_parameters);
-
-    // Add URL parameters to URL
-    let mut url = Url::parse(&url).unwrap();
-    {
-        let valid_query_pairs = url_parameters
-            .into_iter()
-            .filter(|p| p.enabled && !p.name.is_empty())
-            .collect::<Vec<_>>();
-        // NOTE: Only mutate query pairs if there are any, or it will append an empty `?` to the URL
-        if !valid_query_pairs.is_empty() {
-            let mut query_pairs = url.query_pairs_mut();
-            for p in valid_query_pairs {
-                query_pairs.append_pair(p.name.as_str(), p.value.as_str());
-            }
+    let workspace = inherited.resolve_workspace(
+        &app_handle.db().get_workspace(&request.workspace_id)?,
+        &unrendered_request,
+    );
+    let tls_settings = TlsSettings::from_workspace(&workspace);
+    let proxy = ProxyConfig::load(app_handle.db().get_settings().proxy).await?;
+    let dns_overrides = render_dns_overrides(
+        &workspace,
+        &base_environment,
+        environment.as_ref(),
+        &inherited,
+        &PluginTemplateCallback::new(
+            &app_handle,
+            &PluginWindowContext::new(&window),
+            RenderPurpose::Send,
+        ),
+    )
+    .await?;
+    let timeout = match workspace.setting_request_timeout > 0 {
+        true => {
+            Some(Duration::from_millis(workspace.setting_request_timeout.unsigned_abs() as u64))
         }
-    }
-
-    let response = match ws_manager.connect(&connection.id, url.as_str(), headers, receive_tx).await
+        false => None,
+    };
+    let response = match ws_manager
+        .connect(
+            &connection.id,
+            url.as_str(),
+            headers,
+            receive_tx,
+            &tls_settings,
+            &proxy,
+            &dns_overrides,
+            timeout,
+        )
+        .await
     {
         Ok(r) => r,
         Err(e) => {
@@ -1,8 +1,7 @@
+use crate::error::Error::GenericError;
 use log::info;
-use rustls::crypto::ring;
-use rustls::ClientConfig;
-use rustls_platform_verifier::BuilderVerifierExt;
 use std::sync::Arc;
+use std::time::Duration;
 use tauri::http::HeaderMap;
 use tokio::net::TcpStream;
 use tokio_tungstenite::tungstenite::client::IntoClientRequest;
@@ -10,22 +9,30 @@ use tokio_tungstenite::tungstenite::handshake::client::Response;
 use tokio_tungstenite::tungstenite::http::HeaderValue;
 use tokio_tungstenite::tungstenite::protocol::WebSocketConfig;
 use tokio_tungstenite::{
-    connect_async_tls_with_config, Connector, MaybeTlsStream, WebSocketStream,
+    client_async_tls_with_config, Connector, MaybeTlsStream, WebSocketStream,
 };
+use yaak_proxy::config::ProxyConfig;
+use yaak_proxy::connect::connect;
+use yaak_proxy::dns::DnsOverrides;
+use yaak_tls::config::{get_config, TlsSettings};
 
 pub(crate) async fn ws_connect(
     url: &str,
     headers: HeaderMap<HeaderValue>,
+    tls_settings: &TlsSettings,
+    proxy: &ProxyConfig,
+    dns_overrides: &DnsOverrides,
+    timeout: Option<Duration>,
 ) -> crate::error::Result<(WebSocketStream<MaybeTlsStream<TcpStream>>, Response)> {
     info!("Connecting to WS {url}");
-    let arc_crypto_provider = Arc::new(ring::default_provider());
-    let config = ClientConfig::builder_with_provider(arc_crypto_provider)
-        .with_safe_default_protocol_versions()
-        .unwrap()
-        .with_platform_verifier()
-        .with_no_client_auth();
-
     let mut req = url.into_client_request()?;
+    let port = req.uri().port_u16().or_else(|| match req.uri().scheme_str() {
+        Some("wss") => Some(443),
+        _ => Some(80),
+    });
+    let host = req.uri().host().unwrap_or_default().to_string();
+    let config = get_config(tls_settings, &host, port)?;
+
     let req_headers = req.headers_mut();
     for (name, value) in headers {
         if let Some(name) = name {
@@ -33,12 +40,22 @@ pub(crate) async fn ws_connect(
         }
     }
 
-    let (stream, response) = connect_async_tls_with_config(
+    let route = proxy.route_str(url)?;
+    let tcp_stream = connect(&route, dns_overrides, &host, port.unwrap_or(80), timeout).await?;
+
+    let handshake = client_async_tls_with_config(
         req,
+        tcp_stream,
         Some(WebSocketConfig::default()),
-        false,
         Some(Connector::Rustls(Arc::new(config))),
-    )
-    .await?;
+    );
+
+    // The connect timeout also covers the TLS and upgrade handshakes
+    let (stream, response) = match timeout {
+        None => handshake.await?,
+        Some(t) => tokio::time::timeout(t, handshake).await.map_err(|_| {
+            GenericError(format!("Timed out waiting for handshake after {}ms", t.as_millis()))
+        })??,
+    };
     Ok((stream, response))
 }
\ No newline at end of file
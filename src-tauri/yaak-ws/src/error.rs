@@ -16,6 +16,15 @@ pub enum Error {
     #[error(transparent)]
     TemplateError(#[from] yaak_templates::error::Error),
 
+    #[error(transparent)]
+    TlsError(#[from] yaak_tls::error::Error),
+
+    #[error(transparent)]
+    HttpError(#[from] yaak_http::error::Error),
+
+    #[error(transparent)]
+    ProxyError(#[from] yaak_proxy::error::Error),
+
     #[error("WebSocket error: {0}")]
     GenericError(String),
 }
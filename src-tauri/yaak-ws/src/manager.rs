@@ -5,12 +5,16 @@ use futures_util::{SinkExt, StreamExt};
 use log::{debug, warn};
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::net::TcpStream;
 use tokio::sync::{mpsc, Mutex};
 use tokio_tungstenite::tungstenite::handshake::client::Response;
 use tokio_tungstenite::tungstenite::http::{HeaderMap, HeaderValue};
 use tokio_tungstenite::tungstenite::Message;
 use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+use yaak_proxy::config::ProxyConfig;
+use yaak_proxy::dns::DnsOverrides;
+use yaak_tls::config::TlsSettings;
 
 #[derive(Clone)]
 pub struct WebsocketManager {
@@ -31,12 +35,17 @@ impl WebsocketManager {
         url: &str,
         headers: HeaderMap<HeaderValue>,
         receive_tx: mpsc::Sender<Message>,
+        tls_settings: &TlsSettings,
+        proxy: &ProxyConfig,
+        dns_overrides: &DnsOverrides,
+        timeout: Option<Duration>,
     ) -> Result<Response> {
         let connections = self.connections.clone();
         let connection_id = id.to_string();
         let tx = receive_tx.clone();
 
-        let (stream, response) = ws_connect(url, headers).await?;
+        let (stream, response) =
+            ws_connect(url, headers, tls_settings, proxy, dns_overrides, timeout).await?;
         let (write, mut read) = stream.split();
 
         connections.lock().await.insert(id.to_string(), write);
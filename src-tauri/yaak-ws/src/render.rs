@@ -1,19 +1,20 @@
 use crate::error::Result;
 use std::collections::BTreeMap;
 use yaak_models::models::{Environment, HttpRequestHeader, WebsocketRequest};
-use yaak_models::render::make_vars_hashmap;
+use yaak_models::render::{make_request_vars_hashmap, InheritedSettings};
 use yaak_templates::{parse_and_render, render_json_value_raw, TemplateCallback};
 
 pub async fn render_request<T: TemplateCallback>(
     r: &WebsocketRequest,
     base_environment: &Environment,
     environment: Option<&Environment>,
+    inherited: &InheritedSettings,
     cb: &T,
 ) -> Result<WebsocketRequest> {
-    let vars = &make_vars_hashmap(base_environment, environment);
+    let vars = &make_request_vars_hashmap(base_environment, inherited, environment);
 
     let mut headers = Vec::new();
-    for p in r.headers.clone() {
+    for p in inherited.merge_headers(&r.headers) {
         headers.push(HttpRequestHeader {
             enabled: p.enabled,
             name: parse_and_render(&p.name, vars, cb).await?,
@@ -22,8 +23,10 @@ pub async fn render_request<T: TemplateCallback>(
         })
     }
 
+    let (authentication_type, unrendered_authentication) =
+        inherited.resolve_authentication(&r.authentication_type, &r.authentication);
     let mut authentication = BTreeMap::new();
-    for (k, v) in r.authentication.clone() {
+    for (k, v) in unrendered_authentication {
         authentication.insert(k, render_json_value_raw(v, vars, cb).await?);
     }
 
@@ -35,6 +38,7 @@ pub async fn render_request<T: TemplateCallback>(
         url,
         headers,
         authentication,
+        authentication_type,
         message,
         ..r.to_owned()
     })
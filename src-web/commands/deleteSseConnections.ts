@@ -0,0 +1,10 @@
+import type { SseRequest } from '@yaakapp-internal/models';
+import { createFastMutation } from '../hooks/useFastMutation';
+import { invokeCmd } from '../lib/tauri';
+
+export const deleteSseConnections = createFastMutation({
+  mutationKey: ['delete_sse_connections'],
+  mutationFn: async function (request: SseRequest) {
+    return invokeCmd('cmd_delete_all_sse_connections', { requestId: request.id });
+  },
+});
@@ -0,0 +1,149 @@
+import type { CollectionRunResult } from '@yaakapp-internal/models';
+import { collectionRunsAtom, httpRequestsAtom } from '@yaakapp-internal/models';
+import classNames from 'classnames';
+import { useAtomValue } from 'jotai';
+import { useState } from 'react';
+import { useCancelCollectionRun } from '../hooks/useCancelCollectionRun';
+import { useRunCollection } from '../hooks/useRunCollection';
+import { resolvedModelName } from '../lib/resolvedModelName';
+import { Button } from './core/Button';
+import { Checkbox } from './core/Checkbox';
+import { PlainInput } from './core/PlainInput';
+import { HStack, VStack } from './core/Stacks';
+import {
+  Table,
+  TableBody,
+  TableCell,
+  TableHead,
+  TableHeaderCell,
+  TableRow,
+  TruncatedWideTableCell,
+} from './core/Table';
+import { EmptyStateText } from './EmptyStateText';
+
+interface Props {
+  workspaceId: string;
+  folderId: string | null;
+}
+
+export function CollectionRunDialog({ workspaceId, folderId }: Props) {
+  const [iterations, setIterations] = useState<number>(1);
+  const [delay, setDelay] = useState<number>(0);
+  const [stopOnFailure, setStopOnFailure] = useState<boolean>(false);
+  const runCollection = useRunCollection();
+
+  // Runs are ordered newest first, so this is the latest run of the folder
+  const run = useAtomValue(collectionRunsAtom).find(
+    (r) => r.workspaceId === workspaceId && r.folderId === folderId,
+  );
+  const cancelRun = useCancelCollectionRun(run?.id ?? null);
+  const isRunning = run?.state === 'running';
+  const numPassed = run?.results.filter((r) => r.passed).length ?? 0;
+
+  return (
+    <VStack space={3} className="pb-3">
+      <PlainInput
+        required
+        size="sm"
+        name="iterations"
+        label="Iterations"
+        labelClassName="w-[10rem]"
+        labelPosition="left"
+        defaultValue={`${iterations}`}
+        validate={(value) => parseInt(value) >= 1}
+        onChange={(v) => setIterations(parseInt(v) || 1)}
+        type="number"
+      />
+      <PlainInput
+        required
+        size="sm"
+        name="delay"
+        label="Delay (ms)"
+        labelClassName="w-[10rem]"
+        labelPosition="left"
+        defaultValue={`${delay}`}
+        validate={(value) => parseInt(value) >= 0}
+        onChange={(v) => setDelay(parseInt(v) || 0)}
+        type="number"
+      />
+      <Checkbox
+        checked={stopOnFailure}
+        title="Stop on failure"
+        help="Stop the run after the first request that errors, returns a 4xx or 5xx status or fails an assertion"
+        onChange={setStopOnFailure}
+      />
+      <HStack space={2} justifyContent="end">
+        {isRunning && (
+          <Button variant="border" size="sm" onClick={() => cancelRun.mutate()}>
+            Cancel
+          </Button>
+        )}
+        <Button
+          color="primary"
+          size="sm"
+          isLoading={isRunning}
+          disabled={isRunning}
+          onClick={() =>
+            runCollection.mutate({ workspaceId, folderId, iterations, delay, stopOnFailure })
+          }
+        >
+          Run
+        </Button>
+      </HStack>
+      {run == null ? (
+        <EmptyStateText>No runs yet</EmptyStateText>
+      ) : (
+        <>
+          <div className="text-sm text-text-subtle">
+            {numPassed} of {run.results.length} passed in {run.elapsed}ms
+            {run.state === 'cancelled' && ' (cancelled)'}
+          </div>
+          <Table>
+            <TableHead>
+              <TableRow>
+                <TableHeaderCell>Request</TableHeaderCell>
+                {run.iterations > 1 && <TableHeaderCell>Iteration</TableHeaderCell>}
+                <TableHeaderCell>Status</TableHeaderCell>
+                <TableHeaderCell>Time</TableHeaderCell>
+              </TableRow>
+            </TableHead>
+            <TableBody>
+              {run.results.map((r, i) => (
+                <CollectionRunResultRow
+                  key={`${r.responseId}.${i}`}
+                  result={r}
+                  showIteration={run.iterations > 1}
+                />
+              ))}
+            </TableBody>
+          </Table>
+        </>
+      )}
+    </VStack>
+  );
+}
+
+function CollectionRunResultRow({
+  result,
+  showIteration,
+}: {
+  result: CollectionRunResult;
+  showIteration: boolean;
+}) {
+  const request = useAtomValue(httpRequestsAtom).find((r) => r.id === result.requestId);
+  return (
+    <TableRow>
+      <TruncatedWideTableCell>
+        {request == null ? 'Deleted Request' : resolvedModelName(request)}
+        {result.error && <span className="text-danger"> ({result.error})</span>}
+      </TruncatedWideTableCell>
+      {showIteration && <TableCell>{result.iteration + 1}</TableCell>}
+      <TableCell
+        className={classNames('font-mono', result.passed ? 'text-success' : 'text-danger')}
+      >
+        {result.status > 0 ? result.status : 'ERROR'}
+      </TableCell>
+      <TableCell className="font-mono">{result.elapsed}ms</TableCell>
+    </TableRow>
+  );
+}
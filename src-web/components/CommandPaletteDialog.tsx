@@ -105,6 +105,11 @@ export function CommandPaletteDialog({ onClose }: { onClose: () => void }) {
         label: 'Create Websocket Request',
         onSelect: () => createRequestAndNavigate({ model: 'websocket_request', workspaceId }),
       },
+      {
+        key: 'sse_request.create',
+        label: 'Create Server-Sent Events Request',
+        onSelect: () => createRequestAndNavigate({ model: 'sse_request', workspaceId }),
+      },
       {
         key: 'folder.create',
         label: 'Create Folder',
@@ -1,8 +1,11 @@
 import { foldersAtom, patchModel } from '@yaakapp-internal/models';
 import { useAtomValue } from 'jotai';
+import { Heading } from './core/Heading';
 import { Input } from './core/Input';
+import { Separator } from './core/Separator';
 import { VStack } from './core/Stacks';
 import { MarkdownEditor } from './MarkdownEditor';
+import { RequestSettingsEditor } from './RequestSettingsEditor';
 
 interface Props {
   folderId: string | null;
@@ -31,6 +34,10 @@ export function FolderSettingsDialog({ folderId }: Props) {
         stateKey={`description.${folder.id}`}
         onChange={(description) => patchModel(folder, { description })}
       />
+
+      <Separator className="my-2" />
+      <Heading level={2}>Request Settings</Heading>
+      <RequestSettingsEditor model={folder} />
     </VStack>
   );
 }
@@ -5,6 +5,7 @@ import type {
   Folder,
   GrpcRequest,
   HttpRequest,
+  SseRequest,
   WebsocketRequest,
   Workspace,
 } from '@yaakapp-internal/models';
@@ -32,7 +33,14 @@ interface Props {
 }
 
 interface TreeNode {
-  model: HttpRequest | GrpcRequest | WebsocketRequest | Folder | Environment | Workspace;
+  model:
+    | HttpRequest
+    | GrpcRequest
+    | WebsocketRequest
+    | SseRequest
+    | Folder
+    | Environment
+    | Workspace;
   status: GitStatusEntry;
   children: TreeNode[];
   ancestors: TreeNode[];
@@ -237,7 +245,8 @@ function TreeNodeChildren({
             <div className="grid grid-cols-[auto_minmax(0,1fr)_auto] gap-1 w-full items-center">
               {node.model.model !== 'http_request' &&
               node.model.model !== 'grpc_request' &&
-              node.model.model !== 'websocket_request' ? (
+              node.model.model !== 'websocket_request' &&
+              node.model.model !== 'sse_request' ? (
                 <Icon
                   color="secondary"
                   icon={
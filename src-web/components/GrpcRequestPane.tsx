@@ -21,6 +21,7 @@ import { TabContent, Tabs } from './core/Tabs/Tabs';
 import { GrpcEditor } from './GrpcEditor';
 import { HttpAuthenticationEditor } from './HttpAuthenticationEditor';
 import { MarkdownEditor } from './MarkdownEditor';
+import { RequestSettingsEditor } from './RequestSettingsEditor';
 import { UrlBar } from './UrlBar';
 
 interface Props {
@@ -48,6 +49,7 @@ interface Props {
 const TAB_MESSAGE = 'message';
 const TAB_METADATA = 'metadata';
 const TAB_AUTH = 'auth';
+const TAB_SETTINGS = 'settings';
 const TAB_DESCRIPTION = 'description';
 
 export function GrpcRequestPane({
@@ -142,7 +144,8 @@ export function GrpcRequestPane({
               value: a.name,
             })),
             { type: 'separator' },
-            { label: 'No Authentication', shortLabel: 'Auth', value: null },
+            { label: 'Inherit from Parent', shortLabel: 'Auth', value: null },
+            { label: 'No Authentication', shortLabel: 'No Auth', value: 'none' },
           ],
           onChange: async (authenticationType) => {
             let authentication: GrpcRequest['authentication'] = activeRequest.authentication;
@@ -159,6 +162,7 @@ export function GrpcRequestPane({
         },
       },
       { value: TAB_METADATA, label: 'Metadata' },
+      { value: TAB_SETTINGS, label: 'Settings' },
       {
         value: TAB_DESCRIPTION,
         label: 'Info',
@@ -320,6 +324,9 @@ export function GrpcRequestPane({
             stateKey={`grpc_metadata.${activeRequest.id}`}
           />
         </TabContent>
+        <TabContent value={TAB_SETTINGS}>
+          <RequestSettingsEditor model={activeRequest} />
+        </TabContent>
         <TabContent value={TAB_DESCRIPTION}>
           <div className="grid grid-rows-[auto_minmax(0,1fr)] h-full">
             <PlainInput
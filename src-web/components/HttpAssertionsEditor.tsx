@@ -0,0 +1,141 @@
+import type {
+  HttpAssertionOperator,
+  HttpAssertionSource,
+  HttpRequest,
+  HttpRequestAssertion,
+} from '@yaakapp-internal/models';
+import { patchModel } from '@yaakapp-internal/models';
+import { generateId } from '../lib/generateId';
+import { Button } from './core/Button';
+import { Checkbox } from './core/Checkbox';
+import { IconButton } from './core/IconButton';
+import { PlainInput } from './core/PlainInput';
+import { Select } from './core/Select';
+import { HStack, VStack } from './core/Stacks';
+import { EmptyStateText } from './EmptyStateText';
+
+interface Props {
+  request: HttpRequest;
+}
+
+const sources: { label: string; value: HttpAssertionSource }[] = [
+  { label: 'Status', value: 'status' },
+  { label: 'Header', value: 'header' },
+  { label: 'JSONPath', value: 'json_path' },
+  { label: 'Time (ms)', value: 'elapsed' },
+];
+
+const operators: { label: string; value: HttpAssertionOperator }[] = [
+  { label: 'Equals', value: 'equals' },
+  { label: 'Not Equals', value: 'not_equals' },
+  { label: 'Contains', value: 'contains' },
+  { label: 'Matches Regex', value: 'matches' },
+  { label: 'Less Than', value: 'less_than' },
+  { label: 'Greater Than', value: 'greater_than' },
+  { label: 'Exists', value: 'exists' },
+];
+
+export function HttpAssertionsEditor({ request }: Props) {
+  const assertions = request.assertions;
+
+  const patchAssertion = (index: number, patch: Partial<HttpRequestAssertion>) =>
+    patchModel(request, {
+      assertions: assertions.map((a, i) => (i === index ? { ...a, ...patch } : a)),
+    });
+
+  const addAssertion = () =>
+    patchModel(request, {
+      assertions: [
+        ...assertions,
+        {
+          enabled: true,
+          source: 'status',
+          property: '',
+          operator: 'equals',
+          value: '200',
+          id: generateId(),
+        },
+      ],
+    });
+
+  const deleteAssertion = (index: number) =>
+    patchModel(request, { assertions: assertions.filter((_, i) => i !== index) });
+
+  return (
+    <VStack space={2} className="pb-3">
+      {assertions.length === 0 && (
+        <EmptyStateText>Check the status, headers, body or time of each response</EmptyStateText>
+      )}
+      {assertions.map((a, i) => {
+        const key = `${request.id}.${a.id ?? i}`;
+        const hasProperty = a.source === 'header' || a.source === 'json_path';
+        return (
+          <HStack key={key} space={2}>
+            <Checkbox
+              hideLabel
+              title="Enable assertion"
+              checked={a.enabled ?? true}
+              onChange={(enabled) => patchAssertion(i, { enabled })}
+            />
+            <Select
+              hideLabel
+              size="sm"
+              name={`source.${key}`}
+              label="Source"
+              className="!w-auto"
+              value={a.source}
+              options={sources}
+              onChange={(source) => patchAssertion(i, { source })}
+            />
+            {hasProperty && (
+              <PlainInput
+                hideLabel
+                size="sm"
+                name={`property.${key}`}
+                label={a.source === 'header' ? 'Header Name' : 'JSONPath'}
+                placeholder={a.source === 'header' ? 'Content-Type' : '$.data.id'}
+                defaultValue={a.property}
+                forceUpdateKey={`${key}.${a.source}`}
+                onChange={(property) => patchAssertion(i, { property })}
+              />
+            )}
+            <Select
+              hideLabel
+              size="sm"
+              name={`operator.${key}`}
+              label="Operator"
+              className="!w-auto"
+              value={a.operator}
+              options={operators}
+              onChange={(operator) => patchAssertion(i, { operator })}
+            />
+            {a.operator !== 'exists' && (
+              <PlainInput
+                hideLabel
+                size="sm"
+                name={`value.${key}`}
+                label="Expected Value"
+                placeholder="Expected value"
+                defaultValue={a.value}
+                forceUpdateKey={key}
+                onChange={(value) => patchAssertion(i, { value })}
+              />
+            )}
+            <IconButton
+              size="xs"
+              iconSize="sm"
+              icon="trash"
+              title="Delete assertion"
+              onClick={() => deleteAssertion(i)}
+            />
+          </HStack>
+        );
+      })}
+      <div>
+        <Button variant="border" size="xs" onClick={addAssertion}>
+          Add Assertion
+        </Button>
+      </div>
+    </VStack>
+  );
+}
@@ -1,4 +1,9 @@
-import type { GrpcRequest, HttpRequest, WebsocketRequest } from '@yaakapp-internal/models';
+import type {
+  GrpcRequest,
+  HttpRequest,
+  SseRequest,
+  WebsocketRequest,
+} from '@yaakapp-internal/models';
 import { patchModel } from '@yaakapp-internal/models';
 import React, { useCallback } from 'react';
 import { useHttpAuthenticationConfig } from '../hooks/useHttpAuthenticationConfig';
@@ -12,14 +17,14 @@ import { DynamicForm } from './DynamicForm';
 import { EmptyStateText } from './EmptyStateText';
 
 interface Props {
-  request: HttpRequest | GrpcRequest | WebsocketRequest;
+  request: HttpRequest | GrpcRequest | WebsocketRequest | SseRequest;
 }
 
 export function HttpAuthenticationEditor({ request }: Props) {
   const authConfig = useHttpAuthenticationConfig(
     request.authenticationType,
     request.authentication,
-    request.id,
+    request,
   );
 
   const handleChange = useCallback(
@@ -27,6 +32,14 @@ export function HttpAuthenticationEditor({ request }: Props) {
     [request],
   );
 
+  if (request.authenticationType == null) {
+    return <EmptyStateText>Authentication is inherited from the folder or workspace</EmptyStateText>;
+  }
+
+  if (request.authenticationType === 'none') {
+    return <EmptyStateText>No Authentication</EmptyStateText>;
+  }
+
   if (authConfig.data == null) {
     return <EmptyStateText>No Authentication {request.authenticationType}</EmptyStateText>;
   }
@@ -39,6 +39,7 @@ import type { GenericCompletionConfig } from './core/Editor/genericCompletion';
 import { InlineCode } from './core/InlineCode';
 import type { Pair } from './core/PairEditor';
 import { PlainInput } from './core/PlainInput';
+import { Separator } from './core/Separator';
 import type { TabItem } from './core/Tabs/Tabs';
 import { TabContent, Tabs } from './core/Tabs/Tabs';
 import { EmptyStateText } from './EmptyStateText';
@@ -46,8 +47,11 @@ import { FormMultipartEditor } from './FormMultipartEditor';
 import { FormUrlencodedEditor } from './FormUrlencodedEditor';
 import { GraphQLEditor } from './GraphQLEditor';
 import { HeadersEditor } from './HeadersEditor';
+import { HttpAssertionsEditor } from './HttpAssertionsEditor';
 import { HttpAuthenticationEditor } from './HttpAuthenticationEditor';
+import { HttpRetryEditor } from './HttpRetryEditor';
 import { MarkdownEditor } from './MarkdownEditor';
+import { RequestSettingsEditor } from './RequestSettingsEditor';
 import { UrlBar } from './UrlBar';
 import { UrlParametersEditor } from './UrlParameterEditor';
 
@@ -62,6 +66,8 @@ const TAB_BODY = 'body';
 const TAB_PARAMS = 'params';
 const TAB_HEADERS = 'headers';
 const TAB_AUTH = 'auth';
+const TAB_ASSERTIONS = 'assertions';
+const TAB_SETTINGS = 'settings';
 const TAB_DESCRIPTION = 'description';
 
 const nonActiveRequestUrlsAtom = atom((get) => {
@@ -231,7 +237,8 @@ export function HttpRequestPane({ style, fullHeight, className, activeRequest }:
               value: a.name,
             })),
             { type: 'separator' },
-            { label: 'No Authentication', shortLabel: 'Auth', value: null },
+            { label: 'Inherit from Parent', shortLabel: 'Auth', value: null },
+            { label: 'No Authentication', shortLabel: 'No Auth', value: 'none' },
           ],
           onChange: async (authenticationType) => {
             let authentication: HttpRequest['authentication'] = activeRequest.authentication;
@@ -244,6 +251,14 @@ export function HttpRequestPane({ style, fullHeight, className, activeRequest }:
           },
         },
       },
+      {
+        value: TAB_ASSERTIONS,
+        label: 'Assertions',
+        rightSlot: (
+          <CountBadge count={activeRequest.assertions.filter((a) => a.enabled !== false).length} />
+        ),
+      },
+      { value: TAB_SETTINGS, label: 'Settings' },
       {
         value: TAB_DESCRIPTION,
         label: 'Info',
@@ -458,6 +473,14 @@ export function HttpRequestPane({ style, fullHeight, className, activeRequest }:
                 <EmptyStateText>No Body</EmptyStateText>
               )}
             </TabContent>
+            <TabContent value={TAB_ASSERTIONS}>
+              <HttpAssertionsEditor request={activeRequest} />
+            </TabContent>
+            <TabContent value={TAB_SETTINGS}>
+              <RequestSettingsEditor model={activeRequest} />
+              <Separator className="my-2" />
+              <HttpRetryEditor request={activeRequest} />
+            </TabContent>
             <TabContent value={TAB_DESCRIPTION}>
               <div className="grid grid-rows-[auto_minmax(0,1fr)] h-full">
                 <PlainInput
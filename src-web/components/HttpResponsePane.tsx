@@ -20,8 +20,10 @@ import type { TabItem } from './core/Tabs/Tabs';
 import { TabContent, Tabs } from './core/Tabs/Tabs';
 import { EmptyStateText } from './EmptyStateText';
 import { RecentHttpResponsesDropdown } from './RecentHttpResponsesDropdown';
+import { ResponseAssertions } from './ResponseAssertions';
 import { ResponseHeaders } from './ResponseHeaders';
 import { ResponseInfo } from './ResponseInfo';
+import { ResponseRequest } from './ResponseRequest';
 import { AudioViewer } from './responseViewers/AudioViewer';
 import { CsvViewer } from './responseViewers/CsvViewer';
 import { EventStreamViewer } from './responseViewers/EventStreamViewer';
@@ -40,7 +42,9 @@ interface Props {
 
 const TAB_BODY = 'body';
 const TAB_HEADERS = 'headers';
+const TAB_REQUEST = 'request';
 const TAB_INFO = 'info';
+const TAB_ASSERTIONS = 'assertions';
 
 export function HttpResponsePane({ style, className, activeRequestId }: Props) {
   const { activeResponse, setPinnedResponseId, responses } = usePinnedHttpResponse(activeRequestId);
@@ -52,6 +56,8 @@ export function HttpResponsePane({ style, className, activeRequestId }: Props) {
   const contentType = getContentTypeFromHeaders(activeResponse?.headers ?? null);
   const mimeType = contentType == null ? null : getMimeTypeFromContentType(contentType).essence;
 
+  const failedAssertions = activeResponse?.assertionResults.filter((r) => !r.passed).length ?? 0;
+
   const tabs = useMemo<TabItem[]>(
     () => [
       {
@@ -75,12 +81,33 @@ export function HttpResponsePane({ style, className, activeRequestId }: Props) {
           />
         ),
       },
+      {
+        value: TAB_REQUEST,
+        label: 'Request',
+      },
+      {
+        value: TAB_ASSERTIONS,
+        label: 'Assertions',
+        rightSlot: (
+          <CountBadge
+            className={failedAssertions > 0 ? '!text-danger' : undefined}
+            count={activeResponse?.assertionResults.length ?? 0}
+          />
+        ),
+      },
       {
         value: TAB_INFO,
         label: 'Info',
       },
     ],
-    [activeResponse?.headers, mimeType, setViewMode, viewMode],
+    [
+      activeResponse?.assertionResults.length,
+      activeResponse?.headers,
+      failedAssertions,
+      mimeType,
+      setViewMode,
+      viewMode,
+    ],
   );
   const activeTab = activeTabs?.[activeRequestId];
   const setActiveTab = useCallback(
@@ -124,11 +151,31 @@ export function HttpResponsePane({ style, className, activeRequestId }: Props) {
                 )}
               >
                 {activeResponse.state !== 'closed' && <LoadingIcon size="sm" />}
+                {activeResponse.state === 'initialized' && activeResponse.uploadSize != null && (
+                  <>
+                    <span>
+                      Uploading <SizeTag contentLength={activeResponse.bytesUploaded} /> of{' '}
+                      <SizeTag contentLength={activeResponse.uploadSize} />
+                    </span>
+                    <span>&bull;</span>
+                  </>
+                )}
                 <HttpStatusTag showReason response={activeResponse} />
                 <span>&bull;</span>
                 <HttpResponseDurationTag response={activeResponse} />
                 <span>&bull;</span>
                 <SizeTag contentLength={activeResponse.contentLength ?? 0} />
+                {activeResponse.truncated && (
+                  <>
+                    <span>&bull;</span>
+                    <span
+                      className="text-warning"
+                      title="The body was longer than the max response size, so only part of it was stored"
+                    >
+                      Truncated
+                    </span>
+                  </>
+                )}
 
                 <div className="ml-auto">
                   <RecentHttpResponsesDropdown
@@ -191,6 +238,12 @@ export function HttpResponsePane({ style, className, activeRequestId }: Props) {
               <TabContent value={TAB_HEADERS}>
                 <ResponseHeaders response={activeResponse} />
               </TabContent>
+              <TabContent value={TAB_REQUEST}>
+                <ResponseRequest response={activeResponse} />
+              </TabContent>
+              <TabContent value={TAB_ASSERTIONS}>
+                <ResponseAssertions response={activeResponse} />
+              </TabContent>
               <TabContent value={TAB_INFO}>
                 <ResponseInfo response={activeResponse} />
               </TabContent>
@@ -0,0 +1,111 @@
+import type { HttpRequest, HttpRequestRetry, HttpRetryError } from '@yaakapp-internal/models';
+import { patchModel } from '@yaakapp-internal/models';
+import { Checkbox } from './core/Checkbox';
+import { PlainInput } from './core/PlainInput';
+import { HStack, VStack } from './core/Stacks';
+
+interface Props {
+  request: HttpRequest;
+}
+
+const retryErrors: { error: HttpRetryError; title: string }[] = [
+  { error: 'connect', title: 'Connection failures' },
+  { error: 'timeout', title: 'Timeouts' },
+  { error: 'other', title: 'Other errors' },
+];
+
+export function HttpRetryEditor({ request }: Props) {
+  const retry = request.retry;
+  const patchRetry = (patch: Partial<HttpRequestRetry>) =>
+    patchModel(request, { retry: { ...retry, ...patch } });
+
+  return (
+    <VStack space={3} className="pb-3">
+      <Checkbox
+        checked={retry.enabled}
+        title="Retry failed requests"
+        help="Re-send the request when it fails with one of the status codes or errors below, waiting longer between each attempt"
+        onChange={(enabled) => patchRetry({ enabled })}
+      />
+      {retry.enabled && (
+        <>
+          <PlainInput
+            required
+            size="sm"
+            name="maxAttempts"
+            label="Max Attempts"
+            labelClassName="w-[14rem]"
+            labelPosition="left"
+            defaultValue={`${retry.maxAttempts}`}
+            validate={(value) => parseInt(value) >= 1}
+            onChange={(v) => patchRetry({ maxAttempts: parseInt(v) || 1 })}
+            type="number"
+          />
+          <PlainInput
+            size="sm"
+            name="statusCodes"
+            label="Retry Status Codes"
+            labelClassName="w-[14rem]"
+            labelPosition="left"
+            placeholder="429, 502, 503"
+            defaultValue={retry.statusCodes.join(', ')}
+            onChange={(v) =>
+              patchRetry({
+                statusCodes: v
+                  .split(',')
+                  .map((c) => parseInt(c.trim()))
+                  .filter((c) => !isNaN(c)),
+              })
+            }
+          />
+          <HStack space={3}>
+            {retryErrors.map(({ error, title }) => (
+              <Checkbox
+                key={error}
+                checked={retry.errors.includes(error)}
+                title={title}
+                onChange={(checked) =>
+                  patchRetry({
+                    errors: checked
+                      ? [...retry.errors, error]
+                      : retry.errors.filter((e) => e !== error),
+                  })
+                }
+              />
+            ))}
+          </HStack>
+          <PlainInput
+            required
+            size="sm"
+            name="initialDelay"
+            label="Initial Delay (ms)"
+            labelClassName="w-[14rem]"
+            labelPosition="left"
+            defaultValue={`${retry.initialDelay}`}
+            validate={(value) => parseInt(value) >= 0}
+            onChange={(v) => patchRetry({ initialDelay: parseInt(v) || 0 })}
+            type="number"
+          />
+          <PlainInput
+            required
+            size="sm"
+            name="maxDelay"
+            label="Max Delay (ms)"
+            labelClassName="w-[14rem]"
+            labelPosition="left"
+            defaultValue={`${retry.maxDelay}`}
+            validate={(value) => parseInt(value) >= 0}
+            onChange={(v) => patchRetry({ maxDelay: parseInt(v) || 0 })}
+            type="number"
+          />
+          <Checkbox
+            checked={retry.respectRetryAfter}
+            title="Respect Retry-After"
+            help="Wait as long as the server asks for with the Retry-After header. Requests aren't retried when that's longer than the max delay."
+            onChange={(respectRetryAfter) => patchRetry({ respectRetryAfter })}
+          />
+        </>
+      )}
+    </VStack>
+  );
+}
@@ -1,6 +1,7 @@
 import type {
   GrpcRequest,
   HttpRequest,
+  SseRequest,
   WebsocketRequest} from '@yaakapp-internal/models';
 import {
   patchModel,
@@ -18,7 +19,7 @@ import { VStack } from './core/Stacks';
 
 interface Props {
   activeWorkspaceId: string;
-  request: HttpRequest | GrpcRequest | WebsocketRequest;
+  request: HttpRequest | GrpcRequest | WebsocketRequest | SseRequest;
   onDone: () => void;
 }
 
@@ -0,0 +1,121 @@
+import type {
+  Folder,
+  GrpcRequest,
+  HttpRequest,
+  SseRequest,
+  WebsocketRequest,
+} from '@yaakapp-internal/models';
+import { patchModel } from '@yaakapp-internal/models';
+import { appInfo } from '../hooks/useAppInfo';
+import { PlainInput } from './core/PlainInput';
+import { Select } from './core/Select';
+import { VStack } from './core/Stacks';
+
+interface Props {
+  model: Folder | HttpRequest | GrpcRequest | WebsocketRequest | SseRequest;
+}
+
+type Override = 'inherit' | 'on' | 'off';
+
+const MEGABYTE = 1024 * 1024;
+
+function toOverride(value: boolean | null): Override {
+  return value == null ? 'inherit' : value ? 'on' : 'off';
+}
+
+function fromOverride(value: Override): boolean | null {
+  return value === 'inherit' ? null : value === 'on';
+}
+
+/** Settings that override the ones from the parent folder or workspace when set */
+export function RequestSettingsEditor({ model }: Props) {
+  return (
+    <VStack space={3} className="pb-3">
+      <PlainInput
+        size="sm"
+        name="requestTimeout"
+        label="Request Timeout (ms)"
+        labelClassName="w-[14rem]"
+        labelPosition="left"
+        placeholder="Inherit from parent"
+        defaultValue={model.settingRequestTimeout == null ? '' : `${model.settingRequestTimeout}`}
+        validate={(value) => value === '' || parseInt(value) >= 0}
+        onChange={(v) =>
+          patchModel(model, { settingRequestTimeout: v === '' ? null : parseInt(v) || 0 })
+        }
+        type="number"
+      />
+      <Select
+        name="validateCertificates"
+        label="Validate TLS Certificates"
+        labelClassName="w-[14rem]"
+        labelPosition="left"
+        size="sm"
+        value={toOverride(model.settingValidateCertificates)}
+        onChange={(v) => patchModel(model, { settingValidateCertificates: fromOverride(v) })}
+        options={[
+          { label: 'Inherit from Parent', value: 'inherit' },
+          { label: 'Enabled', value: 'on' },
+          { label: 'Disabled', value: 'off' },
+        ]}
+      />
+      {'settingMaxResponseSize' in model && (
+        <PlainInput
+          size="sm"
+          name="maxResponseSize"
+          label="Max Response Size (MB)"
+          labelClassName="w-[14rem]"
+          labelPosition="left"
+          placeholder="Inherit from parent"
+          defaultValue={
+            model.settingMaxResponseSize == null ? '' : `${model.settingMaxResponseSize / MEGABYTE}`
+          }
+          validate={(value) => value === '' || parseFloat(value) >= 0}
+          onChange={(v) =>
+            patchModel(model, {
+              settingMaxResponseSize:
+                v === '' ? null : Math.round((parseFloat(v) || 0) * MEGABYTE),
+            })
+          }
+          type="number"
+        />
+      )}
+      {'settingFollowRedirects' in model && (
+        <Select
+          name="followRedirects"
+          label="Follow Redirects"
+          labelClassName="w-[14rem]"
+          labelPosition="left"
+          size="sm"
+          value={toOverride(model.settingFollowRedirects)}
+          onChange={(v) => patchModel(model, { settingFollowRedirects: fromOverride(v) })}
+          options={[
+            { label: 'Inherit from Parent', value: 'inherit' },
+            { label: 'Enabled', value: 'on' },
+            { label: 'Disabled', value: 'off' },
+          ]}
+        />
+      )}
+      {'httpVersion' in model && (
+        <Select
+          name="httpVersion"
+          label="HTTP Version"
+          labelClassName="w-[14rem]"
+          labelPosition="left"
+          size="sm"
+          value={model.httpVersion}
+          onChange={(httpVersion) => patchModel(model, { httpVersion })}
+          options={[
+            { label: 'Negotiate', value: 'auto' },
+            { label: 'HTTP/1.1', value: 'http1' },
+            { label: 'HTTP/2 (Prior Knowledge)', value: 'http2' },
+            // Keep showing it for requests that already use it, so the select isn't blank
+            ...(appInfo.supportsHttp3 || model.httpVersion === 'http3'
+              ? [{ label: 'HTTP/3', value: 'http3' as const }]
+              : []),
+          ]}
+        />
+      )}
+    </VStack>
+  );
+}
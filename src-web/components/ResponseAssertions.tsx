@@ -0,0 +1,53 @@
+import type { HttpAssertionResult, HttpResponse } from '@yaakapp-internal/models';
+import { KeyValueRow, KeyValueRows } from './core/KeyValueRow';
+import { EmptyStateText } from './EmptyStateText';
+
+interface Props {
+  response: HttpResponse;
+}
+
+const sourceLabels: Record<HttpAssertionResult['source'], string> = {
+  status: 'Status',
+  header: 'Header',
+  json_path: 'JSONPath',
+  elapsed: 'Time',
+};
+
+const operatorLabels: Record<HttpAssertionResult['operator'], string> = {
+  equals: 'equals',
+  not_equals: 'does not equal',
+  contains: 'contains',
+  matches: 'matches',
+  less_than: 'is less than',
+  greater_than: 'is greater than',
+  exists: 'exists',
+};
+
+export function ResponseAssertions({ response }: Props) {
+  if (response.assertionResults.length === 0) {
+    return <EmptyStateText>No assertions were checked</EmptyStateText>;
+  }
+
+  return (
+    <div className="overflow-auto h-full pb-4">
+      <KeyValueRows>
+        {response.assertionResults.map((r, i) => (
+          <KeyValueRow
+            key={i}
+            label={r.passed ? 'PASS' : 'FAIL'}
+            labelClassName={r.passed ? '!text-success' : '!text-danger'}
+          >
+            <div>
+              {sourceLabels[r.source]}
+              {r.property && ` ${r.property}`} {operatorLabels[r.operator]}
+              {r.operator !== 'exists' && ` ${r.expected}`}
+              <div className="text-text-subtle">
+                {r.error ?? (r.actual == null ? 'Not found' : `Actual: ${r.actual}`)}
+              </div>
+            </div>
+          </KeyValueRow>
+        ))}
+      </KeyValueRows>
+    </div>
+  );
+}
@@ -2,6 +2,7 @@ import { openUrl } from '@tauri-apps/plugin-opener';
 import type { HttpResponse } from '@yaakapp-internal/models';
 import { IconButton } from './core/IconButton';
 import { KeyValueRow, KeyValueRows } from './core/KeyValueRow';
+import { SizeTag } from './core/SizeTag';
 
 interface Props {
   response: HttpResponse;
@@ -38,6 +39,46 @@ export function ResponseInfo({ response }: Props) {
             </div>
           }
         </KeyValueRow>
+        {response.uploadSize != null && (
+          <KeyValueRow labelColor="info" label="Uploaded">
+            <SizeTag contentLength={response.bytesUploaded} /> of{' '}
+            <SizeTag contentLength={response.uploadSize} />
+          </KeyValueRow>
+        )}
+        {response.tlsInfo?.version && (
+          <KeyValueRow labelColor="info" label="TLS Version">
+            {response.tlsInfo.version}
+          </KeyValueRow>
+        )}
+        {response.tlsInfo?.cipher && (
+          <KeyValueRow labelColor="info" label="TLS Cipher">
+            {response.tlsInfo.cipher}
+          </KeyValueRow>
+        )}
+        {response.tlsInfo?.certificates.map((c, i) => (
+          <KeyValueRow
+            key={i}
+            labelColor="secondary"
+            label={i === 0 ? 'Certificate' : `Certificate ${i + 1}`}
+          >
+            <pre className="select-text cursor-text text-xs whitespace-pre-wrap">{c}</pre>
+          </KeyValueRow>
+        ))}
+        {response.redirects.map((r, i) => (
+          <KeyValueRow key={i} labelColor="secondary" label={`Redirect ${r.status}`}>
+            <span className="select-text cursor-text">
+              {r.method} {r.url}
+            </span>
+          </KeyValueRow>
+        ))}
+        {response.attempts.map((a, i) => (
+          <KeyValueRow key={i} labelColor="secondary" label={`Attempt ${i + 1}`}>
+            <span className="select-text cursor-text">
+              {a.error ?? a.status} ({a.elapsed}ms)
+              {a.delay > 0 && `, retried after ${a.delay}ms`}
+            </span>
+          </KeyValueRow>
+        ))}
       </KeyValueRows>
     </div>
   );
@@ -0,0 +1,48 @@
+import type { HttpResponse } from '@yaakapp-internal/models';
+import { useMemo } from 'react';
+import { KeyValueRow, KeyValueRows } from './core/KeyValueRow';
+import { EmptyStateText } from './EmptyStateText';
+
+interface Props {
+  response: HttpResponse;
+}
+
+export function ResponseRequest({ response }: Props) {
+  const sentRequest = response.sentRequest;
+  const sortedHeaders = useMemo(
+    () => [...(sentRequest?.headers ?? [])].sort((a, b) => a.name.localeCompare(b.name)),
+    [sentRequest?.headers],
+  );
+
+  if (sentRequest == null) {
+    return <EmptyStateText>No request was recorded for this response</EmptyStateText>;
+  }
+
+  return (
+    <div className="overflow-auto h-full pb-4">
+      <KeyValueRows>
+        <KeyValueRow labelColor="info" label="Method">
+          {sentRequest.method}
+        </KeyValueRow>
+        <KeyValueRow labelColor="info" label="URL">
+          <span className="select-text cursor-text">{sentRequest.url}</span>
+        </KeyValueRow>
+        {sortedHeaders.map((h, i) => (
+          <KeyValueRow labelColor="primary" key={i} label={h.name}>
+            {h.value}
+          </KeyValueRow>
+        ))}
+        <KeyValueRow labelColor="info" label="Body Size">
+          {sentRequest.bodySize == null ? 'Unknown' : `${sentRequest.bodySize} bytes`}
+        </KeyValueRow>
+        {sentRequest.bodyPreview && (
+          <KeyValueRow labelColor="info" label="Body">
+            <pre className="select-text cursor-text whitespace-pre-wrap break-all">
+              {sentRequest.bodyPreview}
+            </pre>
+          </KeyValueRow>
+        )}
+      </KeyValueRows>
+    </div>
+  );
+}
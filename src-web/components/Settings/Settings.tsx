@@ -9,6 +9,8 @@ import { HStack } from '../core/Stacks';
 import { TabContent, Tabs } from '../core/Tabs/Tabs';
 import { HeaderSize } from '../HeaderSize';
 import { SettingsAppearance } from './SettingsAppearance';
+import { SettingsCertificates } from './SettingsCertificates';
+import { SettingsDns } from './SettingsDns';
 import { SettingsGeneral } from './SettingsGeneral';
 import { SettingsLicense } from './SettingsLicense';
 import { SettingsPlugins } from './SettingsPlugins';
@@ -23,10 +25,16 @@ const tabs = [
   SettingsTab.General,
   SettingsTab.Appearance,
   SettingsTab.Proxy,
+  SettingsTab.Certificates,
+  SettingsTab.Dns,
   SettingsTab.Plugins,
   SettingsTab.License,
 ];
 
+const TAB_LABELS: Partial<Record<SettingsTab, string>> = {
+  [SettingsTab.Dns]: 'DNS',
+};
+
 export default function Settings({ hide }: Props) {
   const osInfo = useOsInfo();
   const { tab: tabFromQuery } = useSearch({ from: '/workspaces/$workspaceId/settings' });
@@ -72,7 +80,7 @@ export default function Settings({ hide }: Props) {
         addBorders
         label="Settings"
         onChangeValue={setTab}
-        tabs={tabs.map((value) => ({ value, label: capitalize(value) }))}
+        tabs={tabs.map((value) => ({ value, label: TAB_LABELS[value] ?? capitalize(value) }))}
       >
         <TabContent value={SettingsTab.General} className="pt-3 overflow-y-auto h-full px-4">
           <SettingsGeneral />
@@ -86,6 +94,12 @@ export default function Settings({ hide }: Props) {
         <TabContent value={SettingsTab.Proxy} className="pt-3 overflow-y-auto h-full px-4">
           <SettingsProxy />
         </TabContent>
+        <TabContent value={SettingsTab.Certificates} className="pt-3 overflow-y-auto h-full px-4">
+          <SettingsCertificates />
+        </TabContent>
+        <TabContent value={SettingsTab.Dns} className="pt-3 overflow-y-auto h-full px-4">
+          <SettingsDns />
+        </TabContent>
         <TabContent value={SettingsTab.License} className="pt-3 overflow-y-auto h-full px-4">
           <SettingsLicense />
         </TabContent>
@@ -0,0 +1,166 @@
+import type { ClientCertificate } from '@yaakapp-internal/models';
+import { patchModel } from '@yaakapp-internal/models';
+import { useAtomValue } from 'jotai';
+import React from 'react';
+import { activeWorkspaceAtom } from '../../hooks/useActiveWorkspace';
+import { Button } from '../core/Button';
+import { Checkbox } from '../core/Checkbox';
+import { Heading } from '../core/Heading';
+import { IconButton } from '../core/IconButton';
+import { PlainInput } from '../core/PlainInput';
+import { Separator } from '../core/Separator';
+import { HStack, VStack } from '../core/Stacks';
+import { SelectFile } from '../SelectFile';
+
+export function SettingsCertificates() {
+  const workspace = useAtomValue(activeWorkspaceAtom);
+
+  if (workspace == null) {
+    return null;
+  }
+
+  const certificates = workspace.settingClientCertificates;
+  const caCertificates = workspace.settingCaCertificates;
+
+  const updateCertificate = (i: number, patch: Partial<ClientCertificate>) =>
+    patchModel(workspace, {
+      settingClientCertificates: certificates.map((c, j) => (i === j ? { ...c, ...patch } : c)),
+    });
+
+  return (
+    <VStack space={1.5} className="mb-4">
+      <Heading level={2}>Client Certificates</Heading>
+      <p className="text-text-subtle text-sm">
+        Presented to servers that require mutual TLS. Use either a certificate and key (PEM) or a
+        single PKCS#12 file.
+      </p>
+      {certificates.map((certificate, i) => (
+        <VStack
+          key={`${i}-${certificates.length}`}
+          space={1.5}
+          className="border border-border-subtle rounded p-3"
+        >
+          <HStack space={1.5}>
+            <Checkbox
+              hideLabel
+              title="Enable certificate"
+              checked={certificate.enabled !== false}
+              onChange={(enabled) => updateCertificate(i, { enabled })}
+            />
+            <PlainInput
+              name="host"
+              label="Host"
+              hideLabel
+              size="sm"
+              placeholder="api.example.com, *.example.com or localhost:8443"
+              defaultValue={certificate.host}
+              onChange={(host) => updateCertificate(i, { host })}
+            />
+            <IconButton
+              size="sm"
+              icon="trash"
+              title="Delete certificate"
+              onClick={() =>
+                patchModel(workspace, {
+                  settingClientCertificates: certificates.filter((_, j) => i !== j),
+                })
+              }
+            />
+          </HStack>
+          <SelectFile
+            noun="Certificate"
+            label="Certificate (PEM)"
+            filePath={certificate.crtFile}
+            onChange={({ filePath }) => updateCertificate(i, { crtFile: filePath })}
+          />
+          <SelectFile
+            noun="Key"
+            label="Key (PEM)"
+            filePath={certificate.keyFile}
+            onChange={({ filePath }) => updateCertificate(i, { keyFile: filePath })}
+          />
+          <SelectFile
+            noun="PFX File"
+            label="PKCS#12 (PFX)"
+            filePath={certificate.pfxFile}
+            onChange={({ filePath }) => updateCertificate(i, { pfxFile: filePath })}
+          />
+          <PlainInput
+            name="passphrase"
+            label="Passphrase"
+            size="sm"
+            type="password"
+            defaultValue={certificate.passphrase ?? ''}
+            onChange={(passphrase) => updateCertificate(i, { passphrase: passphrase || null })}
+          />
+        </VStack>
+      ))}
+      <div>
+        <Button
+          variant="border"
+          size="sm"
+          onClick={() =>
+            patchModel(workspace, {
+              settingClientCertificates: [
+                ...certificates,
+                {
+                  enabled: true,
+                  host: '',
+                  crtFile: null,
+                  keyFile: null,
+                  pfxFile: null,
+                  passphrase: null,
+                },
+              ],
+            })
+          }
+        >
+          Add Certificate
+        </Button>
+      </div>
+
+      <Separator className="my-4" />
+
+      <Heading level={2}>CA Certificates</Heading>
+      <p className="text-text-subtle text-sm">
+        Trusted in addition to the system certificates, useful for internal or self-signed CAs.
+      </p>
+      {caCertificates.map((path, i) => (
+        <HStack key={`${i}-${caCertificates.length}`} space={1.5}>
+          <SelectFile
+            noun="CA Bundle"
+            filePath={path || null}
+            onChange={({ filePath }) =>
+              patchModel(workspace, {
+                settingCaCertificates: caCertificates.map((p, j) =>
+                  i === j ? (filePath ?? '') : p,
+                ),
+              })
+            }
+          />
+          <IconButton
+            size="sm"
+            icon="trash"
+            title="Delete CA certificate"
+            onClick={() =>
+              patchModel(workspace, {
+                settingCaCertificates: caCertificates.filter((_, j) => i !== j),
+              })
+            }
+          />
+        </HStack>
+      ))}
+      <div>
+        <Button
+          variant="border"
+          size="sm"
+          onClick={() =>
+            patchModel(workspace, { settingCaCertificates: [...caCertificates, ''] })
+          }
+        >
+          Add CA Certificate
+        </Button>
+      </div>
+    </VStack>
+  );
+}
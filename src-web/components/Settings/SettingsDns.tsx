@@ -0,0 +1,58 @@
+import { patchModel } from '@yaakapp-internal/models';
+import { useAtomValue } from 'jotai';
+import React, { useMemo } from 'react';
+import { activeWorkspaceAtom } from '../../hooks/useActiveWorkspace';
+import { Heading } from '../core/Heading';
+import type { Pair } from '../core/PairEditor';
+import { PairEditor } from '../core/PairEditor';
+import { VStack } from '../core/Stacks';
+
+export function SettingsDns() {
+  const workspace = useAtomValue(activeWorkspaceAtom);
+
+  const pairs = useMemo<Pair[]>(
+    () =>
+      (workspace?.settingDnsOverrides ?? []).map((o) => ({
+        enabled: o.enabled !== false,
+        name: o.host,
+        value: o.address,
+        id: o.id,
+      })),
+    [workspace?.settingDnsOverrides],
+  );
+
+  if (workspace == null) {
+    return null;
+  }
+
+  return (
+    <VStack space={1.5} className="mb-4">
+      <Heading level={2}>DNS Overrides</Heading>
+      <p className="text-text-subtle text-sm">
+        Connect to a host at the given address instead of the one DNS returns, for HTTP, gRPC and
+        WebSocket requests. TLS still uses the original hostname.
+      </p>
+      <PairEditor
+        noScroll
+        nameAutocompleteVariables
+        valueAutocompleteVariables
+        nameAutocompleteFunctions
+        valueAutocompleteFunctions
+        namePlaceholder="api.example.com"
+        valuePlaceholder="127.0.0.1"
+        pairs={pairs}
+        stateKey={`dns_overrides.${workspace.id}`}
+        onChange={(pairs) =>
+          patchModel(workspace, {
+            settingDnsOverrides: pairs.map((p) => ({
+              enabled: p.enabled !== false,
+              host: p.name,
+              address: p.value,
+              id: p.id,
+            })),
+          })
+        }
+      />
+    </VStack>
+  );
+}
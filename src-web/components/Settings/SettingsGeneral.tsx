@@ -15,6 +15,8 @@ import { Select } from '../core/Select';
 import { Separator } from '../core/Separator';
 import { VStack } from '../core/Stacks';
 
+const MEGABYTE = 1024 * 1024;
+
 export function SettingsGeneral() {
   const workspace = useAtomValue(activeWorkspaceAtom);
   const settings = useAtomValue(settingsAtom);
@@ -98,6 +100,24 @@ export function SettingsGeneral() {
           type="number"
         />
 
+        <PlainInput
+          required
+          size="sm"
+          name="maxResponseSize"
+          label="Max Response Size (MB)"
+          labelClassName="w-[14rem]"
+          placeholder="0"
+          labelPosition="left"
+          defaultValue={`${workspace.settingMaxResponseSize / MEGABYTE}`}
+          validate={(value) => parseFloat(value) >= 0}
+          onChange={(v) =>
+            patchModel(workspace, {
+              settingMaxResponseSize: Math.round((parseFloat(v) || 0) * MEGABYTE),
+            })
+          }
+          type="number"
+        />
+
         <Checkbox
           checked={workspace.settingValidateCertificates}
           help="When disabled, skip validatation of server certificates, useful when interacting with self-signed certs."
@@ -116,6 +136,30 @@ export function SettingsGeneral() {
             })
           }
         />
+
+        <PlainInput
+          required
+          size="sm"
+          name="maxRedirects"
+          label="Max Redirects"
+          labelClassName="w-[14rem]"
+          placeholder="10"
+          labelPosition="left"
+          defaultValue={`${workspace.settingMaxRedirects}`}
+          validate={(value) => parseInt(value) >= 0}
+          onChange={(v) => patchModel(workspace, { settingMaxRedirects: parseInt(v) || 0 })}
+          type="number"
+        />
+
+        <Checkbox
+          checked={workspace.settingRedirectRewriteMethod}
+          disabled={!workspace.settingFollowRedirects}
+          help="When enabled, POST requests that receive a 301, 302 or 303 are re-sent as GET without a body, like browsers do. 307 and 308 always keep the method."
+          title="Rewrite Method on Redirect"
+          onChange={(settingRedirectRewriteMethod) =>
+            patchModel(workspace, { settingRedirectRewriteMethod })
+          }
+        />
       </VStack>
 
       <Separator className="my-4" />
@@ -1,3 +1,4 @@
+import type { ProxySetting } from '@yaakapp-internal/models';
 import { patchModel, settingsAtom } from '@yaakapp-internal/models';
 import { useAtomValue } from 'jotai';
 import React from 'react';
@@ -7,8 +8,16 @@ import { Select } from '../core/Select';
 import { Separator } from '../core/Separator';
 import { HStack, VStack } from '../core/Stacks';
 
+type CustomProxySetting = Exclude<ProxySetting, { type: 'disabled' }>;
+
 export function SettingsProxy() {
   const settings = useAtomValue(settingsAtom);
+  const proxy = settings.proxy;
+
+  const patchProxy = async (patch: Partial<CustomProxySetting>) => {
+    if (proxy == null || proxy.type === 'disabled') return;
+    await patchModel(settings, { proxy: { ...proxy, ...patch } as CustomProxySetting });
+  };
 
   return (
     <VStack space={1.5} className="mb-4">
@@ -17,7 +26,7 @@ export function SettingsProxy() {
         label="Proxy"
         hideLabel
         size="sm"
-        value={settings.proxy?.type ?? 'automatic'}
+        value={proxy?.type ?? 'automatic'}
         onChange={async (v) => {
           if (v === 'automatic') {
             await patchModel(settings, { proxy: undefined });
@@ -29,8 +38,24 @@ export function SettingsProxy() {
                 http: '',
                 https: '',
                 auth: { user: '', password: '' },
+                bypass: '',
+              },
+            });
+          } else if (v === 'socks') {
+            await patchModel(settings, {
+              proxy: {
+                disabled: false,
+                type: 'socks',
+                host: '',
+                remoteDns: true,
+                auth: null,
+                bypass: '',
               },
             });
+          } else if (v === 'pac') {
+            await patchModel(settings, {
+              proxy: { disabled: false, type: 'pac', url: '', bypass: '' },
+            });
           } else {
             await patchModel(settings, { proxy: { type: 'disabled' } });
           }
@@ -38,121 +63,108 @@ export function SettingsProxy() {
         options={[
           { label: 'Automatic Proxy Detection', value: 'automatic' },
           { label: 'Custom Proxy Configuration', value: 'enabled' },
+          { label: 'SOCKS5 Proxy', value: 'socks' },
+          { label: 'Proxy Auto-Config (PAC) File', value: 'pac' },
           { label: 'No Proxy', value: 'disabled' },
         ]}
       />
-      {settings.proxy?.type === 'enabled' && (
+      {proxy != null && proxy.type !== 'disabled' && (
         <VStack space={1.5}>
           <Checkbox
             className="my-3"
-            checked={!settings.proxy.disabled}
+            checked={!proxy.disabled}
             title="Enable proxy"
             help="Use this to temporarily disable the proxy without losing the configuration"
-            onChange={async (enabled) => {
-              const { proxy } = settings;
-              const http = proxy?.type === 'enabled' ? proxy.http : '';
-              const https = proxy?.type === 'enabled' ? proxy.https : '';
-              const auth = proxy?.type === 'enabled' ? proxy.auth : null;
-              const disabled = !enabled;
-              await patchModel(settings, {
-                proxy: { type: 'enabled', http, https, auth, disabled },
-              });
-            }}
+            onChange={(enabled) => patchProxy({ disabled: !enabled })}
           />
-          <HStack space={1.5}>
-            <PlainInput
-              size="sm"
-              label="HTTP"
-              placeholder="localhost:9090"
-              defaultValue={settings.proxy?.http}
-              onChange={async (http) => {
-                const { proxy } = settings;
-                const https = proxy?.type === 'enabled' ? proxy.https : '';
-                const auth = proxy?.type === 'enabled' ? proxy.auth : null;
-                const disabled = proxy?.type === 'enabled' ? proxy.disabled : false;
-                await patchModel(settings, {
-                  proxy: {
-                    type: 'enabled',
-                    http,
-                    https,
-                    auth,
-                    disabled,
-                  },
-                });
-              }}
-            />
-            <PlainInput
-              size="sm"
-              label="HTTPS"
-              placeholder="localhost:9090"
-              defaultValue={settings.proxy?.https}
-              onChange={async (https) => {
-                const { proxy } = settings;
-                const http = proxy?.type === 'enabled' ? proxy.http : '';
-                const auth = proxy?.type === 'enabled' ? proxy.auth : null;
-                const disabled = proxy?.type === 'enabled' ? proxy.disabled : false;
-                await patchModel(settings, {
-                  proxy: { type: 'enabled', http, https, auth, disabled },
-                });
-              }}
-            />
-          </HStack>
-          <Separator className="my-6" />
-          <Checkbox
-            checked={settings.proxy.auth != null}
-            title="Enable authentication"
-            onChange={async (enabled) => {
-              const { proxy } = settings;
-              const http = proxy?.type === 'enabled' ? proxy.http : '';
-              const https = proxy?.type === 'enabled' ? proxy.https : '';
-              const disabled = proxy?.type === 'enabled' ? proxy.disabled : false;
-              const auth = enabled ? { user: '', password: '' } : null;
-              await patchModel(settings, {
-                proxy: { type: 'enabled', http, https, auth, disabled },
-              });
-            }}
-          />
-
-          {settings.proxy.auth != null && (
+          {proxy.type === 'enabled' && (
             <HStack space={1.5}>
               <PlainInput
-                required
                 size="sm"
-                label="User"
-                placeholder="myUser"
-                defaultValue={settings.proxy.auth.user}
-                onChange={async (user) => {
-                  const { proxy } = settings;
-                  const http = proxy?.type === 'enabled' ? proxy.http : '';
-                  const https = proxy?.type === 'enabled' ? proxy.https : '';
-                  const disabled = proxy?.type === 'enabled' ? proxy.disabled : false;
-                  const password = proxy?.type === 'enabled' ? (proxy.auth?.password ?? '') : '';
-                  const auth = { user, password };
-                  await patchModel(settings, {
-                    proxy: { type: 'enabled', http, https, auth, disabled },
-                  });
-                }}
+                label="HTTP"
+                placeholder="localhost:9090"
+                defaultValue={proxy.http}
+                onChange={(http) => patchProxy({ http })}
               />
               <PlainInput
                 size="sm"
-                label="Password"
-                type="password"
-                placeholder="s3cretPassw0rd"
-                defaultValue={settings.proxy.auth.password}
-                onChange={async (password) => {
-                  const { proxy } = settings;
-                  const http = proxy?.type === 'enabled' ? proxy.http : '';
-                  const https = proxy?.type === 'enabled' ? proxy.https : '';
-                  const disabled = proxy?.type === 'enabled' ? proxy.disabled : false;
-                  const user = proxy?.type === 'enabled' ? (proxy.auth?.user ?? '') : '';
-                  const auth = { user, password };
-                  await patchModel(settings, {
-                    proxy: { type: 'enabled', http, https, auth, disabled },
-                  });
-                }}
+                label="HTTPS"
+                placeholder="localhost:9090"
+                defaultValue={proxy.https}
+                onChange={(https) => patchProxy({ https })}
               />
             </HStack>
           )}
+          {proxy.type === 'socks' && (
+            <>
+              <PlainInput
+                size="sm"
+                label="Host"
+                placeholder="localhost:1080"
+                defaultValue={proxy.host}
+                onChange={(host) => patchProxy({ host })}
+              />
+              <Checkbox
+                checked={proxy.remoteDns}
+                title="Resolve hostnames through the proxy"
+                help="Otherwise hostnames are resolved locally, before connecting to the proxy"
+                onChange={(remoteDns) => patchProxy({ remoteDns })}
+              />
+            </>
+          )}
+          {proxy.type === 'pac' && (
+            <PlainInput
+              size="sm"
+              label="PAC File"
+              placeholder="http://wpad.example.com/wpad.dat or /path/to/proxy.pac"
+              defaultValue={proxy.url}
+              onChange={(url) => patchProxy({ url })}
+            />
+          )}
+          <PlainInput
+            size="sm"
+            label="Bypass Proxy For"
+            placeholder="localhost, *.internal.example.com, 10.0.0.0/8"
+            defaultValue={proxy.bypass}
+            onChange={(bypass) => patchProxy({ bypass })}
+          />
+          {proxy.type !== 'pac' && (
+            <>
+              <Separator className="my-6" />
+              <Checkbox
+                checked={proxy.auth != null}
+                title="Enable authentication"
+                onChange={(enabled) =>
+                  patchProxy({ auth: enabled ? { user: '', password: '' } : null })
+                }
+              />
+
+              {proxy.auth != null && (
+                <HStack space={1.5}>
+                  <PlainInput
+                    required
+                    size="sm"
+                    label="User"
+                    placeholder="myUser"
+                    defaultValue={proxy.auth.user}
+                    onChange={(user) =>
+                      patchProxy({ auth: { user, password: proxy.auth?.password ?? '' } })
+                    }
+                  />
+                  <PlainInput
+                    size="sm"
+                    label="Password"
+                    type="password"
+                    placeholder="s3cretPassw0rd"
+                    defaultValue={proxy.auth.password}
+                    onChange={(password) =>
+                      patchProxy({ auth: { user: proxy.auth?.user ?? '', password } })
+                    }
+                  />
+                </HStack>
+              )}
+            </>
+          )}
         </VStack>
       )}
     </VStack>
@@ -2,6 +2,8 @@
 export enum SettingsTab {
     General = 'general',
     Proxy = 'proxy',
+    Certificates = 'certificates',
+    Dns = 'dns',
     Appearance = 'appearance',
     Plugins = 'plugins',
     License = 'license',
@@ -0,0 +1,42 @@
+import type { SseRequest } from '@yaakapp-internal/models';
+import classNames from 'classnames';
+import type { CSSProperties } from 'react';
+import React from 'react';
+import { SplitLayout } from './core/SplitLayout';
+import { SseRequestPane } from './SseRequestPane';
+import { SseResponsePane } from './SseResponsePane';
+
+interface Props {
+  activeRequest: SseRequest;
+  style: CSSProperties;
+}
+
+export function SseRequestLayout({ activeRequest, style }: Props) {
+  return (
+    <SplitLayout
+      name="sse_layout"
+      className="p-3 gap-1.5"
+      style={style}
+      firstSlot={({ orientation, style }) => (
+        <SseRequestPane
+          style={style}
+          activeRequest={activeRequest}
+          fullHeight={orientation === 'horizontal'}
+        />
+      )}
+      secondSlot={({ style }) => (
+        <div
+          style={style}
+          className={classNames(
+            'x-theme-responsePane',
+            'max-h-full h-full grid grid-rows-[minmax(0,1fr)] grid-cols-1',
+            'bg-surface rounded-md border border-border-subtle',
+            'shadow relative',
+          )}
+        >
+          <SseResponsePane activeRequest={activeRequest} />
+        </div>
+      )}
+    />
+  );
+}
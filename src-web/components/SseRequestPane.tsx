@@ -0,0 +1,285 @@
+import { emit } from '@tauri-apps/api/event';
+import type { HttpRequest, SseRequest } from '@yaakapp-internal/models';
+import { patchModel } from '@yaakapp-internal/models';
+import type { GenericCompletionOption } from '@yaakapp-internal/plugins';
+import classNames from 'classnames';
+import { atom, useAtomValue } from 'jotai';
+import type { CSSProperties } from 'react';
+import React, { useCallback, useMemo } from 'react';
+import { getActiveEnvironment } from '../hooks/useActiveEnvironment';
+import { activeRequestIdAtom } from '../hooks/useActiveRequestId';
+import { allRequestsAtom } from '../hooks/useAllRequests';
+import { useHttpAuthenticationSummaries } from '../hooks/useHttpAuthentication';
+import { useKeyValue } from '../hooks/useKeyValue';
+import { activeSseConnectionAtom } from '../hooks/usePinnedSseConnection';
+import { useRequestEditor, useRequestEditorEvent } from '../hooks/useRequestEditor';
+import { useRequestUpdateKey } from '../hooks/useRequestUpdateKey';
+import { deepEqualAtom } from '../lib/atoms';
+import { generateId } from '../lib/generateId';
+import { prepareImportQuerystring } from '../lib/prepareImportQuerystring';
+import { resolvedModelName } from '../lib/resolvedModelName';
+import { invokeCmd } from '../lib/tauri';
+import { CountBadge } from './core/CountBadge';
+import type { GenericCompletionConfig } from './core/Editor/genericCompletion';
+import type { Pair } from './core/PairEditor';
+import { PlainInput } from './core/PlainInput';
+import type { TabItem } from './core/Tabs/Tabs';
+import { TabContent, Tabs } from './core/Tabs/Tabs';
+import { HeadersEditor } from './HeadersEditor';
+import { HttpAuthenticationEditor } from './HttpAuthenticationEditor';
+import { MarkdownEditor } from './MarkdownEditor';
+import { RequestSettingsEditor } from './RequestSettingsEditor';
+import { UrlBar } from './UrlBar';
+import { UrlParametersEditor } from './UrlParameterEditor';
+
+interface Props {
+  style: CSSProperties;
+  fullHeight: boolean;
+  className?: string;
+  activeRequest: SseRequest;
+}
+
+const TAB_PARAMS = 'params';
+const TAB_HEADERS = 'headers';
+const TAB_AUTH = 'auth';
+const TAB_SETTINGS = 'settings';
+const TAB_DESCRIPTION = 'description';
+
+const nonActiveRequestUrlsAtom = atom((get) => {
+  const activeRequestId = get(activeRequestIdAtom);
+  const requests = get(allRequestsAtom);
+  return requests
+    .filter((r) => r.id !== activeRequestId)
+    .map((r): GenericCompletionOption => ({ type: 'constant', label: r.url }));
+});
+
+const memoNotActiveRequestUrlsAtom = deepEqualAtom(nonActiveRequestUrlsAtom);
+
+export function SseRequestPane({ style, className, activeRequest }: Props) {
+  const activeRequestId = activeRequest.id;
+  const { value: activeTabs, set: setActiveTabs } = useKeyValue<Record<string, string>>({
+    namespace: 'no_sync',
+    key: 'sseRequestActiveTabs',
+    fallback: {},
+  });
+  const forceUpdateKey = useRequestUpdateKey(activeRequest.id);
+  const [{ urlKey }, { focusParamsTab, forceUrlRefresh, forceParamsRefresh }] = useRequestEditor();
+  const authentication = useHttpAuthenticationSummaries();
+
+  const { urlParameterPairs, urlParametersKey } = useMemo(() => {
+    const placeholderNames = Array.from(activeRequest.url.matchAll(/\/(:[^/]+)/g)).map(
+      (m) => m[1] ?? '',
+    );
+    const nonEmptyParameters = activeRequest.urlParameters.filter((p) => p.name || p.value);
+    const items: Pair[] = [...nonEmptyParameters];
+    for (const name of placeholderNames) {
+      const index = items.findIndex((p) => p.name === name);
+      if (index >= 0) {
+        items[index]!.readOnlyName = true;
+      } else {
+        items.push({ name, value: '', enabled: true, readOnlyName: true, id: generateId() });
+      }
+    }
+    return { urlParameterPairs: items, urlParametersKey: placeholderNames.join(',') };
+  }, [activeRequest.url, activeRequest.urlParameters]);
+
+  const tabs = useMemo<TabItem[]>(() => {
+    return [
+      {
+        value: TAB_PARAMS,
+        rightSlot: <CountBadge count={urlParameterPairs.length} />,
+        label: 'Params',
+      },
+      {
+        value: TAB_HEADERS,
+        label: 'Headers',
+        rightSlot: <CountBadge count={activeRequest.headers.filter((h) => h.name).length} />,
+      },
+      {
+        value: TAB_AUTH,
+        label: 'Auth',
+        options: {
+          value: activeRequest.authenticationType,
+          items: [
+            ...authentication.map((a) => ({
+              label: a.label || 'UNKNOWN',
+              shortLabel: a.shortLabel,
+              value: a.name,
+            })),
+            { type: 'separator' },
+            { label: 'Inherit from Parent', shortLabel: 'Auth', value: null },
+            { label: 'No Authentication', shortLabel: 'No Auth', value: 'none' },
+          ],
+          onChange: async (authenticationType) => {
+            let authentication: HttpRequest['authentication'] = activeRequest.authentication;
+            if (activeRequest.authenticationType !== authenticationType) {
+              authentication = {
+                // Reset auth if changing types
+              };
+            }
+            await patchModel(activeRequest, {
+              authenticationType,
+              authentication,
+            });
+          },
+        },
+      },
+      { value: TAB_SETTINGS, label: 'Settings' },
+      {
+        value: TAB_DESCRIPTION,
+        label: 'Info',
+      },
+    ];
+  }, [activeRequest, authentication, urlParameterPairs.length]);
+
+  const connection = useAtomValue(activeSseConnectionAtom);
+
+  const activeTab = activeTabs?.[activeRequestId];
+  const setActiveTab = useCallback(
+    async (tab: string) => {
+      await setActiveTabs((r) => ({ ...r, [activeRequest.id]: tab }));
+    },
+    [activeRequest.id, setActiveTabs],
+  );
+
+  useRequestEditorEvent('request_pane.focus_tab', async () => {
+    await setActiveTab(TAB_PARAMS);
+  });
+
+  const autocompleteUrls = useAtomValue(memoNotActiveRequestUrlsAtom);
+
+  const autocomplete: GenericCompletionConfig = useMemo(
+    () => ({
+      minMatch: 3,
+      options:
+        autocompleteUrls.length > 0
+          ? autocompleteUrls
+          : [
+              { label: 'http://', type: 'constant' },
+              { label: 'https://', type: 'constant' },
+            ],
+    }),
+    [autocompleteUrls],
+  );
+
+  const handleConnect = useCallback(async () => {
+    await invokeCmd('cmd_sse_connect', {
+      request: activeRequest,
+      environmentId: getActiveEnvironment()?.id ?? null,
+    });
+  }, [activeRequest]);
+
+  const handleClose = useCallback(async () => {
+    if (connection == null) return;
+    await emit(`close_sse_connection_${connection.id}`);
+  }, [connection]);
+
+  const handleUrlChange = useCallback(
+    (url: string) => patchModel(activeRequest, { url }),
+    [activeRequest],
+  );
+
+  const handlePaste = useCallback(
+    async (e: ClipboardEvent, text: string) => {
+      const patch = prepareImportQuerystring(text);
+      if (patch != null) {
+        e.preventDefault(); // Prevent input onChange
+
+        await patchModel(activeRequest, patch);
+        focusParamsTab();
+
+        // Wait for request to update, then refresh the UI
+        // TODO: Somehow make this deterministic
+        setTimeout(() => {
+          forceUrlRefresh();
+          forceParamsRefresh();
+        }, 100);
+      }
+    },
+    [activeRequest, focusParamsTab, forceParamsRefresh, forceUrlRefresh],
+  );
+
+  const isConnected = connection !== null && connection.state !== 'closed';
+
+  return (
+    <div
+      style={style}
+      className={classNames(className, 'h-full grid grid-rows-[auto_minmax(0,1fr)] grid-cols-1')}
+    >
+      {activeRequest && (
+        <>
+          <div className="grid grid-cols-[minmax(0,1fr)_auto]">
+            <UrlBar
+              stateKey={`url.${activeRequest.id}`}
+              key={forceUpdateKey + urlKey}
+              url={activeRequest.url}
+              submitIcon="arrow_up_down"
+              placeholder="https://example.com/events"
+              onPasteOverwrite={handlePaste}
+              autocomplete={autocomplete}
+              onSend={handleConnect}
+              onCancel={handleClose}
+              onUrlChange={handleUrlChange}
+              forceUpdateKey={forceUpdateKey}
+              isLoading={isConnected}
+              method={null}
+            />
+          </div>
+          <Tabs
+            key={activeRequest.id} // Freshen tabs on request change
+            value={activeTab}
+            label="Request"
+            onChangeValue={setActiveTab}
+            tabs={tabs}
+            tabListClassName="mt-2 !mb-1.5"
+          >
+            <TabContent value={TAB_AUTH}>
+              <HttpAuthenticationEditor request={activeRequest} />
+            </TabContent>
+            <TabContent value={TAB_HEADERS}>
+              <HeadersEditor
+                forceUpdateKey={forceUpdateKey}
+                headers={activeRequest.headers}
+                stateKey={`headers.${activeRequest.id}`}
+                onChange={(headers) => patchModel(activeRequest, { headers })}
+              />
+            </TabContent>
+            <TabContent value={TAB_PARAMS}>
+              <UrlParametersEditor
+                stateKey={`params.${activeRequest.id}`}
+                forceUpdateKey={forceUpdateKey + urlParametersKey}
+                pairs={urlParameterPairs}
+                onChange={(urlParameters) => patchModel(activeRequest, { urlParameters })}
+              />
+            </TabContent>
+            <TabContent value={TAB_SETTINGS}>
+              <RequestSettingsEditor model={activeRequest} />
+            </TabContent>
+            <TabContent value={TAB_DESCRIPTION}>
+              <div className="grid grid-rows-[auto_minmax(0,1fr)] h-full">
+                <PlainInput
+                  label="Request Name"
+                  hideLabel
+                  forceUpdateKey={forceUpdateKey}
+                  defaultValue={activeRequest.name}
+                  className="font-sans !text-xl !px-0"
+                  containerClassName="border-0"
+                  placeholder={resolvedModelName(activeRequest)}
+                  onChange={(name) => patchModel(activeRequest, { name })}
+                />
+                <MarkdownEditor
+                  name="request-description"
+                  placeholder="Request description"
+                  defaultValue={activeRequest.description}
+                  stateKey={`description.${activeRequest.id}`}
+                  forceUpdateKey={forceUpdateKey}
+                  onChange={(description) => patchModel(activeRequest, { description })}
+                />
+              </div>
+            </TabContent>
+          </Tabs>
+        </>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,293 @@
+import type { SseEvent, SseRequest } from '@yaakapp-internal/models';
+import classNames from 'classnames';
+import { format } from 'date-fns';
+import { useAtomValue } from 'jotai';
+import { useMemo, useState } from 'react';
+import { useFormatText } from '../hooks/useFormatText';
+import {
+  activeSseConnectionAtom,
+  activeSseConnectionsAtom,
+  setPinnedSseConnectionId,
+  useSseEvents,
+} from '../hooks/usePinnedSseConnection';
+import { useStateWithDeps } from '../hooks/useStateWithDeps';
+import { languageFromContentType } from '../lib/contentType';
+import { copyToClipboard } from '../lib/copy';
+import { pluralizeCount } from '../lib/pluralize';
+import { AutoScroller } from './core/AutoScroller';
+import { Banner } from './core/Banner';
+import { Button } from './core/Button';
+import { Editor } from './core/Editor/Editor';
+import { HotKeyList } from './core/HotKeyList';
+import { Icon } from './core/Icon';
+import { IconButton } from './core/IconButton';
+import { InlineCode } from './core/InlineCode';
+import { LoadingIcon } from './core/LoadingIcon';
+import type { RadioDropdownItem } from './core/RadioDropdown';
+import { RadioDropdown } from './core/RadioDropdown';
+import { Separator } from './core/Separator';
+import { SplitLayout } from './core/SplitLayout';
+import { SseStatusTag } from './core/SseStatusTag';
+import { HStack, VStack } from './core/Stacks';
+import { EmptyStateText } from './EmptyStateText';
+import { ErrorBoundary } from './ErrorBoundary';
+import { RecentSseConnectionsDropdown } from './RecentSseConnectionsDropdown';
+
+interface Props {
+  activeRequest: SseRequest;
+}
+
+// Events without an `event` field are dispatched as "message", like the browser EventSource does
+const DEFAULT_EVENT_TYPE = 'message';
+
+export function SseResponsePane({ activeRequest }: Props) {
+  const [activeEventId, setActiveEventId] = useState<string | null>(null);
+  const [eventTypeFilter, setEventTypeFilter] = useStateWithDeps<string | null>(null, [
+    activeRequest.id,
+  ]);
+  const [showLarge, setShowLarge] = useStateWithDeps<boolean>(false, [activeRequest.id]);
+  const [showingLarge, setShowingLarge] = useState<boolean>(false);
+
+  const activeConnection = useAtomValue(activeSseConnectionAtom);
+  const connections = useAtomValue(activeSseConnectionsAtom);
+  const allEvents = useSseEvents(activeConnection?.id ?? null);
+
+  const eventTypes = useMemo(
+    () => Array.from(new Set(allEvents.map((e) => e.eventType || DEFAULT_EVENT_TYPE))).sort(),
+    [allEvents],
+  );
+
+  const events = useMemo(
+    () =>
+      eventTypeFilter == null
+        ? allEvents
+        : allEvents.filter((e) => (e.eventType || DEFAULT_EVENT_TYPE) === eventTypeFilter),
+    [allEvents, eventTypeFilter],
+  );
+
+  const eventTypeItems = useMemo<RadioDropdownItem<string | null>[]>(
+    () => [
+      { label: 'All Events', value: null },
+      { type: 'separator' },
+      ...eventTypes.map((t) => ({ label: t, value: t })),
+    ],
+    [eventTypes],
+  );
+
+  const activeEvent = useMemo(
+    () => events.find((m) => m.id === activeEventId) ?? null,
+    [activeEventId, events],
+  );
+
+  const language = languageFromContentType(null, activeEvent?.data ?? '');
+  const formattedData = useFormatText({ language, text: activeEvent?.data ?? '', pretty: true });
+
+  return (
+    <SplitLayout
+      layout="vertical"
+      name="sse_events"
+      defaultRatio={0.4}
+      minHeightPx={20}
+      firstSlot={() =>
+        activeConnection == null ? (
+          <HotKeyList
+            hotkeys={['http_request.send', 'http_request.create', 'sidebar.focus', 'url_bar.focus']}
+          />
+        ) : (
+          <div className="w-full grid grid-rows-[auto_minmax(0,1fr)] items-center">
+            <HStack className="pl-3 mb-1 font-mono text-sm text-text-subtle">
+              <HStack space={2}>
+                {activeConnection.state !== 'closed' && (
+                  <LoadingIcon size="sm" className="text-text-subtlest" />
+                )}
+                <SseStatusTag connection={activeConnection} />
+                {activeConnection.status > 0 && (
+                  <>
+                    <span>&bull;</span>
+                    <span>{activeConnection.status}</span>
+                  </>
+                )}
+                <span>&bull;</span>
+                <span>{pluralizeCount('Event', events.length)}</span>
+                {activeConnection.reconnects > 0 && (
+                  <>
+                    <span>&bull;</span>
+                    <span>{pluralizeCount('Reconnect', activeConnection.reconnects)}</span>
+                  </>
+                )}
+                {activeConnection.lastEventId && (
+                  <>
+                    <span>&bull;</span>
+                    <span title="Sent as Last-Event-ID when reconnecting">
+                      Last ID <InlineCode className="py-0">{activeConnection.lastEventId}</InlineCode>
+                    </span>
+                  </>
+                )}
+              </HStack>
+              <HStack space={0.5} className="ml-auto">
+                <RadioDropdown
+                  value={eventTypeFilter}
+                  items={eventTypeItems}
+                  onChange={setEventTypeFilter}
+                >
+                  <IconButton
+                    title="Filter by event type"
+                    icon="filter"
+                    className={classNames(
+                      'm-0.5',
+                      eventTypeFilter == null ? 'text-text-subtle' : 'text-primary',
+                    )}
+                    size="sm"
+                    iconSize="md"
+                  />
+                </RadioDropdown>
+                <RecentSseConnectionsDropdown
+                  connections={connections}
+                  activeConnection={activeConnection}
+                  onPinnedConnectionId={setPinnedSseConnectionId}
+                />
+              </HStack>
+            </HStack>
+            <ErrorBoundary name="SSE Events">
+              <AutoScroller
+                data={events}
+                header={
+                  activeConnection.error && (
+                    <Banner
+                      color={activeConnection.state === 'reconnecting' ? 'notice' : 'danger'}
+                      className="m-3"
+                    >
+                      {activeConnection.error}
+                    </Banner>
+                  )
+                }
+                render={(event) => (
+                  <EventRow
+                    key={event.id}
+                    event={event}
+                    isActive={event.id === activeEventId}
+                    onClick={() => {
+                      if (event.id === activeEventId) setActiveEventId(null);
+                      else setActiveEventId(event.id);
+                    }}
+                  />
+                )}
+              />
+            </ErrorBoundary>
+          </div>
+        )
+      }
+      secondSlot={
+        activeEvent != null && activeConnection != null
+          ? () => (
+              <div className="grid grid-rows-[auto_minmax(0,1fr)]">
+                <div className="pb-3 px-2">
+                  <Separator />
+                </div>
+                <div className="mx-2 overflow-y-auto grid grid-rows-[auto_minmax(0,1fr)]">
+                  <div className="h-xs mb-2 grid grid-cols-[minmax(0,1fr)_auto] items-center">
+                    <HStack space={1.5} className="font-semibold">
+                      <EventLabels event={activeEvent} />
+                      Event Received
+                    </HStack>
+                    {activeEvent.data != '' && (
+                      <IconButton
+                        title="Copy data"
+                        icon="copy"
+                        size="xs"
+                        onClick={() => copyToClipboard(formattedData.data ?? '')}
+                      />
+                    )}
+                  </div>
+                  {!showLarge && activeEvent.data.length > 1000 * 1000 ? (
+                    <VStack space={2} className="italic text-text-subtlest">
+                      Message previews larger than 1MB are hidden
+                      <div>
+                        <Button
+                          onClick={() => {
+                            setShowingLarge(true);
+                            setTimeout(() => {
+                              setShowLarge(true);
+                              setShowingLarge(false);
+                            }, 500);
+                          }}
+                          isLoading={showingLarge}
+                          color="secondary"
+                          variant="border"
+                          size="xs"
+                        >
+                          Try Showing
+                        </Button>
+                      </div>
+                    </VStack>
+                  ) : activeEvent.data.length === 0 ? (
+                    <EmptyStateText>No Content</EmptyStateText>
+                  ) : (
+                    <Editor
+                      language={language}
+                      defaultValue={formattedData.data ?? ''}
+                      wrapLines={false}
+                      readOnly={true}
+                      stateKey={null}
+                    />
+                  )}
+                </div>
+              </div>
+            )
+          : null
+      }
+    />
+  );
+}
+
+function EventRow({
+  onClick,
+  isActive,
+  event,
+}: {
+  onClick?: () => void;
+  isActive?: boolean;
+  event: SseEvent;
+}) {
+  return (
+    <div className="px-1">
+      <button
+        onClick={onClick}
+        className={classNames(
+          'w-full grid grid-cols-[auto_auto_minmax(0,3fr)_auto] gap-2 items-center text-left',
+          'px-1.5 h-xs font-mono cursor-default group focus:outline-none focus:text-text rounded',
+          isActive && '!bg-surface-active !text-text',
+          'text-text-subtle hover:text',
+        )}
+      >
+        <Icon color="info" title="Server Message" icon="arrow_big_down_dash" />
+        <EventLabels event={event} isActive={isActive} />
+        <div className={classNames('w-full truncate text-xs')}>
+          {event.data === '' ? (
+            <em className="italic text-text-subtlest">No content</em>
+          ) : (
+            event.data.slice(0, 1000)
+          )}
+        </div>
+        <div className={classNames('opacity-50 text-xs')}>
+          {format(event.createdAt + 'Z', 'HH:mm:ss.SSS')}
+        </div>
+      </button>
+    </div>
+  );
+}
+
+function EventLabels({ event, isActive }: { event: SseEvent; isActive?: boolean }) {
+  return (
+    <HStack space={1.5} alignItems="center" className="text-sm">
+      {event.eventId && (
+        <InlineCode className={classNames('py-0', isActive && 'bg-text-subtlest text-text')}>
+          {event.eventId}
+        </InlineCode>
+      )}
+      <InlineCode className={classNames('py-0', isActive && 'bg-text-subtlest text-text')}>
+        {event.eventType || DEFAULT_EVENT_TYPE}
+      </InlineCode>
+    </HStack>
+  );
+}
@@ -33,6 +33,7 @@ import { TabContent, Tabs } from './core/Tabs/Tabs';
 import { HeadersEditor } from './HeadersEditor';
 import { HttpAuthenticationEditor } from './HttpAuthenticationEditor';
 import { MarkdownEditor } from './MarkdownEditor';
+import { RequestSettingsEditor } from './RequestSettingsEditor';
 import { UrlBar } from './UrlBar';
 import { UrlParametersEditor } from './UrlParameterEditor';
 
@@ -47,6 +48,7 @@ const TAB_MESSAGE = 'message';
 const TAB_PARAMS = 'params';
 const TAB_HEADERS = 'headers';
 const TAB_AUTH = 'auth';
+const TAB_SETTINGS = 'settings';
 const TAB_DESCRIPTION = 'description';
 
 const nonActiveRequestUrlsAtom = atom((get) => {
@@ -116,7 +118,8 @@ export function WebsocketRequestPane({ style, fullHeight, className, activeReque
               value: a.name,
             })),
             { type: 'separator' },
-            { label: 'No Authentication', shortLabel: 'Auth', value: null },
+            { label: 'Inherit from Parent', shortLabel: 'Auth', value: null },
+            { label: 'No Authentication', shortLabel: 'No Auth', value: 'none' },
           ],
           onChange: async (authenticationType) => {
             let authentication: HttpRequest['authentication'] = activeRequest.authentication;
@@ -132,6 +135,7 @@ export function WebsocketRequestPane({ style, fullHeight, className, activeReque
           },
         },
       },
+      { value: TAB_SETTINGS, label: 'Settings' },
       {
         value: TAB_DESCRIPTION,
         label: 'Info',
@@ -297,6 +301,9 @@ export function WebsocketRequestPane({ style, fullHeight, className, activeReque
                 stateKey={`json.${activeRequest.id}`}
               />
             </TabContent>
+            <TabContent value={TAB_SETTINGS}>
+              <RequestSettingsEditor model={activeRequest} />
+            </TabContent>
             <TabContent value={TAB_DESCRIPTION}>
               <div className="grid grid-rows-[auto_minmax(0,1fr)] h-full">
                 <PlainInput
@@ -39,6 +39,7 @@ import { Overlay } from './Overlay';
 import { ResizeHandle } from './ResizeHandle';
 import { Sidebar } from './sidebar/Sidebar';
 import { SidebarActions } from './sidebar/SidebarActions';
+import { SseRequestLayout } from './SseRequestLayout';
 import { WebsocketRequestLayout } from './WebsocketRequestLayout';
 import { WorkspaceHeader } from './WorkspaceHeader';
 import { ErrorBoundary } from './ErrorBoundary';
@@ -227,6 +228,8 @@ function WorkspaceBody() {
     return <GrpcConnectionLayout style={body} />;
   } else if (activeRequest.model === 'websocket_request') {
     return <WebsocketRequestLayout style={body} activeRequest={activeRequest} />;
+  } else if (activeRequest.model === 'sse_request') {
+    return <SseRequestLayout style={body} activeRequest={activeRequest} />;
   } else {
     return <HttpRequestLayout activeRequest={activeRequest} style={body} />;
   }
@@ -1,8 +1,13 @@
-import type { GrpcRequest, HttpRequest, WebsocketRequest } from '@yaakapp-internal/models';
+import type {
+  GrpcRequest,
+  HttpRequest,
+  SseRequest,
+  WebsocketRequest,
+} from '@yaakapp-internal/models';
 import classNames from 'classnames';
 
 interface Props {
-  request: HttpRequest | GrpcRequest | WebsocketRequest;
+  request: HttpRequest | GrpcRequest | WebsocketRequest | SseRequest;
   className?: string;
   shortNames?: boolean;
 }
@@ -26,7 +31,9 @@ export function HttpMethodTag({ request, className }: Props) {
         ? 'GRPC'
         : request.model === 'websocket_request'
           ? 'WS'
-          : (methodNames[request.method.toLowerCase()] ?? request.method.slice(0, 4));
+          : request.model === 'sse_request'
+            ? 'SSE'
+            : (methodNames[request.method.toLowerCase()] ?? request.method.slice(0, 4));
 
   const paddedMethod = method.padStart(4, ' ').toUpperCase();
 
@@ -18,7 +18,18 @@ export function HttpResponseDurationTag({ response }: Props) {
     return () => clearInterval(timeout.current);
   }, [response.createdAt, response.elapsed, response.state]);
 
-  const title = `HEADER: ${formatMillis(response.elapsedHeaders)}\nTOTAL: ${formatMillis(response.elapsed)}`;
+  const { dns, connect, tls, ttfb, download } = response.timings;
+  const title = [
+    dns != null ? `DNS: ${formatMillis(dns)}` : null,
+    connect != null ? `CONNECT: ${formatMillis(connect)}` : null,
+    tls != null ? `TLS: ${formatMillis(tls)}` : null,
+    `TTFB: ${formatMillis(ttfb)}`,
+    `DOWNLOAD: ${formatMillis(download)}`,
+    `HEADER: ${formatMillis(response.elapsedHeaders)}`,
+    `TOTAL: ${formatMillis(response.elapsed)}`,
+  ]
+    .filter(Boolean)
+    .join('\n');
 
   const elapsed = response.state === 'closed' ? response.elapsed : fallbackElapsed;
 
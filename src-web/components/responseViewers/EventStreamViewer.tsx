@@ -3,6 +3,7 @@ import type { ServerSentEvent } from '@yaakapp-internal/sse';
 import classNames from 'classnames';
 import React, { Fragment, useMemo, useState } from 'react';
 import { useFormatText } from '../../hooks/useFormatText';
+import { useHttpResponseEvents } from '../../hooks/useHttpResponseEvents';
 import { useResponseBodyEventSource } from '../../hooks/useResponseBodyEventSource';
 import { isJSON } from '../../lib/contentType';
 import { AutoScroller } from '../core/AutoScroller';
@@ -34,7 +35,23 @@ function ActualEventStreamViewer({ response }: Props) {
   const [showLarge, setShowLarge] = useState<boolean>(false);
   const [showingLarge, setShowingLarge] = useState<boolean>(false);
   const [activeEventIndex, setActiveEventIndex] = useState<number | null>(null);
-  const events = useResponseBodyEventSource(response);
+  const storedEvents = useHttpResponseEvents(response.id);
+  // Responses from before events were stored, or ephemeral ones, are parsed from the body instead
+  const bodyEvents = useResponseBodyEventSource(response, storedEvents.length === 0);
+  const events = useMemo<{ data?: ServerSentEvent[]; error?: unknown }>(
+    () =>
+      storedEvents.length > 0
+        ? {
+            data: storedEvents.map((e) => ({
+              eventType: e.eventType,
+              data: e.data,
+              id: e.eventId,
+              retry: e.retry == null ? null : BigInt(e.retry),
+            })),
+          }
+        : bodyEvents,
+    [storedEvents, bodyEvents],
+  );
   const activeEvent = useMemo(
     () => (activeEventIndex == null ? null : events.data?.[activeEventIndex]),
     [activeEventIndex, events],
@@ -2,6 +2,7 @@ import type {
   Folder,
   GrpcRequest,
   HttpRequest,
+  SseRequest,
   WebsocketRequest,
   Workspace,
 } from '@yaakapp-internal/models';
@@ -30,7 +31,13 @@ interface Props {
   className?: string;
 }
 
-export type SidebarModel = Folder | GrpcRequest | HttpRequest | WebsocketRequest | Workspace;
+export type SidebarModel =
+  | Folder
+  | GrpcRequest
+  | HttpRequest
+  | WebsocketRequest
+  | SseRequest
+  | Workspace;
 
 export interface SidebarTreeNode {
   id: string;
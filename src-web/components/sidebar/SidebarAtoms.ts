@@ -3,6 +3,7 @@ import {
   foldersAtom,
   type GrpcRequest,
   type HttpRequest,
+  type SseRequest,
   type WebsocketRequest,
 } from '@yaakapp-internal/models';
 
@@ -65,7 +66,8 @@ export const sidebarTreeAtom = atom<{
     return { tree: null, treeParentMap, selectableRequests };
   }
 
-  const selectedRequest: HttpRequest | GrpcRequest | WebsocketRequest | null = null;
+  const selectedRequest: HttpRequest | GrpcRequest | WebsocketRequest | SseRequest | null =
+    null;
   let selectableRequestIndex = 0;
 
   // Put requests and folders into a tree structure
@@ -105,7 +107,7 @@ export const sidebarTreeAtom = atom<{
 
 function itemFromModel(
   item: Pick<
-    Folder | HttpRequest | GrpcRequest | WebsocketRequest,
+    Folder | HttpRequest | GrpcRequest | WebsocketRequest | SseRequest,
     'folderId' | 'model' | 'workspaceId' | 'id' | 'name' | 'sortPriority'
   >,
   depth = 0,
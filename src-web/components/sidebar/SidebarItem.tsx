@@ -2,6 +2,7 @@ import type {
   AnyModel,
   GrpcConnection,
   HttpResponse,
+  SseConnection,
   WebsocketConnection,
 } from '@yaakapp-internal/models';
 import { foldersAtom, patchModelById } from '@yaakapp-internal/models';
@@ -42,6 +43,7 @@ export type SidebarItemProps = {
   latestHttpResponse: HttpResponse | null;
   latestGrpcConnection: GrpcConnection | null;
   latestWebsocketConnection: WebsocketConnection | null;
+  latestSseConnection: SseConnection | null;
 } & Pick<SidebarItemsProps, 'onSelect'>;
 
 type DragItem = {
@@ -62,6 +64,7 @@ export const SidebarItem = memo(function SidebarItem({
   latestHttpResponse,
   latestGrpcConnection,
   latestWebsocketConnection,
+  latestSseConnection,
   children,
 }: SidebarItemProps) {
   const ref = useRef<HTMLLIElement>(null);
@@ -165,7 +168,8 @@ export const SidebarItem = memo(function SidebarItem({
     if (
       itemModel !== 'http_request' &&
       itemModel !== 'grpc_request' &&
-      itemModel !== 'websocket_request'
+      itemModel !== 'websocket_request' &&
+      itemModel !== 'sse_request'
     )
       return;
     setEditing(true);
@@ -282,6 +286,12 @@ export const SidebarItem = memo(function SidebarItem({
                 <LoadingIcon size="sm" className="text-text-subtlest" />
               )}
             </div>
+          ) : latestSseConnection ? (
+            <div className="ml-auto">
+              {latestSseConnection.state !== 'closed' && (
+                <LoadingIcon size="sm" className="text-text-subtlest" />
+              )}
+            </div>
           ) : latestHttpResponse ? (
             <div className="ml-auto">
               {latestHttpResponse.state !== 'closed' ? (
@@ -19,6 +19,7 @@ import { renameModelWithPrompt } from '../../lib/renameModelWithPrompt';
 import type { DropdownItem } from '../core/Dropdown';
 import { ContextMenu } from '../core/Dropdown';
 import { Icon } from '../core/Icon';
+import { CollectionRunDialog } from '../CollectionRunDialog';
 import { FolderSettingsDialog } from '../FolderSettingsDialog';
 import type { SidebarTreeNode } from './Sidebar';
 
@@ -32,6 +33,7 @@ export function SidebarItemContextMenu({ child, show, close }: Props) {
   const sendManyRequests = useSendManyRequests();
   const httpRequestActions = useHttpRequestActions();
   const sendRequest = useSendAnyHttpRequest();
+  const sendRequestFresh = useSendAnyHttpRequest({ freshConnection: true });
   const workspaces = useAtomValue(workspacesAtom);
   const moveToWorkspace = useMoveToWorkspace(child.id);
   const createDropdownItems = useCreateDropdownItems({
@@ -46,6 +48,20 @@ export function SidebarItemContextMenu({ child, show, close }: Props) {
           leftSlot: <Icon icon="send_horizontal" />,
           onSelect: () => sendManyRequests.mutate(child.children.map((c) => c.id)),
         },
+        {
+          label: 'Run',
+          leftSlot: <Icon icon="flask" />,
+          hidden: child.workspaceId == null,
+          onSelect: () =>
+            showDialog({
+              id: 'collection-run',
+              title: `Run ${child.name}`,
+              size: 'md',
+              render: () => (
+                <CollectionRunDialog workspaceId={child.workspaceId ?? ''} folderId={child.id} />
+              ),
+            }),
+        },
         {
           label: 'Settings',
           leftSlot: <Icon icon="settings" />,
@@ -84,6 +100,11 @@ export function SidebarItemContextMenu({ child, show, close }: Props) {
                 leftSlot: <Icon icon="send_horizontal" />,
                 onSelect: () => sendRequest.mutate(child.id),
               },
+              {
+                label: 'Send (Fresh Connection)',
+                leftSlot: <Icon icon="refresh" />,
+                onSelect: () => sendRequestFresh.mutate(child.id),
+              },
               ...httpRequestActions.map((a) => ({
                 label: a.label,
                 // eslint-disable-next-line @typescript-eslint/no-explicit-any
@@ -103,7 +124,7 @@ export function SidebarItemContextMenu({ child, show, close }: Props) {
           leftSlot: <Icon icon="pencil" />,
           onSelect: async () => {
             const request = getModel(
-              ['http_request', 'grpc_request', 'websocket_request'],
+              ['http_request', 'grpc_request', 'websocket_request', 'sse_request'],
               child.id,
             );
             await renameModelWithPrompt(request);
@@ -116,7 +137,7 @@ export function SidebarItemContextMenu({ child, show, close }: Props) {
           leftSlot: <Icon icon="copy" />,
           onSelect: async () => {
             const request = getModel(
-              ['http_request', 'grpc_request', 'websocket_request'],
+              ['http_request', 'grpc_request', 'websocket_request', 'sse_request'],
               child.id,
             );
             await duplicateRequestAndNavigate(request);
@@ -142,6 +163,8 @@ export function SidebarItemContextMenu({ child, show, close }: Props) {
     child.children,
     child.id,
     child.model,
+    child.name,
+    child.workspaceId,
     createDropdownItems,
     httpRequestActions,
     moveToWorkspace.mutate,
@@ -1,6 +1,7 @@
 import {
   grpcConnectionsAtom,
   httpResponsesAtom,
+  sseConnectionsAtom,
   websocketConnectionsAtom,
 } from '@yaakapp-internal/models';
 import classNames from 'classnames';
@@ -39,6 +40,7 @@ export const SidebarItems = memo(function SidebarItems({
   const httpResponses = useAtomValue(httpResponsesAtom);
   const grpcConnections = useAtomValue(grpcConnectionsAtom);
   const websocketConnections = useAtomValue(websocketConnectionsAtom);
+  const sseConnections = useAtomValue(sseConnectionsAtom);
 
   return (
     <VStack
@@ -65,6 +67,7 @@ export const SidebarItems = memo(function SidebarItems({
               latestWebsocketConnection={
                 websocketConnections.find((c) => c.requestId === child.id) ?? null
               }
+              latestSseConnection={sseConnections.find((c) => c.requestId === child.id) ?? null}
               onMove={handleMove}
               onEnd={handleEnd}
               onSelect={onSelect}
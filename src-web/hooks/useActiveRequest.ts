@@ -1,4 +1,9 @@
-import type { GrpcRequest, HttpRequest, WebsocketRequest } from '@yaakapp-internal/models';
+import type {
+  GrpcRequest,
+  HttpRequest,
+  SseRequest,
+  WebsocketRequest,
+} from '@yaakapp-internal/models';
 import { atom, useAtomValue } from 'jotai';
 import { activeRequestIdAtom } from './useActiveRequestId';
 import { allRequestsAtom } from './useAllRequests';
@@ -13,6 +18,7 @@ interface TypeMap {
   http_request: HttpRequest;
   grpc_request: GrpcRequest;
   websocket_request: WebsocketRequest;
+  sse_request: SseRequest;
 }
 
 export function useActiveRequest<T extends keyof TypeMap>(
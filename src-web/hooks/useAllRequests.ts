@@ -1,12 +1,18 @@
 import {
   grpcRequestsAtom,
   httpRequestsAtom,
+  sseRequestsAtom,
   websocketRequestsAtom,
 } from '@yaakapp-internal/models';
 import { atom, useAtomValue } from 'jotai';
 
 export const allRequestsAtom = atom(function (get) {
-  return [...get(httpRequestsAtom), ...get(grpcRequestsAtom), ...get(websocketRequestsAtom)];
+  return [
+    ...get(httpRequestsAtom),
+    ...get(grpcRequestsAtom),
+    ...get(websocketRequestsAtom),
+    ...get(sseRequestsAtom),
+  ];
 });
 
 export function useAllRequests() {
@@ -6,6 +6,7 @@ export interface AppInfo {
   name: string;
   appDataDir: string;
   appLogDir: string;
+  supportsHttp3: boolean;
 }
 
 export const appInfo = (await invokeCmd('cmd_metadata')) as AppInfo;
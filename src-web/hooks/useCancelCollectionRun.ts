@@ -0,0 +1,9 @@
+import { useFastMutation } from './useFastMutation';
+import { event } from '@tauri-apps/api';
+
+export function useCancelCollectionRun(id: string | null) {
+  return useFastMutation<void>({
+    mutationKey: ['cancel_collection_run', id],
+    mutationFn: () => event.emit(`cancel_collection_run_${id}`),
+  });
+}
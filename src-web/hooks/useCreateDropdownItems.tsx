@@ -57,6 +57,11 @@ export function useCreateDropdownItems({
         onSelect: () =>
           createRequestAndNavigate({ model: 'websocket_request', workspaceId, folderId }),
       },
+      {
+        label: 'Server-Sent Events',
+        leftSlot: hideIcons ? undefined : <Icon icon="plus" />,
+        onSelect: () => createRequestAndNavigate({ model: 'sse_request', workspaceId, folderId }),
+      },
       ...((hideFolder
         ? []
         : [
@@ -1,24 +1,58 @@
 import { useQuery } from '@tanstack/react-query';
-import type { GrpcRequest, HttpRequest, WebsocketRequest } from '@yaakapp-internal/models';
-import { httpResponsesAtom } from '@yaakapp-internal/models';
-import type { GetHttpAuthenticationConfigResponse, JsonPrimitive } from '@yaakapp-internal/plugins';
+import type {
+  GrpcRequest,
+  HttpRequest,
+  SseRequest,
+  WebsocketRequest,
+} from '@yaakapp-internal/models';
+import {
+  grpcConnectionsAtom,
+  httpResponsesAtom,
+  sseConnectionsAtom,
+  websocketConnectionsAtom,
+} from '@yaakapp-internal/models';
+import type {
+  AuthenticationRequestType,
+  GetHttpAuthenticationConfigResponse,
+  JsonPrimitive,
+} from '@yaakapp-internal/plugins';
 import { useAtomValue } from 'jotai';
 import { md5 } from 'js-md5';
 import { useState } from 'react';
 import { invokeCmd } from '../lib/tauri';
 
+type AuthenticatedRequest = HttpRequest | GrpcRequest | WebsocketRequest | SseRequest;
+
+function authenticationRequestType(request: AuthenticatedRequest): AuthenticationRequestType {
+  switch (request.model) {
+    case 'grpc_request':
+      return 'grpc';
+    case 'websocket_request':
+      return 'websocket';
+    case 'sse_request':
+      return 'sse';
+    default:
+      return 'http';
+  }
+}
+
 export function useHttpAuthenticationConfig(
   authName: string | null,
   values: Record<string, JsonPrimitive>,
-  requestId: string,
+  request: AuthenticatedRequest,
 ) {
   const responses = useAtomValue(httpResponsesAtom);
+  const grpcConnections = useAtomValue(grpcConnectionsAtom);
+  const websocketConnections = useAtomValue(websocketConnectionsAtom);
+  const sseConnections = useAtomValue(sseConnectionsAtom);
   const [forceRefreshCounter, setForceRefreshCounter] = useState<number>(0);
+  const requestId = request.id;
+  const requestType = authenticationRequestType(request);
 
   // Some auth handlers like OAuth 2.0 show the current token after a successful request. To
-  // handle that, we'll force the auth to re-fetch after each new response closes
+  // handle that, we'll force the auth to re-fetch after each new response or connection closes
   const responseKey = md5(
-    responses
+    [...responses, ...grpcConnections, ...websocketConnections, ...sseConnections]
       .filter((r) => r.state === 'closed')
       .map((r) => r.id)
       .join(':'),
@@ -28,6 +62,7 @@ export function useHttpAuthenticationConfig(
     queryKey: [
       'http_authentication_config',
       requestId,
+      requestType,
       authName,
       values,
       responseKey,
@@ -42,6 +77,7 @@ export function useHttpAuthenticationConfig(
           authName,
           values,
           requestId,
+          requestType,
         },
       );
 
@@ -49,13 +85,14 @@ export function useHttpAuthenticationConfig(
         ...config,
         actions: config.actions?.map((a, i) => ({
           ...a,
-          call: async ({ id: requestId }: HttpRequest | GrpcRequest | WebsocketRequest) => {
+          call: async (request: AuthenticatedRequest) => {
             await invokeCmd('cmd_call_http_authentication_action', {
               pluginRefId: config.pluginRefId,
               actionIndex: i,
               authName,
               values,
-              requestId,
+              requestId: request.id,
+              requestType: authenticationRequestType(request),
             });
 
             // Ensure the config is refreshed after the action is done
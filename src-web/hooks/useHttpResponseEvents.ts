@@ -0,0 +1,18 @@
+import { invoke } from '@tauri-apps/api/core';
+import type { HttpResponseEvent } from '@yaakapp-internal/models';
+import { httpResponseEventsAtom, replaceModelsInStore } from '@yaakapp-internal/models';
+import { useAtomValue } from 'jotai';
+import { useEffect, useMemo } from 'react';
+
+/** Server-sent events that were stored while the response was streaming */
+export function useHttpResponseEvents(responseId: string) {
+  const events = useAtomValue(httpResponseEventsAtom);
+
+  useEffect(() => {
+    invoke<HttpResponseEvent[]>('plugin:yaak-models|http_response_events', { responseId }).then(
+      (events) => replaceModelsInStore('http_response_event', events),
+    );
+  }, [responseId]);
+
+  return useMemo(() => events.filter((e) => e.responseId === responseId), [events, responseId]);
+}
@@ -47,6 +47,9 @@ export function useImportData() {
               {imported.websocketRequests.length > 0 && (
                 <li>{pluralizeCount('Websocket Request', imported.websocketRequests.length)}</li>
               )}
+              {imported.sseRequests.length > 0 && (
+                <li>{pluralizeCount('SSE Request', imported.sseRequests.length)}</li>
+              )}
             </ul>
             <div>
               <Button className="ml-auto" onClick={hide} color="primary">
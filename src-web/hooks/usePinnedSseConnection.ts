@@ -0,0 +1,57 @@
+import { invoke } from '@tauri-apps/api/core';
+import type { SseConnection, SseEvent } from '@yaakapp-internal/models';
+import { replaceModelsInStore, sseConnectionsAtom, sseEventsAtom } from '@yaakapp-internal/models';
+import { atom, useAtomValue } from 'jotai';
+import { useEffect, useMemo } from 'react';
+import { atomWithKVStorage } from '../lib/atoms/atomWithKVStorage';
+import { jotaiStore } from '../lib/jotai';
+import { activeRequestIdAtom } from './useActiveRequestId';
+
+const pinnedSseConnectionIdAtom = atomWithKVStorage<Record<string, string | null>>(
+  'pinned-sse-connection-ids',
+  {},
+);
+
+function recordKey(activeRequestId: string | null, latestConnection: SseConnection | null) {
+  return activeRequestId + '-' + (latestConnection?.id ?? 'none');
+}
+
+export const activeSseConnectionsAtom = atom<SseConnection[]>((get) => {
+  const activeRequestId = get(activeRequestIdAtom) ?? 'n/a';
+  return get(sseConnectionsAtom).filter((c) => c.requestId === activeRequestId);
+});
+
+export const activeSseConnectionAtom = atom<SseConnection | null>((get) => {
+  const activeRequestId = get(activeRequestIdAtom) ?? 'n/a';
+  const activeConnections = get(activeSseConnectionsAtom);
+  const latestConnection = activeConnections[0] ?? null;
+  const pinnedConnectionId = get(pinnedSseConnectionIdAtom)[
+    recordKey(activeRequestId, latestConnection)
+  ];
+  return activeConnections.find((c) => c.id === pinnedConnectionId) ?? activeConnections[0] ?? null;
+});
+
+export function setPinnedSseConnectionId(id: string | null) {
+  const activeRequestId = jotaiStore.get(activeRequestIdAtom);
+  const activeConnections = jotaiStore.get(activeSseConnectionsAtom);
+  const latestConnection = activeConnections[0] ?? null;
+  if (activeRequestId == null) return;
+  jotaiStore.set(pinnedSseConnectionIdAtom, (prev) => {
+    return { ...prev, [recordKey(activeRequestId, latestConnection)]: id };
+  });
+}
+
+export function useSseEvents(connectionId: string | null) {
+  const events = useAtomValue(sseEventsAtom);
+
+  useEffect(() => {
+    invoke<SseEvent[]>('plugin:yaak-models|sse_events', { connectionId }).then((events) =>
+      replaceModelsInStore('sse_event', events),
+    );
+  }, [connectionId]);
+
+  return useMemo(
+    () => events.filter((e) => e.connectionId === connectionId),
+    [events, connectionId],
+  );
+}
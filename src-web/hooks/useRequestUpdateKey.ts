@@ -11,7 +11,8 @@ getCurrentWebviewWindow()
     if (
       (payload.model.model === 'http_request' ||
         payload.model.model === 'grpc_request' ||
-        payload.model.model === 'websocket_request') &&
+        payload.model.model === 'websocket_request' ||
+        payload.model.model === 'sse_request') &&
       ((payload.updateSource.type === 'window' &&
         payload.updateSource.label !== getCurrentWebviewWindow().label) ||
         payload.updateSource.type !== 'window')
@@ -3,8 +3,9 @@ import type { HttpResponse } from '@yaakapp-internal/models';
 import type { ServerSentEvent } from '@yaakapp-internal/sse';
 import { getResponseBodyEventSource } from '../lib/responseBody';
 
-export function useResponseBodyEventSource(response: HttpResponse) {
+export function useResponseBodyEventSource(response: HttpResponse, enabled = true) {
   return useQuery<ServerSentEvent[]>({
+    enabled,
     placeholderData: (prev) => prev, // Keep previous data on refetch
     queryKey: ['response-body-event-source', response.id, response.contentLength],
     queryFn: () => getResponseBodyEventSource(response),
@@ -0,0 +1,25 @@
+import type { CollectionRun } from '@yaakapp-internal/models';
+import { invokeCmd } from '../lib/tauri';
+import { getActiveCookieJar } from './useActiveCookieJar';
+import { getActiveEnvironment } from './useActiveEnvironment';
+import { useFastMutation } from './useFastMutation';
+
+interface RunCollectionArgs {
+  workspaceId: string;
+  folderId: string | null;
+  iterations: number;
+  delay: number;
+  stopOnFailure: boolean;
+}
+
+export function useRunCollection() {
+  return useFastMutation<CollectionRun, string, RunCollectionArgs>({
+    mutationKey: ['run_collection'],
+    mutationFn: (args) =>
+      invokeCmd('cmd_run_collection', {
+        ...args,
+        environmentId: getActiveEnvironment()?.id,
+        cookieJarId: getActiveCookieJar()?.id,
+      }),
+  });
+}
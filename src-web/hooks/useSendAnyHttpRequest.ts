@@ -5,9 +5,14 @@ import { getActiveCookieJar } from './useActiveCookieJar';
 import { getActiveEnvironment } from './useActiveEnvironment';
 import { useFastMutation } from './useFastMutation';
 
-export function useSendAnyHttpRequest() {
+/**
+ * @param freshConnection skip the pooled client, so timings include DNS, connect and TLS
+ */
+export function useSendAnyHttpRequest({
+  freshConnection = false,
+}: { freshConnection?: boolean } = {}) {
   return useFastMutation<HttpResponse | null, string, string | null>({
-    mutationKey: ['send_any_request'],
+    mutationKey: ['send_any_request', freshConnection],
     mutationFn: async (id) => {
       const request = getModel('http_request', id ?? 'n/a');
       if (request == null) {
@@ -18,6 +23,7 @@ export function useSendAnyHttpRequest() {
         request,
         environmentId: getActiveEnvironment()?.id,
         cookieJarId: getActiveCookieJar()?.id,
+        freshConnection,
       });
     },
   });
@@ -71,7 +71,8 @@ function isModelRelevant(m: AnyModel) {
     m.model !== 'environment' &&
     m.model !== 'http_request' &&
     m.model !== 'grpc_request' &&
-    m.model !== 'websocket_request'
+    m.model !== 'websocket_request' &&
+    m.model !== 'sse_request'
   ) {
     return false;
   } else if (m.model === 'workspace') {
@@ -1,11 +1,16 @@
-import type { GrpcRequest, HttpRequest, WebsocketRequest } from '@yaakapp-internal/models';
+import type {
+  GrpcRequest,
+  HttpRequest,
+  SseRequest,
+  WebsocketRequest,
+} from '@yaakapp-internal/models';
 import { duplicateModel } from '@yaakapp-internal/models';
 import { activeWorkspaceIdAtom } from '../hooks/useActiveWorkspace';
 import { jotaiStore } from './jotai';
 import { router } from './router';
 
 export async function duplicateRequestAndNavigate(
-  model: HttpRequest | GrpcRequest | WebsocketRequest | null,
+  model: HttpRequest | GrpcRequest | WebsocketRequest | SseRequest | null,
 ) {
   if (model == null) {
     throw new Error('Cannot duplicate null request');
@@ -21,7 +21,9 @@ export function resolvedModelName(r: AnyModel | null): string {
       ? 'HTTP Request'
       : r.model === 'websocket_request'
         ? 'WebSocket Request'
-        : 'gRPC Request';
+        : r.model === 'sse_request'
+          ? 'SSE Request'
+          : 'gRPC Request';
   }
 
   // GRPC gets nice short names
@@ -11,6 +11,7 @@ type TauriCmd =
   | 'cmd_secure_template'
   | 'cmd_delete_all_grpc_connections'
   | 'cmd_delete_all_http_responses'
+  | 'cmd_delete_all_sse_connections'
   | 'cmd_delete_send_history'
   | 'cmd_dismiss_notification'
   | 'cmd_export_data'
@@ -31,10 +32,12 @@ type TauriCmd =
   | 'cmd_plugin_info'
   | 'cmd_reload_plugins'
   | 'cmd_render_template'
+  | 'cmd_run_collection'
   | 'cmd_save_response'
   | 'cmd_send_ephemeral_request'
   | 'cmd_send_http_request'
   | 'cmd_show_workspace_key'
+  | 'cmd_sse_connect'
   | 'cmd_template_functions'
   | 'cmd_template_tokens_to_string'
   | 'cmd_uninstall_plugin';